@@ -68,6 +68,21 @@ export const DEFAULT_RETENTION_FACTOR = 0.95;
  */
 export const INFORMATION_HALF_LIFE_DAYS = 30;
 
+// ============================================================================
+// PROGI SZUMU SEMANTYCZNEGO (STATUS SYGNAŁU)
+// ============================================================================
+
+/**
+ * @cybernetic Maksymalny szum dla STATUS: CLEAR - pełna akceptacja sygnału
+ * Używany przez Receptor (validator.ts) i rdzeń Rust (receptor/validator.rs)
+ */
+export const SIGNAL_CLEAR_MAX_NOISE = 0.4;
+
+/**
+ * @cybernetic Maksymalny szum dla STATUS: WARNING - powyżej sygnał jest odrzucany (REJECT)
+ */
+export const SIGNAL_WARNING_MAX_NOISE = 0.7;
+
 // ============================================================================
 // WAGI TYPÓW ŹRÓDEŁ
 // ============================================================================
@@ -0,0 +1,74 @@
+/**
+ * @fileoverview Test parytetu pathfinder-core.ts z rdzeniem Rust
+ * @cybernetic TS fallback i wasm_recommend muszą dawać te same rekomendacje
+ *
+ * Wzorzec recommend-parity.json sprawdza też test Rust
+ * (recommend.rs: test_matches_pathfinder_core_golden) - obie strony
+ * porównywane są z tym samym plikiem.
+ */
+
+import { describe, it, expect } from '@jest/globals';
+import { readFileSync } from 'fs';
+import type { CyberneticObject, Correlation } from '../../supabase/types';
+import type { SteeringGoal } from './types';
+import { simulateSteeringLocal, type RecommendationLanguage } from './pathfinder-core';
+
+// ============================================================================
+// FIXTURES (wspólne z testami Rust)
+// ============================================================================
+
+const FIXTURES = new URL('../wasm_core/tests/fixtures/kms-core/', import.meta.url);
+
+function fixture<T>(name: string): T {
+  return JSON.parse(readFileSync(new URL(name, FIXTURES), 'utf-8')) as T;
+}
+
+interface ParityCase {
+  target_id: string;
+  objects?: CyberneticObject[];       // Własny graf (remisy toFixed) zamiast fixtures
+  correlations?: Correlation[];
+  goal: SteeringGoal;
+  language: RecommendationLanguage;
+  expected: {
+    primary_recommendation: unknown;
+    alternative_recommendations: unknown[];
+    warnings: string[];
+    total_paths_analyzed: number;
+  };
+}
+
+const objects = fixture<CyberneticObject[]>('objects.json');
+const correlations = fixture<Correlation[]>('correlations.json');
+const cases = fixture<ParityCase[]>('recommend-parity.json');
+
+// ============================================================================
+// TESTY
+// ============================================================================
+
+describe('simulateSteeringLocal - parytet z wasm_recommend', () => {
+  it.each(cases.map((c) => [`${c.target_id} / ${c.goal} / ${c.language}`, c] as const))(
+    'powinien dać te same rekomendacje co Rust: %s',
+    (_label, parityCase) => {
+      const result = simulateSteeringLocal(
+        parityCase.objects ?? objects,
+        parityCase.correlations ?? correlations,
+        parityCase.target_id,
+        parityCase.goal,
+        parityCase.language
+      );
+
+      expect({
+        primary_recommendation: result.primary_recommendation,
+        alternative_recommendations: result.alternative_recommendations,
+        warnings: result.warnings,
+        total_paths_analyzed: result.simulation_metadata.total_paths_analyzed,
+      }).toEqual(parityCase.expected);
+    }
+  );
+
+  it('powinien odrzucić nieistniejący cel komunikatem z szablonu', () => {
+    expect(() => simulateSteeringLocal(objects, correlations, 'brak', 'strengthen', 'en')).toThrow(
+      'Object brak does not exist'
+    );
+  });
+});
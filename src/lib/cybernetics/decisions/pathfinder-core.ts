@@ -6,11 +6,17 @@
  * Używane w:
  * - benchmark.ts (testy wydajności)
  * - fallback.ts (TypeScript fallback dla Wasm)
+ *
+ * Pełny wynik symulacji liczy rdzeń Rust (`wasm_recommend`, patrz
+ * simulateSteeringCore). Teksty rekomendacji pochodzą z tych samych szablonów
+ * co w Rust (recommendation_templates.json) - wersja TS jest fallbackiem
+ * zgodnym z Wasm (pathfinder-core.test.ts).
  */
 
 import type { CyberneticObject, Correlation } from '../../supabase/types';
 import type {
   SteeringGoal,
+  SteeringSimulationResult,
   InfluentialNode,
   InfluencePath,
 } from './types';
@@ -18,6 +24,8 @@ import {
   calculateFeedbackMultiplier,
   calculateControlLeverage,
 } from './types';
+import { recommendWasm } from '../wasm_core/bridge';
+import RECOMMENDATION_TEMPLATES from '../wasm_core/src/decisions/recommendation_templates.json';
 
 // ============================================================================
 // KONFIGURACJA
@@ -28,6 +36,7 @@ export const PATHFINDER_CONFIG = {
   MAX_PATHS: 100,                  // Maksymalna liczba ścieżek do analizy
   MIN_INFLUENCE_THRESHOLD: 0.1,   // Minimalny wpływ do rozważenia
   TOP_RECOMMENDATIONS: 5,          // Liczba top rekomendacji
+  TOP_INFLUENTIAL_NODES: 10,       // Liczba węzłów wpływowych w wyniku
 };
 
 // ============================================================================
@@ -43,6 +52,11 @@ export interface Graph {
   reverseAdjacencyList: Map<string, Correlation[]>; // target_id -> [correlations]
 }
 
+/**
+ * @cybernetic Język uzasadnień i ostrzeżeń (jak `Language` w Rust)
+ */
+export type RecommendationLanguage = keyof typeof RECOMMENDATION_TEMPLATES;
+
 // ============================================================================
 // CORE LOGIC
 // ============================================================================
@@ -269,24 +283,44 @@ export function calculateNodeInfluences(
   return result;
 }
 
+/**
+ * @cybernetic Podstawia `{klucz}` w szablonie
+ */
+function render(template: string, values: Record<string, string>): string {
+  return Object.entries(values).reduce(
+    (text, [key, value]) => text.split(`{${key}}`).join(value),
+    template
+  );
+}
+
 /**
  * @cybernetic Generuje uzasadnienie rekomendacji
  *
  * @param node - Wpływowy węzeł
  * @param goal - Cel sterowania
+ * @param language - Język szablonu (domyślnie polski)
  * @returns Opis uzasadnienia
  */
-export function generateRationale(node: InfluentialNode, goal: SteeringGoal): string {
+export function generateRationale(
+  node: InfluentialNode,
+  goal: SteeringGoal,
+  language: RecommendationLanguage = 'pl'
+): string {
+  const templates = RECOMMENDATION_TEMPLATES[language];
   const feedbackDesc = node.feedback_multiplier > 1
-    ? 'sprzężenie dodatnie (wzmacniające)'
+    ? templates.feedback_positive
     : node.feedback_multiplier < 1
-    ? 'sprzężenie ujemne (hamujące)'
-    : 'brak sprzężenia zwrotnego';
-
-  return `Obiekt generuje ${feedbackDesc} i ma ${node.path_count} ścieżek wpływu. ` +
-         `Dźwignia sterownicza: ${node.control_leverage.toFixed(2)} ` +
-         `(moc: ${node.available_power.toFixed(2)}, wpływ: ${node.influence_strength.toFixed(2)}, ` +
-         `rzetelność: ${node.certainty_score.toFixed(2)}).`;
+    ? templates.feedback_negative
+    : templates.feedback_none;
+
+  return render(templates.rationale, {
+    feedback: feedbackDesc,
+    path_count: String(node.path_count),
+    leverage: node.control_leverage.toFixed(2),
+    power: node.available_power.toFixed(2),
+    influence: node.influence_strength.toFixed(2),
+    certainty: node.certainty_score.toFixed(2),
+  });
 }
 
 /**
@@ -295,23 +329,26 @@ export function generateRationale(node: InfluentialNode, goal: SteeringGoal): st
  * @param influentialNodes - Posortowana lista wpływowych węzłów
  * @param targetObject - Obiekt docelowy
  * @param goal - Cel sterowania
+ * @param language - Język szablonów (domyślnie polski)
  * @returns Rekomendacje + ostrzeżenia
  */
 export function generateRecommendations(
   influentialNodes: InfluentialNode[],
   targetObject: CyberneticObject,
-  goal: SteeringGoal
+  goal: SteeringGoal,
+  language: RecommendationLanguage = 'pl'
 ) {
+  const templates = RECOMMENDATION_TEMPLATES[language];
   const warnings: string[] = [];
 
   if (influentialNodes.length === 0) {
-    warnings.push('Nie znaleziono żadnych ścieżek wpływu na obiekt docelowy.');
+    warnings.push(templates.warning_no_paths);
     return {
       primary: {
         object_id: '',
-        object_name: 'Brak rekomendacji',
-        action: 'Brak dostępnych akcji',
-        rationale: 'Obiekt jest izolowany w grafie relacji.',
+        object_name: templates.no_recommendation_name,
+        action: templates.no_action,
+        rationale: templates.isolated_rationale,
         expected_impact: 0,
         confidence: 0,
       },
@@ -322,39 +359,100 @@ export function generateRecommendations(
 
   // Primary recommendation - najwyższa dźwignia sterownicza
   const top = influentialNodes[0];
-  const actionVerb = goal === 'strengthen' ? 'wzmocnić' : 'osłabić';
-
-  const primary = {
-    object_id: top.object_id,
-    object_name: top.object_name,
-    action: `${actionVerb} "${top.object_name}"`,
-    rationale: generateRationale(top, goal),
-    expected_impact: Math.min(top.influence_strength * top.feedback_multiplier, 1.0),
-    confidence: top.certainty_score,
-  };
+  const actionVerb = goal === 'strengthen' ? templates.verb_strengthen : templates.verb_weaken;
 
-  // Alternative recommendations
-  const alternatives = influentialNodes.slice(1, PATHFINDER_CONFIG.TOP_RECOMMENDATIONS).map(node => ({
+  const recommend = (node: InfluentialNode) => ({
     object_id: node.object_id,
     object_name: node.object_name,
-    action: `${actionVerb} "${node.object_name}"`,
-    rationale: generateRationale(node, goal),
+    action: render(templates.action, { verb: actionVerb, name: node.object_name }),
+    rationale: generateRationale(node, goal, language),
     expected_impact: Math.min(node.influence_strength * node.feedback_multiplier, 1.0),
     confidence: node.certainty_score,
-  }));
+  });
+
+  const primary = recommend(top);
+
+  // Alternative recommendations
+  const alternatives = influentialNodes.slice(1, PATHFINDER_CONFIG.TOP_RECOMMENDATIONS).map(recommend);
 
   // Warnings
   if (primary.confidence < 0.5) {
-    warnings.push('OSTRZEŻENIE: Niska rzetelność relacji. Rekomendacja wymaga weryfikacji.');
+    warnings.push(templates.warning_low_confidence);
   }
 
   if (top.available_power < 1.0) {
-    warnings.push('OSTRZEŻENIE: Obiekt ma niską moc swobodną. Wpływ może być ograniczony.');
+    warnings.push(templates.warning_low_power);
   }
 
   return { primary, alternatives, warnings };
 }
 
+/**
+ * @cybernetic Pełna symulacja sterowania w TypeScript (fallback dla Wasm)
+ *
+ * Ten sam wynik co `wasm_recommend` bez pól tylko z rdzenia Rust
+ * (biegunowość, wyjaśnienia węzłów).
+ *
+ * @param objects - Tablica obiektów cybernetycznych
+ * @param correlations - Tablica relacji
+ * @param targetId - ID obiektu docelowego
+ * @param goal - Cel sterowania
+ * @param language - Język szablonów (domyślnie polski)
+ * @returns Wynik symulacji z rekomendacjami
+ */
+export function simulateSteeringLocal(
+  objects: CyberneticObject[],
+  correlations: Correlation[],
+  targetId: string,
+  goal: SteeringGoal,
+  language: RecommendationLanguage = 'pl'
+): SteeringSimulationResult {
+  const startTime = performance.now();
+  const graph = buildGraph(objects, correlations);
+  const targetObject = graph.objects.get(targetId);
+
+  if (!targetObject) {
+    throw new Error(render(RECOMMENDATION_TEMPLATES[language].target_not_found, { id: targetId }));
+  }
+
+  const paths = findInfluencePaths(graph, targetId, goal);
+  const influentialNodes = calculateNodeInfluences(paths, graph);
+  influentialNodes.sort((a, b) => b.control_leverage - a.control_leverage);
+
+  const recommendations = generateRecommendations(influentialNodes, targetObject, goal, language);
+
+  return {
+    target_object_id: targetId,
+    target_object_name: targetObject.name,
+    goal,
+    influential_nodes: influentialNodes.slice(0, PATHFINDER_CONFIG.TOP_INFLUENTIAL_NODES),
+    primary_recommendation: recommendations.primary,
+    alternative_recommendations: recommendations.alternatives,
+    warnings: recommendations.warnings,
+    simulation_metadata: {
+      total_paths_analyzed: paths.length,
+      max_depth: PATHFINDER_CONFIG.MAX_DEPTH,
+      computation_time_ms: performance.now() - startTime,
+    },
+  };
+}
+
+/**
+ * @cybernetic Symulacja sterowania w rdzeniu Rust (`wasm_recommend`)
+ *
+ * Rekomendacje, ostrzeżenia i uzasadnienia liczy Wasm; gdy moduł się nie
+ * załaduje, bridge przechodzi na simulateSteeringLocal.
+ */
+export async function simulateSteeringCore(
+  objects: CyberneticObject[],
+  correlations: Correlation[],
+  targetId: string,
+  goal: SteeringGoal,
+  language: RecommendationLanguage = 'pl'
+): Promise<SteeringSimulationResult> {
+  return recommendWasm(objects, correlations, targetId, goal, language);
+}
+
 // ============================================================================
 // EXPORT
 // ============================================================================
@@ -365,5 +463,7 @@ export default {
   calculateNodeInfluences,
   generateRationale,
   generateRecommendations,
+  simulateSteeringLocal,
+  simulateSteeringCore,
   PATHFINDER_CONFIG,
 };
@@ -25,6 +25,9 @@ export interface InfluencePath {
   certainty_score: number;           // Średnia rzetelność relacji w ścieżce
   depth: number;                     // Długość ścieżki
   is_feedback_loop: boolean;         // Czy ścieżka zawiera pętlę sprzężenia zwrotnego
+  correlation_ids?: string[];        // ID relacji wzdłuż ścieżki (tylko rdzeń Wasm)
+  polarity?: 'positive' | 'negative'; // Iloczyn znaków relacji (negative_feedback = -1)
+  signed_strength?: number;          // polarity × total_strength
 }
 
 /**
@@ -40,6 +43,41 @@ export interface InfluentialNode {
   certainty_score: number;           // Średnia rzetelność relacji
   control_leverage: number;          // Dźwignia sterownicza (power * influence * certainty)
   paths: InfluencePath[];            // Ścieżki wpływu
+  positive_influence?: number;       // Część influence_strength ze ścieżek dodatnich
+  negative_influence?: number;       // Część influence_strength ze ścieżek ujemnych
+  net_influence?: number;            // > 0: pchnięcie węzła wzmacnia cel, < 0: osłabia
+  explanation?: LeverageExplanation; // Rozkład dźwigni (tylko rdzeń Wasm)
+}
+
+/**
+ * @cybernetic Wyjaśnienie dźwigni sterowniczej (wasm_explain_influence)
+ */
+export interface LeverageExplanation {
+  factors: {
+    available_power: number;
+    influence_strength: number;
+    feedback_multiplier: number;
+    certainty_score: number;
+  };
+  leverage_without_feedback: number;   // P × I × C
+  leverage_at_full_certainty: number;  // P × I × F
+  top_paths: Array<{
+    path: string[];
+    path_names: string[];
+    correlation_ids: string[];
+    total_strength: number;
+    certainty_score: number;
+    share: number;                     // Udział w Σ sił ścieżek (0-1)
+    leverage_contribution: number;
+  }>;
+  bottleneck_edges: Array<{
+    correlation_id: string;
+    source_id: string;
+    target_id: string;
+    certainty_score: number;
+    paths_limited: number;             // W ilu ścieżkach jest najsłabszym ogniwem
+    leverage_gain: number;             // Przyrost dźwigni przy certainty = 1
+  }>;
 }
 
 /**
@@ -8,6 +8,7 @@
 
 import { z } from 'zod';
 import type { FeedbackType, ControlType } from '../types';
+import { SIGNAL_CLEAR_MAX_NOISE, SIGNAL_WARNING_MAX_NOISE } from '../constants';
 
 // ============================================================================
 // SCHEMAT OBIEKTU WEJŚCIOWEGO
@@ -60,8 +61,7 @@ export const ExtractedObjectSchema = z.object({
   motivation_type: z.enum([
     'vital',         // Motywacje witalne (przeżycie biologiczne)
     'informational', // Motywacje informacyjne (poznanie)
-    'economic',      // Motywacje ekonomiczne (zysk, zasoby)
-    'mixed'          // Mieszane
+    'mixed'          // Mieszane (MotivationType w supabase/types.ts)
   ]).default('informational'),
 });
 
@@ -223,9 +223,9 @@ export function isAutonomousSystem(obj: ExtractedObject): boolean {
  * Implementacja gradacji zgodnej z rygorem Kosseckiego
  */
 export function calculateSignalStatus(noiseLevel: number): SignalStatus {
-  if (noiseLevel <= 0.4) {
+  if (noiseLevel <= SIGNAL_CLEAR_MAX_NOISE) {
     return 'CLEAR';
-  } else if (noiseLevel <= 0.7) {
+  } else if (noiseLevel <= SIGNAL_WARNING_MAX_NOISE) {
     return 'WARNING';
   } else {
     return 'REJECT';
@@ -289,14 +289,17 @@ export function validateCyberneticInput(input: CyberneticInput): {
   }
   
   // REJECT: Odrzuć sygnał jeśli noise_level > 0.7
-  if (input.metadata.semantic_noise_level > 0.7 || input.metadata.signal_status === 'REJECT') {
+  if (input.metadata.semantic_noise_level > SIGNAL_WARNING_MAX_NOISE || input.metadata.signal_status === 'REJECT') {
     errors.push(
       `STATUS: REJECT - Sygnał zbyt zniekształcony ideologicznie lub merytorycznie pusty. Noise level: ${input.metadata.semantic_noise_level.toFixed(2)}`
     );
   }
   
   // WARNING: Sygnał niejednoznaczny wymaga is_ambiguous = true
-  if (input.metadata.semantic_noise_level > 0.4 && input.metadata.semantic_noise_level <= 0.7) {
+  if (
+    input.metadata.semantic_noise_level > SIGNAL_CLEAR_MAX_NOISE &&
+    input.metadata.semantic_noise_level <= SIGNAL_WARNING_MAX_NOISE
+  ) {
     if (!input.metadata.is_ambiguous) {
       errors.push(
         `STATUS: WARNING - Sygnał wymaga flagi is_ambiguous=true. Noise level: ${input.metadata.semantic_noise_level.toFixed(2)}`
@@ -1,6 +1,14 @@
 /**
  * @fileoverview Benchmark Rust/Wasm vs TypeScript
  * @cybernetic Pomiar wydajności dla różnych rozmiarów grafów
+ *
+ * Czas to mediana z `iterations` powtórzeń; pamięć to przyrost sterty JS na
+ * jedno wywołanie (Node: process.memoryUsage). Dokładną liczbę alokacji
+ * rdzenia (przed/po zmianach BFS) mierzy natywny
+ * `cargo run --release --example path_search_bench` - wyniki w BENCHMARK-RESULTS.md.
+ *
+ * Przed/po w Wasm: benchmarkBeforeAfter porównuje bieżący `pkg/` z buildem
+ * rdzenia sprzed zmian BFS w `pkg-baseline/` (instrukcja w BENCHMARK-RESULTS.md).
  */
 
 import type { CyberneticObject, Correlation, SteeringGoal } from '../decisions/types';
@@ -14,14 +22,40 @@ import { findInfluencePathsWasm, isWasmAvailable } from './bridge';
 export interface BenchmarkResult {
   object_count: number;
   correlation_count: number;
-  typescript_time_ms: number;
-  wasm_time_ms: number | null;
+  iterations: number;
+  typescript_time_ms: number;         // Mediana
+  wasm_time_ms: number | null;        // Mediana
+  typescript_heap_bytes: number | null; // Przyrost sterty JS na wywołanie
+  wasm_heap_bytes: number | null;
   speedup: number | null;
   results_match: boolean;
   wasm_available: boolean;
   error?: string;
 }
 
+export interface BeforeAfterResult {
+  object_count: number;
+  correlation_count: number;
+  iterations: number;
+  before_time_ms: number;             // Mediana, pkg-baseline
+  after_time_ms: number;              // Mediana, pkg
+  time_reduction: number;             // 1 - po / przed
+  before_memory_bytes: number | null; // Pamięć liniowa Wasm po pomiarze
+  after_memory_bytes: number | null;
+  results_match: boolean;
+}
+
+/** Moduł wasm-pack (--target web) z funkcją BFS */
+interface WasmBuild {
+  wasm_find_influence_paths(
+    objectsJson: string,
+    correlationsJson: string,
+    targetId: string,
+    goal: string
+  ): string;
+  memory: WebAssembly.Memory | null;
+}
+
 export interface BenchmarkReport {
   timestamp: string;
   wasm_available: boolean;
@@ -106,6 +140,44 @@ export function generateTestGraph(
   return [objects, correlations];
 }
 
+// ============================================================================
+// POMIAR
+// ============================================================================
+
+/** Zajęta sterta JS (null poza Node) */
+function heapUsed(): number | null {
+  const proc = (globalThis as any).process;
+  return proc?.memoryUsage ? proc.memoryUsage().heapUsed : null;
+}
+
+/**
+ * @cybernetic Mediana czasu i średni przyrost sterty z `iterations` wywołań
+ */
+async function measure<T>(
+  iterations: number,
+  run: () => T | Promise<T>
+): Promise<{ result: T; median_ms: number; heap_bytes: number | null }> {
+  const times: number[] = [];
+  const heapBefore = heapUsed();
+  let result!: T;
+
+  for (let i = 0; i < iterations; i++) {
+    const start = performance.now();
+    result = await run();
+    times.push(performance.now() - start);
+  }
+
+  const heapAfter = heapUsed();
+  times.sort((a, b) => a - b);
+  return {
+    result,
+    median_ms: times[Math.floor(iterations / 2)],
+    heap_bytes: heapBefore !== null && heapAfter !== null
+      ? Math.max(0, heapAfter - heapBefore) / iterations
+      : null,
+  };
+}
+
 // ============================================================================
 // BENCHMARK
 // ============================================================================
@@ -115,11 +187,13 @@ export function generateTestGraph(
  *
  * @param objectCount - Liczba obiektów
  * @param goal - Cel sterowania
+ * @param iterations - Liczba powtórzeń (mediana czasu)
  * @returns Wynik benchmarku
  */
 export async function benchmarkGraphSize(
   objectCount: number,
-  goal: SteeringGoal = 'strengthen'
+  goal: SteeringGoal = 'strengthen',
+  iterations: number = 10
 ): Promise<BenchmarkResult> {
   console.log(`\n[BENCHMARK] Testowanie grafu z ${objectCount} obiektami...`);
 
@@ -133,8 +207,11 @@ export async function benchmarkGraphSize(
   const result: BenchmarkResult = {
     object_count: objectCount,
     correlation_count: correlations.length,
+    iterations,
     typescript_time_ms: 0,
     wasm_time_ms: null,
+    typescript_heap_bytes: null,
+    wasm_heap_bytes: null,
     speedup: null,
     results_match: false,
     wasm_available: false,
@@ -145,18 +222,19 @@ export async function benchmarkGraphSize(
     // TEST 1: TypeScript
     // ========================================
     console.log('[BENCHMARK] Running TypeScript...');
-    const tsStart = performance.now();
 
     // Bezpośrednie wywołanie core logic (bez bazy danych)
-    const graph = buildGraph(objects, correlations);
-    const paths = findInfluencePaths(graph, targetId, goal);
-    const tsResult = calculateNodeInfluences(paths, graph);
-    tsResult.sort((a, b) => b.control_leverage - a.control_leverage);
-
-    const tsEnd = performance.now();
-    result.typescript_time_ms = tsEnd - tsStart;
+    const ts = await measure(iterations, () => {
+      const graph = buildGraph(objects, correlations);
+      const paths = findInfluencePaths(graph, targetId, goal);
+      const nodes = calculateNodeInfluences(paths, graph);
+      return nodes.sort((a, b) => b.control_leverage - a.control_leverage);
+    });
+    const tsResult = ts.result;
+    result.typescript_time_ms = ts.median_ms;
+    result.typescript_heap_bytes = ts.heap_bytes;
 
-    console.log(`[BENCHMARK] ✓ TypeScript: ${result.typescript_time_ms.toFixed(2)}ms`);
+    console.log(`[BENCHMARK] ✓ TypeScript: ${result.typescript_time_ms.toFixed(2)}ms (mediana z ${iterations})`);
     console.log(`[BENCHMARK]   Wpływowych węzłów: ${tsResult.length}`);
 
     // ========================================
@@ -167,12 +245,14 @@ export async function benchmarkGraphSize(
 
     if (wasmAvailable) {
       console.log('[BENCHMARK] Running Wasm...');
-      const wasmStart = performance.now();
-      const wasmResult = await findInfluencePathsWasm(objects, correlations, targetId, goal);
-      const wasmEnd = performance.now();
-      result.wasm_time_ms = wasmEnd - wasmStart;
-
-      console.log(`[BENCHMARK] ✓ Wasm: ${result.wasm_time_ms.toFixed(2)}ms`);
+      const wasm = await measure(iterations, () =>
+        findInfluencePathsWasm(objects, correlations, targetId, goal)
+      );
+      const wasmResult = wasm.result;
+      result.wasm_time_ms = wasm.median_ms;
+      result.wasm_heap_bytes = wasm.heap_bytes;
+
+      console.log(`[BENCHMARK] ✓ Wasm: ${result.wasm_time_ms.toFixed(2)}ms (mediana z ${iterations})`);
       console.log(`[BENCHMARK]   Wpływowych węzłów: ${wasmResult.length}`);
 
       // Oblicz speedup
@@ -258,6 +338,75 @@ export async function runFullBenchmark(
   return report;
 }
 
+// ============================================================================
+// PRZED / PO (BFS RDZENIA)
+// ============================================================================
+
+/**
+ * @cybernetic Ładuje build wasm-pack i zwraca funkcję BFS + pamięć liniową
+ */
+async function loadWasmBuild(modulePath: string): Promise<WasmBuild> {
+  const module = await import(/* @vite-ignore */ modulePath);
+  const exports = await module.default();
+  return {
+    wasm_find_influence_paths: module.wasm_find_influence_paths,
+    memory: exports?.memory ?? null,
+  };
+}
+
+/**
+ * @cybernetic Porównuje rdzeń przed i po zmianach BFS na tych samych grafach
+ *
+ * Oba buildy dostają te same, raz zserializowane dane - mierzony jest tylko
+ * rdzeń (parsowanie + BFS + ranking). Pamięć to rozmiar pamięci liniowej
+ * Wasm po pomiarze (rośnie tylko, więc pokazuje szczyt).
+ *
+ * @param sizes - Rozmiary grafów
+ * @param baselinePath - Build sprzed zmian (domyślnie ./pkg-baseline)
+ * @param iterations - Liczba powtórzeń (mediana czasu)
+ */
+export async function benchmarkBeforeAfter(
+  sizes: number[] = [50, 100, 200, 500],
+  baselinePath: string = './pkg-baseline/wasm_core.js',
+  iterations: number = 20
+): Promise<BeforeAfterResult[]> {
+  console.log('\n' + '='.repeat(60));
+  console.log('BENCHMARK RDZENIA: PRZED / PO');
+  console.log('='.repeat(60));
+
+  const before = await loadWasmBuild(baselinePath);
+  const after = await loadWasmBuild('./pkg/wasm_core.js');
+  const results: BeforeAfterResult[] = [];
+
+  for (const size of sizes) {
+    const [objects, correlations] = generateTestGraph(size, 0.3);
+    const objectsJson = JSON.stringify(objects);
+    const correlationsJson = JSON.stringify(correlations);
+    const targetId = objects[0].id;
+
+    const run = (build: WasmBuild) => () =>
+      build.wasm_find_influence_paths(objectsJson, correlationsJson, targetId, 'strengthen');
+
+    const beforeRun = await measure(iterations, run(before));
+    const afterRun = await measure(iterations, run(after));
+
+    results.push({
+      object_count: objects.length,
+      correlation_count: correlations.length,
+      iterations,
+      before_time_ms: beforeRun.median_ms,
+      after_time_ms: afterRun.median_ms,
+      time_reduction: 1 - afterRun.median_ms / beforeRun.median_ms,
+      before_memory_bytes: before.memory?.buffer.byteLength ?? null,
+      after_memory_bytes: after.memory?.buffer.byteLength ?? null,
+      results_match: compareResults(JSON.parse(beforeRun.result), JSON.parse(afterRun.result)),
+    });
+  }
+
+  printBeforeAfterSummary(results);
+  return results;
+}
+
 // ============================================================================
 // FUNKCJE POMOCNICZE
 // ============================================================================
@@ -314,7 +463,7 @@ function printBenchmarkSummary(report: BenchmarkReport): void {
 
   console.log('\n📊 WYNIKI:');
   console.log('─'.repeat(60));
-  console.log('Rozmiar | TS (ms) | Wasm (ms) | Speedup | Zgodność');
+  console.log('Rozmiar | TS (ms) | Wasm (ms) | Speedup | Zgodność | Sterta TS / Wasm (KB)');
   console.log('─'.repeat(60));
 
   for (const result of report.results) {
@@ -323,8 +472,10 @@ function printBenchmarkSummary(report: BenchmarkReport): void {
     const wasmTime = result.wasm_time_ms?.toFixed(2).padStart(9) || '     N/A';
     const speedup = result.speedup?.toFixed(2).padStart(7) + 'x' || '    N/A';
     const match = result.results_match ? '   ✅' : (result.wasm_available ? '   ❌' : '    -');
+    const kb = (bytes: number | null) => (bytes === null ? 'N/A' : (bytes / 1024).toFixed(1));
+    const heap = `${kb(result.typescript_heap_bytes)} / ${kb(result.wasm_heap_bytes)}`;
 
-    console.log(`${size} | ${tsTime} | ${wasmTime} | ${speedup} | ${match}`);
+    console.log(`${size} | ${tsTime} | ${wasmTime} | ${speedup} | ${match}      | ${heap}`);
   }
 
   if (report.wasm_available) {
@@ -354,6 +505,29 @@ function printBenchmarkSummary(report: BenchmarkReport): void {
   console.log('='.repeat(60) + '\n');
 }
 
+/**
+ * @cybernetic Wyświetla porównanie przed/po
+ */
+function printBeforeAfterSummary(results: BeforeAfterResult[]): void {
+  const kb = (bytes: number | null) => (bytes === null ? 'N/A' : (bytes / 1024).toFixed(0));
+
+  console.log('\n📊 PRZED / PO:');
+  console.log('─'.repeat(60));
+  console.log('Rozmiar | Przed (ms) | Po (ms) | Redukcja | Zgodność | Pamięć Wasm przed / po (KB)');
+  console.log('─'.repeat(60));
+
+  for (const result of results) {
+    const size = result.object_count.toString().padEnd(7);
+    const beforeTime = result.before_time_ms.toFixed(2).padStart(10);
+    const afterTime = result.after_time_ms.toFixed(2).padStart(7);
+    const reduction = `${(result.time_reduction * 100).toFixed(0)}%`.padStart(8);
+    const match = result.results_match ? '   ✅' : '   ❌';
+    const memory = `${kb(result.before_memory_bytes)} / ${kb(result.after_memory_bytes)}`;
+
+    console.log(`${size} | ${beforeTime} | ${afterTime} | ${reduction} | ${match}      | ${memory}`);
+  }
+}
+
 // ============================================================================
 // EXPORT
 // ============================================================================
@@ -361,5 +535,6 @@ function printBenchmarkSummary(report: BenchmarkReport): void {
 export default {
   generateTestGraph,
   benchmarkGraphSize,
+  benchmarkBeforeAfter,
   runFullBenchmark,
 };
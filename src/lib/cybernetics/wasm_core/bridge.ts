@@ -7,7 +7,8 @@
  */
 
 import type { CyberneticObject, Correlation } from '../../supabase/types';
-import type { InfluentialNode, SteeringGoal } from '../decisions/types';
+import type { InfluentialNode, SteeringGoal, SteeringSimulationResult } from '../decisions/types';
+import type { RecommendationLanguage } from '../decisions/pathfinder-core';
 
 // ============================================================================
 // TYPY
@@ -20,6 +21,13 @@ interface WasmModule {
     targetId: string,
     goal: string
   ): string;
+  wasm_recommend(
+    objectsJson: string,
+    correlationsJson: string,
+    targetId: string,
+    goal: string,
+    language: string
+  ): string;
 }
 
 // ============================================================================
@@ -116,6 +124,55 @@ export async function findInfluencePathsWasm(
   }
 }
 
+/**
+ * @cybernetic Pełna symulacja sterowania w Rust (`wasm_recommend`) z fallback
+ *
+ * Rekomendacje, ostrzeżenia i uzasadnienie (pl/en) generuje rdzeń - TS tylko
+ * parsuje wynik. Jeśli Wasm fail → simulateSteeringTS (te same szablony).
+ */
+export async function recommendWasm(
+  objects: CyberneticObject[],
+  correlations: Correlation[],
+  targetId: string,
+  goal: SteeringGoal,
+  language: RecommendationLanguage = 'pl'
+): Promise<SteeringSimulationResult> {
+  try {
+    await loadWasmModule();
+
+    if (!wasmModule) {
+      throw new Error('Wasm module not loaded');
+    }
+
+    const startTime = performance.now();
+    const resultJson = wasmModule.wasm_recommend(
+      JSON.stringify(objects),
+      JSON.stringify(correlations),
+      targetId,
+      goal,
+      language
+    );
+    const endTime = performance.now();
+
+    const result: SteeringSimulationResult = JSON.parse(resultJson);
+
+    console.log(`[WASM] Recommendation completed in ${(endTime - startTime).toFixed(2)}ms`);
+    console.log(`[WASM] Primary: ${result.primary_recommendation.object_name}`);
+
+    return result;
+  } catch (error) {
+    // Moduł działa, a Rust odrzucił dane (np. brak celu) - fallback dałby ten sam błąd
+    if (wasmModule) {
+      throw error instanceof Error ? error : new Error(String(error));
+    }
+
+    console.warn('[WASM] Fallback to TypeScript recommendation due to error:', error);
+
+    const { simulateSteeringTS } = await import('./fallback');
+    return simulateSteeringTS(objects, correlations, targetId, goal, language);
+  }
+}
+
 /**
  * @cybernetic Sprawdza czy Wasm jest dostępny
  */
@@ -179,6 +236,7 @@ export async function calculateTotalPowerWasm(
  */
 export default {
   findInfluencePathsWasm,
+  recommendWasm,
   isWasmAvailable,
   calculateTotalPowerWasm,
 };
@@ -0,0 +1,171 @@
+//! @fileoverview Natywny benchmark przeszukiwania ścieżek wpływu
+//! @cybernetic Czas i liczba alokacji BFS - porównanie przed/po zmianach rdzenia
+//!
+//! Grafy generowane jak w benchmark.ts (`generateTestGraph`), ale z
+//! deterministycznym ziarnem, więc ten sam program uruchomiony na dwóch
+//! wersjach rdzenia mierzy dokładnie te same dane. Alokacje liczy
+//! opakowanie alokatora systemowego.
+//!
+//! Uruchomienie: `cargo run --release --example path_search_bench`
+//! Wyniki: BENCHMARK-RESULTS.md
+
+use serde_json::json;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+use wasm_core::{Correlation, CyberneticObject, Graph, SteeringGoal};
+
+// ============================================================================
+// LICZNIK ALOKACJI
+// ============================================================================
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// ============================================================================
+// GRAF TESTOWY
+// ============================================================================
+
+/// Generator liniowy kongruencyjny - powtarzalne grafy bez zależności
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> f64 {
+        self.0 = self.0.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Odpowiednik `generateTestGraph(objectCount, connectionProbability)` z benchmark.ts
+fn test_graph(object_count: usize, connection_probability: f64, seed: u64) -> Graph {
+    let mut rng = Lcg(seed);
+    let relation_types = ["direct_control", "positive_feedback", "negative_feedback", "supply"];
+
+    let objects: Vec<CyberneticObject> = (0..object_count)
+        .map(|i| {
+            let power = (rng.next() * 950.0).floor() + 50.0;
+            let idle = (power * 0.2).floor();
+            serde_json::from_value(json!({
+                "id": format!("node_{}", i),
+                "name": format!("Node {}", i),
+                "description": format!("Test node {}", i),
+                "system_class": if i % 3 == 0 { "autonomous_system" } else { "environment" },
+                "control_system_type": if i % 2 == 0 { "ethical" } else { "economic" },
+                "energy_params": { "working_power": power, "idle_power": idle, "available_power": power - idle },
+                "power_v": 1.0,
+                "quality_a": 1.0,
+                "mass_c": 1.0,
+                "created_at": "2024-01-01T00:00:00Z",
+            }))
+            .expect("valid object")
+        })
+        .collect();
+
+    let mut correlations: Vec<Correlation> = Vec::new();
+    for i in 0..object_count {
+        for j in 0..object_count {
+            if i == j || rng.next() >= connection_probability {
+                continue;
+            }
+            let relation_type = relation_types[(rng.next() * relation_types.len() as f64) as usize];
+            correlations.push(
+                serde_json::from_value(json!({
+                    "id": format!("corr_{}_{}", i, j),
+                    "source_id": format!("node_{}", i),
+                    "target_id": format!("node_{}", j),
+                    "relation_type": relation_type,
+                    "certainty_score": rng.next() * 0.3 + 0.7,
+                    "impact_factor": rng.next() * 0.4 + 0.6,
+                    "source_name": format!("Test correlation {}->{}", i, j),
+                    "superseded_at": null,
+                    "superseded_by": null,
+                    "created_at": "2024-01-01T00:00:00Z",
+                }))
+                .expect("valid correlation"),
+            );
+        }
+    }
+
+    Graph::new(objects, correlations)
+}
+
+// ============================================================================
+// POMIAR
+// ============================================================================
+
+/// Mediana czasu [ms] oraz alokacje i bajty na jedno wywołanie
+fn measure(runs: usize, mut work: impl FnMut() -> usize) -> (f64, usize, usize, usize) {
+    let mut times = Vec::with_capacity(runs);
+    let mut output = 0;
+    let (allocations, bytes) = (ALLOCATIONS.load(Ordering::Relaxed), ALLOCATED_BYTES.load(Ordering::Relaxed));
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        output = std::hint::black_box(work());
+        times.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / runs;
+    let bytes = (ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes) / runs;
+    times.sort_by(f64::total_cmp);
+    (times[runs / 2], allocations, bytes, output)
+}
+
+fn main() {
+    let goal = SteeringGoal::Strengthen;
+    println!("| Scenariusz | Obiekty | Relacje | Wynik | Mediana [ms] | Alokacje / wywołanie | Bajty / wywołanie |");
+    println!("|---|---:|---:|---:|---:|---:|---:|");
+
+    let report = |name: &str, graph: &Graph, runs: usize, work: &mut dyn FnMut(&Graph) -> usize| {
+        let (millis, allocations, bytes, output) = measure(runs, || work(graph));
+        println!(
+            "| {} | {} | {} | {} | {:.3} | {} | {} |",
+            name,
+            graph.objects().count(),
+            graph.correlations().len(),
+            output,
+            millis,
+            allocations,
+            bytes
+        );
+    };
+
+    // Ranking z limitem MAX_PATHS - gęste grafy jak w benchmark.ts (p = 0.3)
+    for size in [50, 100, 200, 500] {
+        let graph = test_graph(size, 0.3, size as u64);
+        report("rank_influential_nodes", &graph, 50, &mut |g: &Graph| {
+            g.rank_influential_nodes("node_0", &goal).len()
+        });
+    }
+
+    // Pełne wyliczenie bez limitu - rzadkie grafy (średnio ~4 relacje wychodzące)
+    for size in [200, 1000] {
+        let graph = test_graph(size, 4.0 / size as f64, 7 * size as u64);
+        report("influence_path_search (bez limitu)", &graph, 10, &mut |g: &Graph| {
+            g.influence_path_search("node_0", &goal).count()
+        });
+    }
+}
@@ -7,8 +7,14 @@
  */
 
 import type { CyberneticObject, Correlation } from '../../supabase/types';
-import type { InfluentialNode, SteeringGoal } from '../decisions/types';
-import { buildGraph, findInfluencePaths, calculateNodeInfluences } from '../decisions/pathfinder-core';
+import type { InfluentialNode, SteeringGoal, SteeringSimulationResult } from '../decisions/types';
+import type { RecommendationLanguage } from '../decisions/pathfinder-core';
+import {
+  buildGraph,
+  findInfluencePaths,
+  calculateNodeInfluences,
+  simulateSteeringLocal,
+} from '../decisions/pathfinder-core';
 
 // ============================================================================
 // FALLBACK IMPLEMENTATION
@@ -75,9 +81,39 @@ export async function findInfluencePathsTS(
   }
 }
 
+/**
+ * @cybernetic Fallback na TypeScript dla wasm_recommend
+ *
+ * Rekomendacje z tych samych szablonów co rdzeń Rust (recommendation_templates.json).
+ *
+ * @param objects - Tablica obiektów cybernetycznych
+ * @param correlations - Tablica relacji
+ * @param targetId - ID obiektu docelowego
+ * @param goal - Cel sterowania (strengthen/weaken)
+ * @param language - Język uzasadnień i ostrzeżeń
+ * @returns Wynik symulacji z rekomendacjami
+ */
+export async function simulateSteeringTS(
+  objects: CyberneticObject[],
+  correlations: Correlation[],
+  targetId: string,
+  goal: SteeringGoal,
+  language: RecommendationLanguage = 'pl'
+): Promise<SteeringSimulationResult> {
+  console.log('[FALLBACK] Using TypeScript recommendation');
+
+  const result = simulateSteeringLocal(objects, correlations, targetId, goal, language);
+
+  console.log(`[FALLBACK] ✓ Completed in ${result.simulation_metadata.computation_time_ms.toFixed(2)}ms`);
+  console.log(`[FALLBACK] Primary: ${result.primary_recommendation.object_name}`);
+
+  return result;
+}
+
 /**
  * @cybernetic Export
  */
 export default {
   findInfluencePathsTS,
+  simulateSteeringTS,
 };
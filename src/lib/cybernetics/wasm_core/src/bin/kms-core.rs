@@ -0,0 +1,625 @@
+//! @fileoverview kms-core - natywne CLI rdzenia dla zadań wsadowych
+//! @cybernetic Te same algorytmy co w Wasm, bez Node: cron, CI, regresje
+//!
+//! Graf wczytujemy z eksportu JSON (osobne pliki obiektów i relacji albo
+//! jeden plik `{objects, correlations}`) lub ze snapshotu binarnego.
+//! Wynik to tabela dla człowieka albo JSON identyczny z wynikiem
+//! odpowiadającej funkcji `wasm_*` (influence - wasm_find_influence_paths,
+//! contradictions - wasm_detect_contradictions, metrics - wasm_calculate_centrality,
+//! loops - wasm_strongly_connected_components, export - wasm_export_graph);
+//! tylko jawne `--top` / `--sort` przycinają lub przestawiają tę samą listę.
+//!
+//! Kody wyjścia: 0 - sukces, 1 - błąd danych / wykonania, 2 - błędne wywołanie.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+use wasm_core::contradictions::ContradictionConfig;
+use wasm_core::interchange::export::{ExportFormat, ExportOverlays};
+use wasm_core::metrics::{CentralityConfig, NodeCentrality};
+use wasm_core::{Correlation, CyberneticObject, Graph, SteeringGoal};
+
+const USAGE: &str = "\
+kms-core - analiza grafu sterowania KMS
+
+UŻYCIE:
+    kms-core <KOMENDA> [OPCJE]
+
+KOMENDY:
+    influence        Ranking dźwigni sterowania dla celu (--target)
+    contradictions   Sprzeczności między relacjami tej samej pary obiektów
+    metrics          Centralność: PageRank, pośrednictwo, Katz
+    loops            Pętle sprzężeń (silnie spójne składowe)
+    export <FORMAT>  Eksport do graphml | gexf | dot
+
+WEJŚCIE (jedno z):
+    --objects <PLIK> --correlations <PLIK>   Tablice JSON ('-' = stdin, tylko dla jednego)
+    --graph <PLIK>                           JSON {\"objects\": [...], \"correlations\": [...]}
+    --snapshot <PLIK>                        Snapshot binarny (wasm_save_snapshot)
+
+OPCJE:
+    --format <table|json>   Format wyniku (domyślnie table)
+    --target <ID>           Obiekt docelowy (influence; nakładka dźwigni w export)
+    --goal <strengthen|weaken>
+    --top <N>               Tylko N pierwszych wierszy (influence, metrics)
+    --sort <MIARA>          metrics: pagerank_in | pagerank_out | betweenness | katz_in | katz_out
+                            (tabela domyślnie po pagerank_in, JSON w kolejności Wasm)
+    --config <PLIK>         contradictions: ContradictionConfig w JSON
+    --path <ID,ID,...>      export: wyróżniona ścieżka
+    --out <PLIK>            Zapis wyniku do pliku zamiast stdout
+    -h, --help              Ta pomoc
+";
+
+/// Miary dostępne w `--sort`
+const METRICS: &[&str] = &["pagerank_in", "pagerank_out", "betweenness", "katz_in", "katz_out"];
+
+// ============================================================================
+// ARGUMENTY
+// ============================================================================
+
+/// @cybernetic Komenda CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Influence,
+    Contradictions,
+    Metrics,
+    Loops,
+    Export(ExportFormat),
+}
+
+/// @cybernetic Format wyniku
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Table,
+    Json,
+}
+
+/// @cybernetic Sparsowane wywołanie
+#[derive(Debug)]
+struct Args {
+    command: Command,
+    objects: Option<String>,
+    correlations: Option<String>,
+    graph: Option<String>,
+    snapshot: Option<String>,
+    format: OutputFormat,
+    target: Option<String>,
+    goal: Option<SteeringGoal>,
+    top: Option<usize>,
+    sort: Option<String>,
+    config: Option<String>,
+    path: Vec<String>,
+    out: Option<String>,
+}
+
+/// Błąd wywołania (kod 2) lub wykonania (kod 1)
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Failed(String),
+}
+
+fn usage(message: impl Into<String>) -> CliError {
+    CliError::Usage(message.into())
+}
+
+fn failed(message: impl Into<String>) -> CliError {
+    CliError::Failed(message.into())
+}
+
+fn parse_goal(value: &str) -> Result<SteeringGoal, CliError> {
+    match value {
+        "strengthen" => Ok(SteeringGoal::Strengthen),
+        "weaken" => Ok(SteeringGoal::Weaken),
+        _ => Err(usage("Invalid goal: must be 'strengthen' or 'weaken'")),
+    }
+}
+
+/// Parsuje argumenty (bez nazwy programu); `Ok(None)` = pomoc
+fn parse_args(raw: &[String]) -> Result<Option<Args>, CliError> {
+    if raw.is_empty() || raw.iter().any(|a| a == "-h" || a == "--help") {
+        return Ok(None);
+    }
+
+    let mut rest = raw.iter();
+    let command = match rest.next().map(String::as_str) {
+        Some("influence") => Command::Influence,
+        Some("contradictions") => Command::Contradictions,
+        Some("metrics") => Command::Metrics,
+        Some("loops") => Command::Loops,
+        Some("export") => {
+            let name = rest.next().ok_or_else(|| usage("export requires a format: graphml | gexf | dot"))?;
+            Command::Export(ExportFormat::parse(name).ok_or_else(|| usage(format!("Unknown export format '{}'", name)))?)
+        }
+        Some(other) => return Err(usage(format!("Unknown command '{}'", other))),
+        None => unreachable!(),
+    };
+
+    let mut args = Args {
+        command,
+        objects: None,
+        correlations: None,
+        graph: None,
+        snapshot: None,
+        format: OutputFormat::Table,
+        target: None,
+        goal: None,
+        top: None,
+        sort: None,
+        config: None,
+        path: Vec::new(),
+        out: None,
+    };
+
+    while let Some(flag) = rest.next() {
+        let mut value = || {
+            rest.next()
+                .cloned()
+                .ok_or_else(|| usage(format!("Option {} requires a value", flag)))
+        };
+        match flag.as_str() {
+            "--objects" => args.objects = Some(value()?),
+            "--correlations" => args.correlations = Some(value()?),
+            "--graph" => args.graph = Some(value()?),
+            "--snapshot" => args.snapshot = Some(value()?),
+            "--format" => {
+                args.format = match value()?.as_str() {
+                    "table" => OutputFormat::Table,
+                    "json" => OutputFormat::Json,
+                    other => return Err(usage(format!("Unknown output format '{}'", other))),
+                }
+            }
+            "--target" => args.target = Some(value()?),
+            "--goal" => args.goal = Some(parse_goal(&value()?)?),
+            "--top" => {
+                let raw = value()?;
+                args.top = Some(raw.parse().map_err(|_| usage(format!("Invalid --top value '{}'", raw)))?);
+            }
+            "--sort" => {
+                let metric = value()?;
+                if !METRICS.contains(&metric.as_str()) {
+                    return Err(usage(format!("Unknown metric '{}'", metric)));
+                }
+                args.sort = Some(metric);
+            }
+            "--config" => args.config = Some(value()?),
+            "--path" => args.path = value()?.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect(),
+            "--out" => args.out = Some(value()?),
+            other => return Err(usage(format!("Unknown option '{}'", other))),
+        }
+    }
+
+    let sources = [args.graph.is_some(), args.snapshot.is_some(), args.objects.is_some() || args.correlations.is_some()];
+    match sources.iter().filter(|s| **s).count() {
+        0 => return Err(usage("No input: use --objects/--correlations, --graph or --snapshot")),
+        1 => {}
+        _ => return Err(usage("Use only one input: --objects/--correlations, --graph or --snapshot")),
+    }
+    if args.objects.is_some() != args.correlations.is_some() {
+        return Err(usage("--objects and --correlations must be given together"));
+    }
+    // Stdin da się przeczytać tylko raz - drugi odczyt dałby pustą tablicę
+    if args.objects.as_deref() == Some("-") && args.correlations.as_deref() == Some("-") {
+        return Err(usage("Only one of --objects and --correlations can read from stdin ('-')"));
+    }
+    if args.command == Command::Influence && args.target.is_none() {
+        return Err(usage("influence requires --target"));
+    }
+    Ok(Some(args))
+}
+
+// ============================================================================
+// WEJŚCIE / WYJŚCIE
+// ============================================================================
+
+fn read_bytes(path: &str) -> Result<Vec<u8>, CliError> {
+    if path == "-" {
+        let mut buffer = Vec::new();
+        io::stdin()
+            .read_to_end(&mut buffer)
+            .map_err(|e| failed(format!("Failed to read stdin: {}", e)))?;
+        return Ok(buffer);
+    }
+    fs::read(path).map_err(|e| failed(format!("Failed to read {}: {}", path, e)))
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &str, what: &str) -> Result<T, CliError> {
+    serde_json::from_slice(&read_bytes(path)?).map_err(|e| failed(format!("Failed to parse {}: {}", what, e)))
+}
+
+/// Plik `--graph`: ten sam kształt co wynik wasm_load_snapshot
+#[derive(Deserialize)]
+struct GraphFile {
+    objects: Vec<CyberneticObject>,
+    correlations: Vec<Correlation>,
+}
+
+fn load_graph(args: &Args) -> Result<Graph, CliError> {
+    if let Some(path) = &args.snapshot {
+        return Graph::from_snapshot(&read_bytes(path)?).map_err(|e| failed(format!("Failed to load snapshot: {}", e)));
+    }
+    if let Some(path) = &args.graph {
+        let file: GraphFile = read_json(path, "graph")?;
+        return Ok(Graph::new(file.objects, file.correlations));
+    }
+    let objects = read_json(args.objects.as_deref().unwrap_or("-"), "objects")?;
+    let correlations = read_json(args.correlations.as_deref().unwrap_or("-"), "correlations")?;
+    Ok(Graph::new(objects, correlations))
+}
+
+fn write_output(args: &Args, text: &str) -> Result<(), CliError> {
+    match &args.out {
+        Some(path) => fs::write(path, text).map_err(|e| failed(format!("Failed to write {}: {}", path, e))),
+        None => io::stdout()
+            .write_all(text.as_bytes())
+            .map_err(|e| failed(format!("Failed to write output: {}", e))),
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, CliError> {
+    serde_json::to_string_pretty(value)
+        .map(|s| s + "\n")
+        .map_err(|e| failed(format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic Prosta tabela tekstowa z wyrównanymi kolumnami
+struct Table {
+    header: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn new(header: Vec<&'static str>) -> Self {
+        Table { header, rows: Vec::new() }
+    }
+
+    fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    fn render(&self) -> String {
+        let mut widths: Vec<usize> = self.header.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |cells: Vec<&str>| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{}{}", cell, " ".repeat(width - cell.chars().count())))
+                .collect();
+            padded.join("  ").trim_end().to_string() + "\n"
+        };
+
+        let mut out = line(self.header.clone());
+        out += &line(widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>().iter().map(String::as_str).collect());
+        for row in &self.rows {
+            out += &line(row.iter().map(String::as_str).collect());
+        }
+        out
+    }
+}
+
+// ============================================================================
+// KOMENDY
+// ============================================================================
+
+fn influence(graph: &Graph, args: &Args) -> Result<String, CliError> {
+    let target = args.target.as_deref().unwrap_or_default();
+    if graph.object(target).is_none() {
+        return Err(failed(format!("Unknown target '{}'", target)));
+    }
+    let mut ranking = graph.rank_influential_nodes(target, &args.goal.clone().unwrap_or(SteeringGoal::Strengthen));
+    ranking.truncate(args.top.unwrap_or(usize::MAX));
+
+    if args.format == OutputFormat::Json {
+        return to_json(&ranking);
+    }
+    let mut table = Table::new(vec!["#", "id", "name", "leverage", "influence", "net", "paths", "certainty"]);
+    for (i, node) in ranking.iter().enumerate() {
+        table.row(vec![
+            (i + 1).to_string(),
+            node.object_id.clone(),
+            node.object_name.clone(),
+            format!("{:.4}", node.control_leverage),
+            format!("{:.4}", node.influence_strength),
+            format!("{:+.4}", node.net_influence),
+            node.path_count.to_string(),
+            format!("{:.2}", node.certainty_score),
+        ]);
+    }
+    Ok(table.render())
+}
+
+fn contradictions(graph: &Graph, args: &Args) -> Result<String, CliError> {
+    let config: ContradictionConfig = match &args.config {
+        Some(path) => read_json(path, "contradiction config")?,
+        None => ContradictionConfig::default(),
+    };
+    let report = graph.detect_contradictions(&config);
+
+    if args.format == OutputFormat::Json {
+        return to_json(&report);
+    }
+    let mut table = Table::new(vec!["type", "severity", "source → target", "existing", "new", "description"]);
+    for c in &report.contradictions {
+        table.row(vec![
+            c.contradiction_type.as_str().to_string(),
+            format!("{:.2}", c.severity),
+            format!("{} → {}", c.source_id, c.target_id),
+            c.existing_relation_id.clone(),
+            c.new_relation_id.clone(),
+            c.description.clone(),
+        ]);
+    }
+    let summary = &report.summary;
+    Ok(format!(
+        "{}\n{} contradictions, max severity {:.2}, recommended action: {}\n",
+        table.render(),
+        summary.total_contradictions,
+        summary.max_severity,
+        serde_json::to_value(summary.recommended_action)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default()
+    ))
+}
+
+fn metric(node: &NodeCentrality, name: &str) -> f64 {
+    match name {
+        "pagerank_out" => node.pagerank_out,
+        "betweenness" => node.betweenness,
+        "katz_in" => node.katz_in,
+        "katz_out" => node.katz_out,
+        _ => node.pagerank_in,
+    }
+}
+
+fn metrics(graph: &Graph, args: &Args) -> Result<String, CliError> {
+    let mut nodes = graph.centrality(&CentralityConfig::default());
+    // JSON bez --sort zachowuje kolejność wasm_calculate_centrality
+    let sort = match (&args.sort, args.format) {
+        (Some(sort), _) => Some(sort.as_str()),
+        (None, OutputFormat::Table) => Some("pagerank_in"),
+        (None, OutputFormat::Json) => None,
+    };
+    if let Some(sort) = sort {
+        // Stabilnie: malejąco po mierze, remisy w kolejności ID
+        nodes.sort_by(|a, b| metric(b, sort).total_cmp(&metric(a, sort)));
+    }
+    nodes.truncate(args.top.unwrap_or(usize::MAX));
+
+    if args.format == OutputFormat::Json {
+        return to_json(&nodes);
+    }
+    let mut table = Table::new(vec!["id", "name", "pagerank_in", "pagerank_out", "betweenness", "katz_in", "katz_out"]);
+    for node in &nodes {
+        table.row(vec![
+            node.object_id.clone(),
+            node.object_name.clone(),
+            format!("{:.4}", node.pagerank_in),
+            format!("{:.4}", node.pagerank_out),
+            format!("{:.4}", node.betweenness),
+            format!("{:.4}", node.katz_in),
+            format!("{:.4}", node.katz_out),
+        ]);
+    }
+    Ok(table.render())
+}
+
+fn loops(graph: &Graph, args: &Args) -> Result<String, CliError> {
+    let report = graph.condensation();
+    if args.format == OutputFormat::Json {
+        return to_json(&report);
+    }
+
+    let cyclic: Vec<_> = report.components.iter().filter(|c| c.is_cyclic).collect();
+    let mut table = Table::new(vec!["#", "size", "cycles", "+fb", "-fb", "mean_weight", "objects"]);
+    for component in &cyclic {
+        let stats = &component.loop_stats;
+        table.row(vec![
+            component.index.to_string(),
+            component.object_ids.len().to_string(),
+            stats.cyclomatic_number.to_string(),
+            stats.positive_feedback_edges.to_string(),
+            stats.negative_feedback_edges.to_string(),
+            format!("{:.4}", stats.mean_weight),
+            component.object_ids.join(", "),
+        ]);
+    }
+    Ok(format!("{}\n{} feedback loops\n", table.render(), cyclic.len()))
+}
+
+fn export(graph: &Graph, args: &Args, format: ExportFormat) -> Result<String, CliError> {
+    let overlays = ExportOverlays {
+        leverage_target: args.target.clone(),
+        goal: args.goal.clone(),
+        highlight_path: args.path.clone(),
+    };
+    Ok(graph.export(format, &overlays))
+}
+
+/// Wczytuje graf i wykonuje komendę - wynik jako tekst
+fn execute(args: &Args) -> Result<String, CliError> {
+    let graph = load_graph(args)?;
+    match args.command {
+        Command::Influence => influence(&graph, args),
+        Command::Contradictions => contradictions(&graph, args),
+        Command::Metrics => metrics(&graph, args),
+        Command::Loops => loops(&graph, args),
+        Command::Export(format) => export(&graph, args, format),
+    }
+}
+
+fn run(args: &Args) -> Result<(), CliError> {
+    write_output(args, &execute(args)?)
+}
+
+fn main() -> ExitCode {
+    let raw: Vec<String> = std::env::args().skip(1).collect();
+    let result = parse_args(&raw).and_then(|args| match args {
+        Some(args) => run(&args),
+        None => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Usage(message)) => {
+            eprintln!("kms-core: {}\n\nUżyj `kms-core --help`, aby zobaczyć opcje.", message);
+            ExitCode::from(2)
+        }
+        Err(CliError::Failed(message)) => {
+            eprintln!("kms-core: {}", message);
+            ExitCode::from(1)
+        }
+    }
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse_args(&argv("influence --graph g.json --target t --goal weaken --top 5 --format json"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(args.command, Command::Influence);
+        assert!(matches!(args.goal, Some(SteeringGoal::Weaken)));
+        assert_eq!((args.top, args.format), (Some(5), OutputFormat::Json));
+
+        let args = parse_args(&argv("export gv --snapshot g.kmsg --path a,b,,c")).unwrap().unwrap();
+        assert_eq!(args.command, Command::Export(ExportFormat::Dot));
+        assert_eq!(args.path, vec!["a", "b", "c"]);
+
+        assert!(parse_args(&argv("loops --objects o.json --help")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        for line in [
+            "frobnicate --graph g.json",
+            "influence --graph g.json",
+            "metrics",
+            "metrics --objects o.json",
+            "metrics --graph g.json --snapshot g.kmsg",
+            "metrics --graph g.json --top many",
+            "metrics --graph g.json --sort degree",
+            "export svg --graph g.json",
+            "loops --graph",
+            "loops --objects - --correlations -",
+        ] {
+            assert!(matches!(parse_args(&argv(line)), Err(CliError::Usage(_))), "{}", line);
+        }
+    }
+
+    /// Pliki z tests/fixtures/kms-core
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/fixtures/kms-core/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    /// Wynik `run()` zapisany przez `--out` do pliku tymczasowego
+    fn run_to_string(line: &str) -> String {
+        static RUNS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let run_id = RUNS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let out = std::env::temp_dir().join(format!("kms-core-{}-{}.out", std::process::id(), run_id));
+        let mut raw = argv(line);
+        raw.extend(["--objects", &fixture("objects.json"), "--correlations", &fixture("correlations.json")].map(String::from));
+        raw.extend(["--out".to_string(), out.display().to_string()]);
+
+        let args = parse_args(&raw).unwrap().unwrap();
+        assert!(run(&args).is_ok(), "{}", line);
+        let text = fs::read_to_string(&out).unwrap();
+        fs::remove_file(&out).unwrap();
+        text
+    }
+
+    fn json(text: &str) -> serde_json::Value {
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_json_output_matches_wasm_entry_points() {
+        let objects = fs::read_to_string(fixture("objects.json")).unwrap();
+        let correlations = fs::read_to_string(fixture("correlations.json")).unwrap();
+        let wasm = |result: Result<String, wasm_bindgen::JsValue>| result.map_err(|_| "wasm call failed").unwrap();
+
+        let cases = [
+            (
+                "influence --target opinia --goal weaken --format json",
+                wasm(wasm_core::wasm_find_influence_paths(&objects, &correlations, "opinia", "weaken")),
+            ),
+            (
+                "contradictions --format json",
+                wasm(wasm_core::contradictions::wasm_detect_contradictions(&objects, &correlations, "")),
+            ),
+            (
+                "metrics --format json",
+                wasm(wasm_core::metrics::wasm_calculate_centrality(&objects, &correlations, "")),
+            ),
+            (
+                "loops --format json",
+                wasm(wasm_core::scc::wasm_strongly_connected_components(&objects, &correlations)),
+            ),
+        ];
+        assert!(json(&cases[1].1)["detected"].as_bool().unwrap());
+        for (line, expected) in cases {
+            let output = run_to_string(line);
+            assert!(!json(&expected).as_array().is_some_and(Vec::is_empty), "{}: empty fixture result", line);
+            assert_eq!(json(&output), json(&expected), "{}", line);
+        }
+
+        let dot = run_to_string("export dot --target opinia");
+        let expected = wasm_core::interchange::export::wasm_export_graph(
+            &objects,
+            &correlations,
+            "dot",
+            r#"{"leverage_target": "opinia"}"#,
+        );
+        assert_eq!(dot, wasm(expected));
+    }
+
+    #[test]
+    fn test_top_and_sort_reshape_library_results() {
+        let graph = Graph::new(
+            serde_json::from_str(&fs::read_to_string(fixture("objects.json")).unwrap()).unwrap(),
+            serde_json::from_str(&fs::read_to_string(fixture("correlations.json")).unwrap()).unwrap(),
+        );
+
+        let ranking = graph.rank_influential_nodes("opinia", &SteeringGoal::Strengthen);
+        let top = run_to_string("influence --target opinia --top 2 --format json");
+        assert_eq!(top, to_json(&ranking[..2].to_vec()).unwrap());
+
+        let nodes = json(&run_to_string("metrics --sort betweenness --format json"));
+        let scores: Vec<f64> = nodes.as_array().unwrap().iter().map(|n| n["betweenness"].as_f64().unwrap()).collect();
+        assert_eq!(scores.len(), graph.centrality(&CentralityConfig::default()).len());
+        assert!(scores.windows(2).all(|w| w[0] >= w[1]));
+
+        // Tabela: nagłówek, separator i wiersz na każdy obiekt z dźwignią
+        let table = run_to_string("influence --target opinia");
+        assert_eq!(table.lines().count(), ranking.len() + 2);
+    }
+
+    #[test]
+    fn test_table_alignment() {
+        let mut table = Table::new(vec!["id", "name"]);
+        table.row(vec!["długi-id".to_string(), "A".to_string()]);
+        table.row(vec!["b".to_string(), String::new()]);
+        assert_eq!(table.render(), "id        name\n--------  ----\ndługi-id  A\nb\n");
+    }
+}
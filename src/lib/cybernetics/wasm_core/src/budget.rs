@@ -0,0 +1,393 @@
+//! @fileoverview Budżet pracy i kooperacyjne anulowanie
+//! @cybernetic Ciężkie obliczenia nie mogą zamrozić wątku głównego przeglądarki
+//!
+//! `WorkBudget` łączy trzy ograniczenia sprawdzane wewnątrz pętli algorytmów:
+//! - maksymalną liczbę rozwiniętych węzłów (`max_expanded_nodes`),
+//! - maksymalny czas w ms mierzony zegarem gospodarza (callback),
+//! - token anulowania (`CancellationToken`) ustawiany z zewnątrz.
+//!
+//! Po wyczerpaniu budżetu algorytm kończy się wcześniej i zwraca wynik
+//! częściowy opakowany w `Budgeted` z `completed: false`.
+//!
+//! Niezależnie od limitów budżet można wstrzymać po porcji rozwinięć
+//! (`pause_after`) - algorytm wznawialny oddaje wtedy sterowanie, a kolejna
+//! porcja liczy się dalej w tym samym budżecie.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use wasm_bindgen::prelude::*;
+
+/// Co ile rozwinięć pytamy zegar gospodarza (wywołanie JS jest kosztowne)
+pub const CLOCK_CHECK_INTERVAL: usize = 64;
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Limity budżetu (None = bez limitu)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BudgetConfig {
+    pub max_expanded_nodes: Option<usize>,
+    pub max_millis: Option<f64>,
+}
+
+/// @cybernetic Token kooperacyjnego anulowania (klony współdzielą flagę)
+///
+/// Wasm jest jednowątkowy: w trakcie synchronicznego wywołania
+/// `wasm_*_budgeted` na wątku głównym nie wykona się żaden handler JS
+/// (przycisk "Anuluj", `onmessage`, timer), więc `cancel()` z UI zadziała
+/// dopiero przy następnym wywołaniu. Jedyny kod gospodarza uruchamiany w
+/// trakcie obliczeń to callback zegara budżetu (co CLOCK_CHECK_INTERVAL
+/// rozwinięć) - tylko z niego można przełączyć token w trakcie wywołania,
+/// np. gdy zegar odczytuje flagę ustawianą przez inny wątek
+/// (SharedArrayBuffer + Atomics).
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::Relaxed);
+    }
+}
+
+#[wasm_bindgen]
+impl CancellationToken {
+    #[wasm_bindgen(constructor)]
+    pub fn wasm_new() -> CancellationToken {
+        CancellationToken::new()
+    }
+
+    #[wasm_bindgen(js_name = cancel)]
+    pub fn wasm_cancel(&self) {
+        self.cancel();
+    }
+
+    #[wasm_bindgen(getter, js_name = isCancelled)]
+    pub fn wasm_is_cancelled(&self) -> bool {
+        self.is_cancelled()
+    }
+
+    #[wasm_bindgen(js_name = reset)]
+    pub fn wasm_reset(&self) {
+        self.reset();
+    }
+}
+
+// ============================================================================
+// WYNIK
+// ============================================================================
+
+/// @cybernetic Powód przerwania obliczeń
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StopReason {
+    NodeLimit,
+    TimeLimit,
+    Cancelled,
+}
+
+/// @cybernetic Stan budżetu po zakończeniu obliczeń
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BudgetOutcome {
+    pub completed: bool,
+    pub stop_reason: Option<StopReason>,
+    pub expanded_nodes: usize,
+    pub elapsed_millis: Option<f64>, // Tylko gdy podano zegar
+}
+
+/// @cybernetic Wynik (być może częściowy) obliczeń z budżetem
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Budgeted<T> {
+    pub result: T,
+    #[serde(flatten)]
+    pub outcome: BudgetOutcome,
+}
+
+// ============================================================================
+// BUDŻET
+// ============================================================================
+
+/// @cybernetic Licznik budżetu przekazywany do pętli algorytmów
+pub struct WorkBudget {
+    config: BudgetConfig,
+    clock: Option<Box<dyn Fn() -> f64>>,
+    started_at: f64,
+    token: Option<CancellationToken>,
+    expanded: usize,
+    stop_reason: Option<StopReason>,
+    pause_at: Option<usize>, // Koniec bieżącej porcji rozwinięć
+}
+
+impl Default for WorkBudget {
+    fn default() -> Self {
+        WorkBudget::unlimited()
+    }
+}
+
+impl WorkBudget {
+    /// Budżet bez limitów (zachowanie sprzed wprowadzenia budżetów)
+    pub fn unlimited() -> Self {
+        WorkBudget::new(BudgetConfig::default())
+    }
+
+    pub fn new(config: BudgetConfig) -> Self {
+        WorkBudget {
+            config,
+            clock: None,
+            started_at: 0.0,
+            token: None,
+            expanded: 0,
+            stop_reason: None,
+            pause_at: None,
+        }
+    }
+
+    /// Zegar gospodarza w ms (np. `performance.now`); start liczony od teraz
+    pub fn with_clock(mut self, clock: impl Fn() -> f64 + 'static) -> Self {
+        self.started_at = clock();
+        self.clock = Some(Box::new(clock));
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// @cybernetic Wstrzymuje budżet po kolejnych `slice` rozwinięciach
+    ///
+    /// Pauza nie jest wyczerpaniem: `tick` zwraca `false`, ale `completed`
+    /// pozostaje prawdą, a następne `pause_after` wyznacza kolejną porcję.
+    pub fn pause_after(&mut self, slice: usize) {
+        self.pause_at = Some(self.expanded + slice);
+    }
+
+    /// Czy budżet stoi na pauzie (porcja zużyta, limity nie przekroczone)
+    pub fn is_paused(&self) -> bool {
+        self.stop_reason.is_none() && self.pause_at.is_some_and(|at| self.expanded >= at)
+    }
+
+    /// @cybernetic Zgoda na rozwinięcie kolejnego węzła
+    ///
+    /// Zwraca `false` (trwale), gdy budżet się wyczerpał - pętla powinna
+    /// zakończyć się z wynikiem częściowym - albo (do następnej porcji),
+    /// gdy budżet stoi na pauzie. Anulowanie i limity wygrywają z pauzą.
+    pub fn tick(&mut self) -> bool {
+        if self.stop_reason.is_some() {
+            return false;
+        }
+
+        if self.token.as_ref().is_some_and(|t| t.is_cancelled()) {
+            self.stop_reason = Some(StopReason::Cancelled);
+        } else if self.config.max_expanded_nodes.is_some_and(|max| self.expanded >= max) {
+            self.stop_reason = Some(StopReason::NodeLimit);
+        } else if self.expanded.is_multiple_of(CLOCK_CHECK_INTERVAL) && self.time_exceeded() {
+            self.stop_reason = Some(StopReason::TimeLimit);
+        }
+
+        if self.stop_reason.is_some() || self.is_paused() {
+            return false;
+        }
+        self.expanded += 1;
+        true
+    }
+
+    fn time_exceeded(&self) -> bool {
+        match (self.config.max_millis, self.elapsed_millis()) {
+            (Some(max), Some(elapsed)) => elapsed >= max,
+            _ => false,
+        }
+    }
+
+    fn elapsed_millis(&self) -> Option<f64> {
+        self.clock.as_ref().map(|clock| clock() - self.started_at)
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.stop_reason.is_some()
+    }
+
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+
+    pub fn outcome(&self) -> BudgetOutcome {
+        BudgetOutcome {
+            completed: self.stop_reason.is_none(),
+            stop_reason: self.stop_reason,
+            expanded_nodes: self.expanded,
+            elapsed_millis: self.elapsed_millis(),
+        }
+    }
+
+    /// Opakowuje wynik stanem budżetu
+    pub fn wrap<T>(&self, result: T) -> Budgeted<T> {
+        Budgeted {
+            result,
+            outcome: self.outcome(),
+        }
+    }
+}
+
+// ============================================================================
+// WASM HELPERS
+// ============================================================================
+
+/// @cybernetic Budżet z JSON + opcjonalny zegar JS + opcjonalny token
+///
+/// `budget_json` pusty = bez limitów. Gdy podano `max_millis`, a nie podano
+/// zegara, używany jest `Date.now`.
+pub(crate) fn budget_from_js(
+    budget_json: &str,
+    clock: Option<js_sys::Function>,
+    token: Option<&CancellationToken>,
+) -> Result<WorkBudget, JsValue> {
+    let config: BudgetConfig = if budget_json.trim().is_empty() {
+        BudgetConfig::default()
+    } else {
+        serde_json::from_str(budget_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse budget: {}", e)))?
+    };
+
+    let mut budget = WorkBudget::new(config);
+    match clock {
+        Some(clock) => {
+            budget = budget.with_clock(move || {
+                clock.call0(&JsValue::NULL).ok().and_then(|v| v.as_f64()).unwrap_or(0.0)
+            })
+        }
+        None if config.max_millis.is_some() => budget = budget.with_clock(js_sys::Date::now),
+        None => {}
+    }
+    if let Some(token) = token {
+        budget = budget.with_cancellation(token.clone());
+    }
+    Ok(budget)
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_node_limit() {
+        let mut budget = WorkBudget::new(BudgetConfig {
+            max_expanded_nodes: Some(3),
+            ..BudgetConfig::default()
+        });
+        assert_eq!((0..10).filter(|_| budget.tick()).count(), 3);
+
+        let outcome = budget.outcome();
+        assert!(!outcome.completed);
+        assert_eq!(outcome.stop_reason, Some(StopReason::NodeLimit));
+        assert_eq!(outcome.expanded_nodes, 3);
+    }
+
+    #[test]
+    fn test_host_clock_time_limit() {
+        let now = Rc::new(Cell::new(100.0));
+        let clock = now.clone();
+        let mut budget = WorkBudget::new(BudgetConfig {
+            max_millis: Some(50.0),
+            ..BudgetConfig::default()
+        })
+        .with_clock(move || clock.get());
+
+        assert!(budget.tick());
+        now.set(200.0);
+        // Zegar sprawdzany co CLOCK_CHECK_INTERVAL rozwinięć
+        let ticks = (0..1000).take_while(|_| budget.tick()).count();
+        assert_eq!(ticks, CLOCK_CHECK_INTERVAL - 1);
+        assert_eq!(budget.outcome().stop_reason, Some(StopReason::TimeLimit));
+        assert_eq!(budget.outcome().elapsed_millis, Some(100.0));
+    }
+
+    #[test]
+    fn test_cancellation_token_shared_between_clones() {
+        let token = CancellationToken::new();
+        let mut budget = WorkBudget::unlimited().with_cancellation(token.clone());
+        assert!(budget.tick());
+
+        token.cancel();
+        assert!(!budget.tick());
+        assert_eq!(budget.outcome().stop_reason, Some(StopReason::Cancelled));
+
+        let json = serde_json::to_value(budget.wrap(vec![1, 2])).unwrap();
+        assert_eq!(json["completed"], false);
+        assert_eq!(json["stop_reason"], "cancelled");
+        assert_eq!(json["result"], serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn test_pause_is_not_exhaustion() {
+        let token = CancellationToken::new();
+        let mut budget = WorkBudget::new(BudgetConfig {
+            max_expanded_nodes: Some(5),
+            ..BudgetConfig::default()
+        })
+        .with_cancellation(token.clone());
+
+        budget.pause_after(2);
+        assert_eq!((0..10).take_while(|_| budget.tick()).count(), 2);
+        assert!(budget.is_paused() && !budget.is_exhausted());
+        assert!(budget.outcome().completed);
+
+        // Kolejna porcja kończy się limitem całego budżetu
+        budget.pause_after(10);
+        assert_eq!((0..10).take_while(|_| budget.tick()).count(), 3);
+        assert_eq!(budget.outcome().stop_reason, Some(StopReason::NodeLimit));
+
+        // Anulowanie w czasie pauzy wygrywa przy następnym tick
+        let mut budget = WorkBudget::unlimited().with_cancellation(token.clone());
+        budget.pause_after(0);
+        token.cancel();
+        assert!(!budget.tick());
+        assert_eq!(budget.outcome().stop_reason, Some(StopReason::Cancelled));
+    }
+
+    #[test]
+    fn test_clock_callback_is_the_only_mid_call_cancellation_point() {
+        // Jak na wątku głównym: w trakcie wywołania działa tylko callback zegara
+        let token = CancellationToken::new();
+        let calls = Rc::new(Cell::new(0));
+        let (flag, counter) = (token.clone(), calls.clone());
+        let mut budget = WorkBudget::unlimited()
+            .with_cancellation(token.clone())
+            .with_clock(move || {
+                counter.set(counter.get() + 1);
+                if counter.get() == 3 {
+                    flag.cancel(); // np. odczyt flagi z SharedArrayBuffer
+                }
+                0.0
+            });
+
+        // Zegar: przy starcie, przy rozwinięciu 0 i 64 - przełączenie widać od następnego
+        let ticks = (0..1000).take_while(|_| budget.tick()).count();
+        assert_eq!(ticks, CLOCK_CHECK_INTERVAL + 1);
+        assert_eq!(calls.get(), 3);
+        assert_eq!(budget.outcome().stop_reason, Some(StopReason::Cancelled));
+    }
+}
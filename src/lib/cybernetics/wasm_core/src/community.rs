@@ -0,0 +1,469 @@
+//! @fileoverview Wykrywanie bloków sterowniczych (społeczności grafu)
+//! @cybernetic Grupy obiektów działające jak jeden system sterowania
+//!
+//! Algorytm Louvain na grafie zsymetryzowanym: A_ij = w_ij + w_ji, gdzie
+//! w = impact_factor × certainty_score. Kierunek nie wpływa na przydział do
+//! bloków (blok = gęste wzajemne powiązania), ale przepływy MIĘDZY blokami
+//! raportujemy zgodnie z kierunkiem relacji.
+//!
+//! Kolejność odwiedzania węzłów = posortowane ID, więc wynik jest
+//! deterministyczny dla tych samych danych.
+//!
+//! Budżet: odwiedzenie węzła w fazie lokalnych przesunięć to jedno
+//! rozwinięcie. Po wyczerpaniu zostaje bieżący (poprawny, choć nie
+//! optymalny) przydział do bloków.
+
+use crate::budget::{budget_from_js, Budgeted, CancellationToken, WorkBudget};
+use crate::indexed::IndexedGraph;
+use crate::{build_graph_from_json, Graph};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use wasm_bindgen::prelude::*;
+
+/// Minimalny przyrost modularności uznawany za poprawę
+const MIN_GAIN: f64 = 1e-12;
+
+// ============================================================================
+// KONFIGURACJA / WYNIK
+// ============================================================================
+
+/// @cybernetic Parametry wykrywania bloków
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommunityConfig {
+    pub resolution: f64, // γ: > 1 mniejsze bloki, < 1 większe
+    pub max_levels: usize,
+}
+
+impl Default for CommunityConfig {
+    fn default() -> Self {
+        CommunityConfig {
+            resolution: 1.0,
+            max_levels: 16,
+        }
+    }
+}
+
+/// @cybernetic Blok sterowniczy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlBloc {
+    pub index: usize,
+    pub object_ids: Vec<String>, // Posortowane
+    pub object_names: Vec<String>,
+    pub internal_weight: f64,    // Suma wag relacji wewnątrz bloku
+    pub available_power: f64,    // Suma mocy swobodnej członków
+}
+
+/// @cybernetic Skierowany przepływ wpływu między blokami
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlocFlow {
+    pub source_bloc: usize,
+    pub target_bloc: usize,
+    pub total_weight: f64,
+    pub correlation_count: usize,
+}
+
+/// @cybernetic Podział na bloki z modularnością i przepływami
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityReport {
+    pub modularity: f64,
+    pub blocs: Vec<ControlBloc>, // Od największego
+    pub flows: Vec<BlocFlow>,    // Od najsilniejszego
+    pub bloc_of: BTreeMap<String, usize>,
+}
+
+// ============================================================================
+// LOUVAIN
+// ============================================================================
+
+/// Graf nieskierowany: listy sąsiedztwa (z pętlami własnymi) i stopnie ważone
+struct LevelGraph {
+    adjacency: Vec<Vec<(usize, f64)>>,
+    degree: Vec<f64>,
+}
+
+impl LevelGraph {
+    fn symmetrized(graph: &IndexedGraph) -> Self {
+        let n = graph.node_count();
+        let mut weights: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); n];
+        for edge in graph.edges.iter().filter(|e| e.weight > 0.0) {
+            *weights[edge.source].entry(edge.target).or_insert(0.0) += edge.weight;
+            *weights[edge.target].entry(edge.source).or_insert(0.0) += edge.weight;
+        }
+        Self::from_weights(weights)
+    }
+
+    fn from_weights(weights: Vec<BTreeMap<usize, f64>>) -> Self {
+        let adjacency: Vec<Vec<(usize, f64)>> =
+            weights.into_iter().map(|w| w.into_iter().collect()).collect();
+        let degree = adjacency.iter().map(|a| a.iter().map(|(_, w)| w).sum()).collect();
+        LevelGraph { adjacency, degree }
+    }
+
+    fn total_weight(&self) -> f64 {
+        self.degree.iter().sum()
+    }
+
+    /// Faza lokalnych przesunięć; zwraca przydział (0..k) i czy coś się zmieniło
+    fn local_moving(&self, resolution: f64, budget: &mut WorkBudget) -> (Vec<usize>, bool) {
+        let n = self.adjacency.len();
+        let m2 = self.total_weight();
+        let mut community: Vec<usize> = (0..n).collect();
+        let mut total: Vec<f64> = self.degree.clone();
+        let mut improved = false;
+
+        let mut links = vec![0.0; n];
+        let mut touched: Vec<usize> = Vec::new();
+
+        'passes: loop {
+            let mut moved = false;
+            for i in 0..n {
+                if !budget.tick() {
+                    break 'passes;
+                }
+                let current = community[i];
+                for &c in &touched {
+                    links[c] = 0.0;
+                }
+                touched.clear();
+                touched.push(current);
+                for &(j, w) in &self.adjacency[i] {
+                    if j == i {
+                        continue;
+                    }
+                    let c = community[j];
+                    if links[c] == 0.0 && !touched.contains(&c) {
+                        touched.push(c);
+                    }
+                    links[c] += w;
+                }
+
+                total[current] -= self.degree[i];
+                let gain = |c: usize| links[c] - resolution * total[c] * self.degree[i] / m2;
+
+                let mut best = current;
+                let mut best_gain = gain(current);
+                for &c in &touched {
+                    let g = gain(c);
+                    if g > best_gain + MIN_GAIN {
+                        best = c;
+                        best_gain = g;
+                    }
+                }
+
+                total[best] += self.degree[i];
+                if best != current {
+                    community[i] = best;
+                    moved = true;
+                    improved = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+
+        (renumber(&community), improved)
+    }
+
+    /// Graf następnego poziomu: bloki jako węzły
+    fn aggregate(&self, community: &[usize]) -> Self {
+        let k = community.iter().max().map(|m| m + 1).unwrap_or(0);
+        let mut weights: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); k];
+        for (i, neighbours) in self.adjacency.iter().enumerate() {
+            for &(j, w) in neighbours {
+                *weights[community[i]].entry(community[j]).or_insert(0.0) += w;
+            }
+        }
+        Self::from_weights(weights)
+    }
+
+    /// Q = (1/2m) Σ_c [Σ_in - γ Σ_tot² / 2m]
+    fn modularity(&self, community: &[usize], resolution: f64) -> f64 {
+        let m2 = self.total_weight();
+        if m2 <= 0.0 {
+            return 0.0;
+        }
+        let k = community.iter().max().map(|m| m + 1).unwrap_or(0);
+        let mut internal = vec![0.0; k];
+        let mut total = vec![0.0; k];
+        for (i, neighbours) in self.adjacency.iter().enumerate() {
+            total[community[i]] += self.degree[i];
+            for &(j, w) in neighbours {
+                if community[i] == community[j] {
+                    internal[community[i]] += w;
+                }
+            }
+        }
+        (0..k)
+            .map(|c| internal[c] - resolution * total[c] * total[c] / m2)
+            .sum::<f64>()
+            / m2
+    }
+}
+
+/// Numeruje społeczności kolejno od 0 w kolejności pierwszego wystąpienia
+fn renumber(community: &[usize]) -> Vec<usize> {
+    let mut mapping: HashMap<usize, usize> = HashMap::new();
+    community
+        .iter()
+        .map(|&c| {
+            let next = mapping.len();
+            *mapping.entry(c).or_insert(next)
+        })
+        .collect()
+}
+
+/// @cybernetic Louvain: przydział węzłów do bloków i modularność
+pub fn louvain(graph: &IndexedGraph, config: &CommunityConfig) -> (Vec<usize>, f64) {
+    louvain_within(graph, config, &mut WorkBudget::unlimited())
+}
+
+/// @cybernetic Louvain w ramach budżetu (odwiedzenie węzła = jedno rozwinięcie)
+pub fn louvain_within(
+    graph: &IndexedGraph,
+    config: &CommunityConfig,
+    budget: &mut WorkBudget,
+) -> (Vec<usize>, f64) {
+    let base = LevelGraph::symmetrized(graph);
+    let mut membership: Vec<usize> = (0..graph.node_count()).collect();
+
+    if base.total_weight() > 0.0 {
+        let mut level = LevelGraph::symmetrized(graph);
+        for _ in 0..config.max_levels {
+            let (community, improved) = level.local_moving(config.resolution, budget);
+            if !improved {
+                break;
+            }
+            membership.iter_mut().for_each(|m| *m = community[*m]);
+            if budget.is_exhausted() {
+                break;
+            }
+            level = level.aggregate(&community);
+        }
+    }
+
+    let modularity = base.modularity(&membership, config.resolution);
+    (membership, modularity)
+}
+
+// ============================================================================
+// API GRAFU
+// ============================================================================
+
+impl Graph {
+    /// @cybernetic Bloki sterownicze, modularność i przepływy między blokami
+    pub fn detect_communities(&self, config: &CommunityConfig) -> CommunityReport {
+        self.detect_communities_budgeted(config, &mut WorkBudget::unlimited()).result
+    }
+
+    /// @cybernetic Bloki sterownicze w ramach budżetu (`completed: false` = przydział nieoptymalny)
+    pub fn detect_communities_budgeted(
+        &self,
+        config: &CommunityConfig,
+        budget: &mut WorkBudget,
+    ) -> Budgeted<CommunityReport> {
+        let indexed = self.indexed();
+        let (membership, modularity) = louvain_within(&indexed, config, budget);
+
+        // Bloki od największego; remis - wg pierwszego ID
+        let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (v, &c) in membership.iter().enumerate() {
+            groups.entry(c).or_default().push(v);
+        }
+        let mut groups: Vec<Vec<usize>> = groups.into_values().collect();
+        groups.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(&b[0])));
+
+        let mut bloc_idx = vec![0; indexed.node_count()];
+        for (b, members) in groups.iter().enumerate() {
+            for &v in members {
+                bloc_idx[v] = b;
+            }
+        }
+
+        let mut internal_weight = vec![0.0; groups.len()];
+        let mut flows: BTreeMap<(usize, usize), BlocFlow> = BTreeMap::new();
+        for edge in indexed.edges.iter().filter(|e| e.weight > 0.0) {
+            let (from, to) = (bloc_idx[edge.source], bloc_idx[edge.target]);
+            if from == to {
+                internal_weight[from] += edge.weight;
+                continue;
+            }
+            let flow = flows.entry((from, to)).or_insert(BlocFlow {
+                source_bloc: from,
+                target_bloc: to,
+                total_weight: 0.0,
+                correlation_count: 0,
+            });
+            flow.total_weight += edge.weight;
+            flow.correlation_count += 1;
+        }
+
+        let blocs = groups
+            .iter()
+            .enumerate()
+            .map(|(b, members)| {
+                let objects: Vec<_> = members
+                    .iter()
+                    .filter_map(|&v| self.object(&indexed.ids[v]))
+                    .collect();
+                ControlBloc {
+                    index: b,
+                    object_ids: members.iter().map(|&v| indexed.ids[v].clone()).collect(),
+                    object_names: objects.iter().map(|o| o.name.clone()).collect(),
+                    internal_weight: internal_weight[b],
+                    available_power: objects.iter().map(|o| o.energy_params.available_power).sum(),
+                }
+            })
+            .collect();
+
+        let mut flows: Vec<BlocFlow> = flows.into_values().collect();
+        flows.sort_by(|a, b| {
+            b.total_weight
+                .partial_cmp(&a.total_weight)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        budget.wrap(CommunityReport {
+            modularity,
+            blocs,
+            flows,
+            bloc_of: indexed
+                .ids
+                .iter()
+                .enumerate()
+                .map(|(v, id)| (id.clone(), bloc_idx[v]))
+                .collect(),
+        })
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// Parsuje CommunityConfig (pusty JSON = domyślna)
+fn parse_community_config(config_json: &str) -> Result<CommunityConfig, JsValue> {
+    if config_json.trim().is_empty() {
+        return Ok(CommunityConfig::default());
+    }
+    serde_json::from_str(config_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse community config: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Bloki sterownicze (Louvain)
+///
+/// `config_json` pusty = rozdzielczość 1.0.
+#[wasm_bindgen]
+pub fn wasm_detect_communities(
+    objects_json: &str,
+    correlations_json: &str,
+    config_json: &str,
+) -> Result<String, JsValue> {
+    let config = parse_community_config(config_json)?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.detect_communities(&config);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Bloki sterownicze z budżetem pracy
+///
+/// `budget_json` pusty = bez limitów. Wynik: `{ result: CommunityReport, completed, ... }`.
+#[wasm_bindgen]
+pub fn wasm_detect_communities_budgeted(
+    objects_json: &str,
+    correlations_json: &str,
+    config_json: &str,
+    budget_json: &str,
+    clock: Option<js_sys::Function>,
+    token: &CancellationToken,
+) -> Result<String, JsValue> {
+    let config = parse_community_config(config_json)?;
+    let mut budget = budget_from_js(budget_json, clock, Some(token))?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.detect_communities_budgeted(&config, &mut budget);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::graph;
+
+    /// Dwa trójkąty (media+partia+NGO, rząd+wojsko+służby) połączone słabym mostem
+    fn two_triangles() -> Graph {
+        graph(
+            &["media", "partia", "ngo", "rzad", "wojsko", "sluzby"],
+            &[
+                ("media", "partia", 1.0),
+                ("partia", "ngo", 1.0),
+                ("ngo", "media", 1.0),
+                ("rzad", "wojsko", 1.0),
+                ("wojsko", "sluzby", 1.0),
+                ("sluzby", "rzad", 1.0),
+                ("ngo", "rzad", 0.2),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_two_blocs_with_bridge_flow() {
+        let report = two_triangles().detect_communities(&CommunityConfig::default());
+
+        assert_eq!(report.blocs.len(), 2);
+        assert_eq!(report.bloc_of["media"], report.bloc_of["ngo"]);
+        assert_eq!(report.bloc_of["rzad"], report.bloc_of["sluzby"]);
+        assert_ne!(report.bloc_of["media"], report.bloc_of["rzad"]);
+        assert!(report.modularity > 0.4);
+
+        assert_eq!(report.flows.len(), 1);
+        let flow = &report.flows[0];
+        assert_eq!(flow.source_bloc, report.bloc_of["ngo"]);
+        assert_eq!(flow.correlation_count, 1);
+        assert!((flow.total_weight - 0.2).abs() < 1e-12);
+        assert!((report.blocs[0].internal_weight - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_low_resolution_merges_blocs() {
+        let config = CommunityConfig {
+            resolution: 0.01,
+            ..CommunityConfig::default()
+        };
+        let report = two_triangles().detect_communities(&config);
+        assert_eq!(report.blocs.len(), 1);
+        assert!(report.flows.is_empty());
+    }
+
+    #[test]
+    fn test_budget_keeps_valid_partial_assignment() {
+        // Po 3 odwiedzinach pierwszej fazy bloki nie zdążą się uformować
+        let mut budget = WorkBudget::new(crate::budget::BudgetConfig {
+            max_expanded_nodes: Some(3),
+            ..Default::default()
+        });
+        let partial = two_triangles().detect_communities_budgeted(&CommunityConfig::default(), &mut budget);
+
+        assert!(!partial.outcome.completed);
+        assert_eq!(partial.result.bloc_of.len(), 6);
+        assert!(partial.result.blocs.len() > 2);
+        let full = two_triangles().detect_communities(&CommunityConfig::default());
+        assert!(partial.result.modularity < full.modularity);
+    }
+
+    #[test]
+    fn test_isolated_objects_form_own_blocs() {
+        let report = graph(&["a", "b", "c"], &[]).detect_communities(&CommunityConfig::default());
+        assert_eq!(report.blocs.len(), 3);
+        assert_eq!(report.modularity, 0.0);
+        assert_eq!(report.blocs[0].available_power, 1.0);
+    }
+}
@@ -0,0 +1,435 @@
+//! @fileoverview Detekcja sprzeczności w relacjach (Głęboki Homeostat)
+//! @cybernetic Port `analyzeContradiction` / `createSummary` z contradiction-engine.ts
+//!
+//! Silnik TS porównuje nowo dodane relacje z historią pobraną z bazy. Tu
+//! historią jest sam graf: aktywne relacje (bez `superseded_at`) o tej samej
+//! parze source → target są porządkowane po `created_at` i każda jest
+//! porównywana z wcześniejszymi z okna `lookback_days`. Zapis alertów
+//! i kara dla źródła zostają po stronie TS - rdzeń zwraca gotowe alerty.
+
+use crate::homeostat::{AlertStatus, AlertType, SystemAlert};
+use crate::temporal::parse_timestamp;
+use crate::{build_graph_from_json, Correlation, Graph, RelationType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+const DAY_MILLIS: i64 = 86_400_000;
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Parametry detekcji (DEFAULT_DETECTION_PARAMS z homeostat/types.ts)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContradictionConfig {
+    pub impact_diff_threshold: f64,    // Różnica impact_factor uznawana za sprzeczność
+    pub certainty_diff_threshold: f64, // Spadek certainty_score uznawany za sprzeczność
+    pub check_opposite_relations: bool,
+    pub lookback_days: f64,            // Jak daleko wstecz od nowszej relacji
+    pub min_severity_for_alert: f64,
+}
+
+impl Default for ContradictionConfig {
+    fn default() -> Self {
+        ContradictionConfig {
+            impact_diff_threshold: 0.5,
+            certainty_diff_threshold: 0.3,
+            check_opposite_relations: true,
+            lookback_days: 365.0,
+            min_severity_for_alert: 0.5,
+        }
+    }
+}
+
+// ============================================================================
+// WYNIK
+// ============================================================================
+
+/// @cybernetic Typ sprzeczności
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContradictionType {
+    OppositeRelation, // Przeciwne typy relacji
+    ImpactReversal,   // Drastyczna zmiana siły wpływu
+    CertaintyDrop,    // Gwałtowny spadek rzetelności
+    Narrative180,     // Pełna zmiana narracji (180°)
+}
+
+impl ContradictionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContradictionType::OppositeRelation => "opposite_relation",
+            ContradictionType::ImpactReversal => "impact_reversal",
+            ContradictionType::CertaintyDrop => "certainty_drop",
+            ContradictionType::Narrative180 => "narrative_180",
+        }
+    }
+}
+
+/// @cybernetic Zalecana akcja (RecommendedAction z homeostat/types.ts)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecommendedAction {
+    RejectNew,
+    FlagForReview,
+    LowerReliability,
+}
+
+/// @cybernetic Sprzeczność między relacją nowszą a wcześniejszą
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contradiction {
+    pub existing_relation_id: String,
+    pub new_relation_id: String,
+    pub source_id: String,
+    pub target_id: String,
+    pub source_name: Option<String>, // Źródło informacji nowszej relacji
+    #[serde(rename = "type")]
+    pub contradiction_type: ContradictionType,
+    pub existing_relation_type: RelationType,
+    pub new_relation_type: RelationType,
+    pub impact_factor_diff: f64,
+    pub certainty_score_diff: f64, // new - existing
+    pub severity: f64,
+    pub description: String,
+}
+
+/// @cybernetic Podsumowanie sprzeczności
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContradictionSummary {
+    pub total_contradictions: usize,
+    pub by_type: BTreeMap<String, usize>,
+    pub max_severity: f64,
+    pub affected_sources: Vec<String>,
+    pub recommended_action: RecommendedAction,
+}
+
+/// @cybernetic Raport detekcji
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContradictionReport {
+    pub detected: bool,
+    pub contradictions: Vec<Contradiction>,
+    pub summary: ContradictionSummary,
+    pub alerts: Vec<SystemAlert>, // severity >= min_severity_for_alert
+}
+
+// ============================================================================
+// ANALIZA PARY RELACJI
+// ============================================================================
+
+/// @cybernetic Czy typy relacji są przeciwne (OPPOSITE_RELATIONS)
+pub fn are_relations_opposite(a: &RelationType, b: &RelationType) -> bool {
+    matches!(
+        (a, b),
+        (RelationType::PositiveFeedback, RelationType::NegativeFeedback)
+            | (RelationType::NegativeFeedback, RelationType::PositiveFeedback)
+    )
+}
+
+/// @cybernetic Severity z różnic (calculateContradictionSeverity)
+pub fn contradiction_severity(impact_diff: f64, certainty_diff: f64, is_opposite: bool) -> f64 {
+    let mut severity = if is_opposite { 0.8 } else { 0.0 };
+    severity += impact_diff * 0.3;
+    if certainty_diff < 0.0 {
+        severity += certainty_diff.abs() * 0.2;
+    }
+    severity.clamp(0.0, 1.0)
+}
+
+/// @cybernetic Czy nowa relacja jest sprzeczna z istniejącą (analyzeContradiction)
+pub fn analyze_contradiction(
+    new: &Correlation,
+    existing: &Correlation,
+    config: &ContradictionConfig,
+) -> Option<Contradiction> {
+    if new.id == existing.id {
+        return None;
+    }
+
+    let mut found: Option<ContradictionType> = None;
+    let mut severity = 0.0;
+    let mut description = String::new();
+
+    // 1. Przeciwne typy relacji
+    if config.check_opposite_relations && are_relations_opposite(&new.relation_type, &existing.relation_type) {
+        found = Some(ContradictionType::OppositeRelation);
+        severity = 0.8;
+        description = format!(
+            "Wykryto przeciwne typy relacji: \"{}\" → \"{}\"",
+            crate::interchange::serde_name(&existing.relation_type),
+            crate::interchange::serde_name(&new.relation_type)
+        );
+    }
+
+    // 2. Drastyczna zmiana impact_factor
+    let impact_diff = (new.impact_factor - existing.impact_factor).abs();
+    let certainty_diff = new.certainty_score - existing.certainty_score;
+    if impact_diff >= config.impact_diff_threshold && (found.is_none() || severity < 0.6) {
+        found = Some(ContradictionType::ImpactReversal);
+        severity = contradiction_severity(impact_diff, certainty_diff, false);
+        description = format!(
+            "Drastyczna zmiana siły wpływu: {:.2} → {:.2} (różnica: {:.2})",
+            existing.impact_factor, new.impact_factor, impact_diff
+        );
+    }
+
+    // 3. Spadek certainty_score
+    if certainty_diff < -config.certainty_diff_threshold && (found.is_none() || severity < 0.5) {
+        found = Some(ContradictionType::CertaintyDrop);
+        severity = f64::max(severity, 0.5);
+        description = format!(
+            "Spadek rzetelności: {:.0}% → {:.0}%",
+            existing.certainty_score * 100.0,
+            new.certainty_score * 100.0
+        );
+    }
+
+    // 4. Zmiana typu + drastyczna zmiana siły = pełna zmiana narracji
+    if found == Some(ContradictionType::OppositeRelation) && impact_diff >= 0.4 {
+        found = Some(ContradictionType::Narrative180);
+        severity = 1.0;
+        description = "Pełna zmiana narracji (180°): zmiana typu relacji + drastyczna zmiana siły wpływu".to_string();
+    }
+
+    Some(Contradiction {
+        existing_relation_id: existing.id.clone(),
+        new_relation_id: new.id.clone(),
+        source_id: new.source_id.clone(),
+        target_id: new.target_id.clone(),
+        source_name: new.source_name.clone(),
+        contradiction_type: found?,
+        existing_relation_type: existing.relation_type.clone(),
+        new_relation_type: new.relation_type.clone(),
+        impact_factor_diff: impact_diff,
+        certainty_score_diff: certainty_diff,
+        severity,
+        description,
+    })
+}
+
+/// @cybernetic Podsumowanie i zalecana akcja (createSummary)
+pub fn summarize(contradictions: &[Contradiction]) -> ContradictionSummary {
+    let mut by_type: BTreeMap<String, usize> = [
+        ContradictionType::OppositeRelation,
+        ContradictionType::ImpactReversal,
+        ContradictionType::CertaintyDrop,
+        ContradictionType::Narrative180,
+    ]
+    .iter()
+    .map(|t| (t.as_str().to_string(), 0))
+    .collect();
+
+    let mut max_severity: f64 = 0.0;
+    let mut affected_sources: Vec<String> = Vec::new();
+    for c in contradictions {
+        *by_type.entry(c.contradiction_type.as_str().to_string()).or_default() += 1;
+        max_severity = max_severity.max(c.severity);
+        if let Some(source) = &c.source_name {
+            if !affected_sources.contains(source) {
+                affected_sources.push(source.clone());
+            }
+        }
+    }
+
+    let recommended_action = if max_severity >= 0.9 {
+        RecommendedAction::RejectNew
+    } else if max_severity >= 0.7 || contradictions.len() >= 3 {
+        RecommendedAction::LowerReliability
+    } else {
+        RecommendedAction::FlagForReview
+    };
+
+    ContradictionSummary {
+        total_contradictions: contradictions.len(),
+        by_type,
+        max_severity,
+        affected_sources,
+        recommended_action,
+    }
+}
+
+/// Alert w kształcie tabeli `system_alerts` (createAlert)
+fn to_alert(contradiction: &Contradiction) -> SystemAlert {
+    SystemAlert {
+        id: None,
+        alert_type: AlertType::Contradiction,
+        severity: contradiction.severity,
+        title: format!("Sprzeczność: {}", contradiction.contradiction_type.as_str()),
+        description: contradiction.description.clone(),
+        conflicting_relation_ids: vec![
+            contradiction.existing_relation_id.clone(),
+            contradiction.new_relation_id.clone(),
+        ],
+        affected_object_ids: vec![contradiction.source_id.clone(), contradiction.target_id.clone()],
+        source_name: contradiction.source_name.clone(),
+        metadata: serde_json::json!({
+            "contradiction_type": contradiction.contradiction_type,
+            "details": {
+                "relation_type_conflict": {
+                    "existing": contradiction.existing_relation_type,
+                    "new": contradiction.new_relation_type,
+                },
+                "impact_factor_diff": contradiction.impact_factor_diff,
+                "certainty_score_diff": contradiction.certainty_score_diff,
+            },
+        }),
+        status: AlertStatus::Active,
+        resolved_at: None,
+        resolved_by: None,
+        created_at: String::new(),
+    }
+}
+
+// ============================================================================
+// API GRAFU
+// ============================================================================
+
+impl Graph {
+    /// @cybernetic Sprzeczności między aktywnymi relacjami tej samej pary obiektów
+    ///
+    /// Relacje bez poprawnego `created_at` traktujemy jak najstarsze; przy
+    /// równych datach decyduje kolejność wejściowa.
+    pub fn detect_contradictions(&self, config: &ContradictionConfig) -> ContradictionReport {
+        let mut pairs: BTreeMap<(&str, &str), Vec<(i64, &Correlation)>> = BTreeMap::new();
+        for correlation in self.correlations().iter().filter(|c| c.superseded_at.is_none()) {
+            let created = parse_timestamp(&correlation.created_at).unwrap_or(i64::MIN);
+            pairs
+                .entry((&correlation.source_id, &correlation.target_id))
+                .or_default()
+                .push((created, correlation));
+        }
+
+        let lookback = (config.lookback_days * DAY_MILLIS as f64) as i64;
+        let mut contradictions = Vec::new();
+        for history in pairs.values_mut() {
+            history.sort_by_key(|(created, _)| *created);
+            for (i, &(created, new)) in history.iter().enumerate() {
+                // Najpierw najnowsze (order created_at DESC w findExistingRelations)
+                for &(existing_created, existing) in history[..i].iter().rev() {
+                    if created.saturating_sub(existing_created) > lookback {
+                        break;
+                    }
+                    contradictions.extend(analyze_contradiction(new, existing, config));
+                }
+            }
+        }
+
+        let alerts = contradictions
+            .iter()
+            .filter(|c| c.severity >= config.min_severity_for_alert)
+            .map(to_alert)
+            .collect();
+
+        ContradictionReport {
+            detected: !contradictions.is_empty(),
+            summary: summarize(&contradictions),
+            contradictions,
+            alerts,
+        }
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Detekcja sprzeczności w grafie
+#[wasm_bindgen]
+pub fn wasm_detect_contradictions(
+    objects_json: &str,
+    correlations_json: &str,
+    config_json: &str,
+) -> Result<String, JsValue> {
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let config: ContradictionConfig = if config_json.trim().is_empty() {
+        ContradictionConfig::default()
+    } else {
+        serde_json::from_str(config_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse contradiction config: {}", e)))?
+    };
+
+    serde_json::to_string(&graph.detect_contradictions(&config))
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{correlation, object};
+
+    fn relation(id: &str, relation_type: RelationType, impact: f64, certainty: f64, created_at: &str) -> Correlation {
+        let mut c = correlation("a", "b", relation_type, impact, certainty);
+        c.id = id.to_string();
+        c.created_at = created_at.to_string();
+        c.source_name = Some("Gazeta".to_string());
+        c
+    }
+
+    #[test]
+    fn test_pair_classification_matches_engine() {
+        let config = ContradictionConfig::default();
+        let old = relation("old", RelationType::PositiveFeedback, 0.9, 0.8, "2025-01-01");
+
+        let flip = relation("n1", RelationType::NegativeFeedback, 0.8, 0.8, "2025-02-01");
+        let c = analyze_contradiction(&flip, &old, &config).unwrap();
+        assert_eq!(c.contradiction_type, ContradictionType::OppositeRelation);
+        assert_eq!(c.severity, 0.8);
+
+        let reversal = relation("n2", RelationType::NegativeFeedback, 0.2, 0.8, "2025-02-01");
+        let c = analyze_contradiction(&reversal, &old, &config).unwrap();
+        assert_eq!(c.contradiction_type, ContradictionType::Narrative180);
+        assert_eq!(c.severity, 1.0);
+
+        let weaker = relation("n3", RelationType::PositiveFeedback, 0.3, 0.7, "2025-02-01");
+        let c = analyze_contradiction(&weaker, &old, &config).unwrap();
+        assert_eq!(c.contradiction_type, ContradictionType::ImpactReversal);
+        assert!((c.severity - (0.6 * 0.3 + 0.1 * 0.2)).abs() < 1e-12);
+
+        let doubt = relation("n4", RelationType::PositiveFeedback, 0.9, 0.4, "2025-02-01");
+        let c = analyze_contradiction(&doubt, &old, &config).unwrap();
+        assert_eq!(c.contradiction_type, ContradictionType::CertaintyDrop);
+        assert_eq!(c.severity, 0.5);
+
+        assert!(analyze_contradiction(&old, &old, &config).is_none());
+    }
+
+    #[test]
+    fn test_graph_history_window_and_superseded() {
+        let mut retracted = relation("retracted", RelationType::NegativeFeedback, 0.1, 0.8, "2025-03-01");
+        retracted.superseded_at = Some("2025-03-02".to_string());
+        let graph = Graph::new(
+            vec![object("a", 1.0), object("b", 1.0)],
+            vec![
+                relation("ancient", RelationType::NegativeFeedback, 0.9, 0.8, "2022-01-01"),
+                relation("new", RelationType::PositiveFeedback, 0.9, 0.8, "2025-06-01"),
+                relation("old", RelationType::PositiveFeedback, 0.85, 0.8, "2025-01-01"),
+                retracted,
+            ],
+        );
+
+        let report = graph.detect_contradictions(&ContradictionConfig::default());
+        assert!(!report.detected);
+        assert_eq!(report.summary.recommended_action, RecommendedAction::FlagForReview);
+
+        let report = graph.detect_contradictions(&ContradictionConfig {
+            lookback_days: 5000.0,
+            ..Default::default()
+        });
+        let pairs: Vec<(&str, &str)> = report
+            .contradictions
+            .iter()
+            .map(|c| (c.existing_relation_id.as_str(), c.new_relation_id.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("ancient", "old"), ("ancient", "new")]);
+        assert_eq!(report.summary.by_type["opposite_relation"], 2);
+        assert_eq!(report.summary.affected_sources, vec!["Gazeta".to_string()]);
+        assert_eq!(report.summary.recommended_action, RecommendedAction::LowerReliability);
+        assert_eq!(report.alerts.len(), 2);
+        assert_eq!(report.alerts[0].conflicting_relation_ids, vec!["ancient", "old"]);
+    }
+}
@@ -0,0 +1,320 @@
+//! @fileoverview Sterowalność strukturalna - minimalny zbiór węzłów sterujących
+//! @cybernetic Które obiekty trzeba sterować bezpośrednio, by sterować całym systemem
+//!
+//! Podejście Liu–Slotine–Barabási: maksymalne skojarzenie w grafie dwudzielnym
+//! (kopie "wyjściowe" → kopie "wejściowe" obiektów). Obiekt, do którego nie
+//! wchodzi żadna skojarzona krawędź, nie jest sterowany przez inny obiekt -
+//! musi dostać sygnał z zewnątrz (węzeł sterujący). N_D = max(N - |M*|, 1).
+//!
+//! Klasyfikacja ról (po wszystkich maksymalnych skojarzeniach):
+//! - `always`    - brak relacji wchodzących, zawsze węzeł sterujący
+//! - `sometimes` - sterujący w części maksymalnych skojarzeń
+//! - `never`     - zawsze sterowany przez inny obiekt
+//!
+//! Strukturalnie liczy się tylko istnienie relacji: uwzględniamy krawędzie
+//! o dodatniej wadze (impact_factor × certainty_score > 0).
+//!
+//! Budżet: zdjęcie węzła z kolejki BFS skojarzenia to jedno rozwinięcie.
+//! Skojarzenie przerwane budżetem jest poprawne, ale nie maksymalne - zbiór
+//! węzłów sterujących wystarcza do sterowania, lecz może nie być minimalny.
+
+use crate::budget::{budget_from_js, Budgeted, CancellationToken, WorkBudget};
+use crate::indexed::IndexedGraph;
+use crate::{build_graph_from_json, Graph};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// WYNIK
+// ============================================================================
+
+/// @cybernetic Rola obiektu w minimalnych zbiorach węzłów sterujących
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DriverRole {
+    Always,
+    Sometimes,
+    Never,
+}
+
+/// @cybernetic Klasyfikacja jednego obiektu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriverNodeClass {
+    pub object_id: String,
+    pub object_name: String,
+    pub role: DriverRole,
+}
+
+/// @cybernetic Raport sterowalności strukturalnej
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControllabilityReport {
+    pub driver_count: usize,      // N_D
+    pub driver_fraction: f64,     // n_D = N_D / N
+    pub matching_size: usize,     // |M*|
+    pub driver_nodes: Vec<String>, // Jeden minimalny zbiór (ID obiektów)
+    pub nodes: Vec<DriverNodeClass>,
+}
+
+// ============================================================================
+// MAKSYMALNE SKOJARZENIE
+// ============================================================================
+
+/// Maksymalne skojarzenie (ścieżki powiększające szukane BFS, bez rekurencji)
+///
+/// Zwraca `match_in[v] = Some(u)`, gdy krawędź u → v jest skojarzona.
+fn maximum_matching(successors: &[Vec<usize>], budget: &mut WorkBudget) -> Vec<Option<usize>> {
+    let n = successors.len();
+    let mut match_out: Vec<Option<usize>> = vec![None; n];
+    let mut match_in: Vec<Option<usize>> = vec![None; n];
+
+    for start in 0..n {
+        let mut parent: Vec<Option<usize>> = vec![None; n]; // Wejście → wyjście, z którego doszliśmy
+        let mut queue = VecDeque::from([start]);
+        let mut free_end = None;
+
+        'search: while let Some(u) = queue.pop_front() {
+            if !budget.tick() {
+                // Niedokończona ścieżka powiększająca - skojarzenie bez zmian
+                return match_in;
+            }
+            for &v in &successors[u] {
+                if parent[v].is_some() {
+                    continue;
+                }
+                parent[v] = Some(u);
+                match match_in[v] {
+                    None => {
+                        free_end = Some(v);
+                        break 'search;
+                    }
+                    Some(next) => queue.push_back(next),
+                }
+            }
+        }
+
+        // Odwrócenie ścieżki naprzemiennej
+        let mut current = free_end;
+        while let Some(v) = current {
+            let u = parent[v].expect("augmenting path has parent");
+            current = match_out[u];
+            match_out[u] = Some(v);
+            match_in[v] = Some(u);
+        }
+    }
+
+    match_in
+}
+
+// ============================================================================
+// ANALIZA
+// ============================================================================
+
+/// @cybernetic Minimalny zbiór węzłów sterujących i role obiektów
+pub fn structural_controllability(graph: &IndexedGraph) -> (Vec<usize>, Vec<DriverRole>, usize) {
+    structural_controllability_within(graph, &mut WorkBudget::unlimited())
+}
+
+/// @cybernetic Sterowalność w ramach budżetu (krok BFS skojarzenia = jedno rozwinięcie)
+pub fn structural_controllability_within(
+    graph: &IndexedGraph,
+    budget: &mut WorkBudget,
+) -> (Vec<usize>, Vec<DriverRole>, usize) {
+    let n = graph.node_count();
+    if n == 0 {
+        return (Vec::new(), Vec::new(), 0);
+    }
+
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for edge in graph.edges.iter().filter(|e| e.weight > 0.0) {
+        if !successors[edge.source].contains(&edge.target) {
+            successors[edge.source].push(edge.target);
+            predecessors[edge.target].push(edge.source);
+        }
+    }
+
+    let match_in = maximum_matching(&successors, budget);
+    let mut match_out: Vec<Option<usize>> = vec![None; n];
+    for (v, u) in match_in.iter().enumerate() {
+        if let Some(u) = u {
+            match_out[*u] = Some(v);
+        }
+    }
+    let matching_size = match_in.iter().filter(|m| m.is_some()).count();
+
+    let unmatched: Vec<usize> = (0..n).filter(|&v| match_in[v].is_none()).collect();
+
+    // Skojarzenie doskonałe: wystarczy jeden dowolny węzeł sterujący
+    if unmatched.is_empty() {
+        return (vec![0], vec![DriverRole::Sometimes; n], matching_size);
+    }
+
+    // Węzeł może zostać odkojarzony, gdy prowadzi do niego ścieżka naprzemienna
+    // parzystej długości od węzła nieskojarzonego: v wolny, u → v, u skojarzony z w
+    let mut can_be_driver = vec![false; n];
+    let mut queue: VecDeque<usize> = unmatched.iter().copied().collect();
+    for &v in &unmatched {
+        can_be_driver[v] = true;
+    }
+    while let Some(v) = queue.pop_front() {
+        for &u in &predecessors[v] {
+            if let Some(w) = match_out[u] {
+                if !can_be_driver[w] {
+                    can_be_driver[w] = true;
+                    queue.push_back(w);
+                }
+            }
+        }
+    }
+
+    let roles = (0..n)
+        .map(|v| {
+            if predecessors[v].is_empty() {
+                DriverRole::Always
+            } else if can_be_driver[v] {
+                DriverRole::Sometimes
+            } else {
+                DriverRole::Never
+            }
+        })
+        .collect();
+
+    (unmatched, roles, matching_size)
+}
+
+impl Graph {
+    /// @cybernetic Sterowalność strukturalna całego systemu (kolejność wg ID)
+    pub fn controllability(&self) -> ControllabilityReport {
+        self.controllability_budgeted(&mut WorkBudget::unlimited()).result
+    }
+
+    /// @cybernetic Sterowalność w ramach budżetu (`completed: false` = zbiór może nie być minimalny)
+    pub fn controllability_budgeted(&self, budget: &mut WorkBudget) -> Budgeted<ControllabilityReport> {
+        let indexed = self.indexed();
+        let (drivers, roles, matching_size) = structural_controllability_within(&indexed, budget);
+        let n = indexed.node_count();
+
+        let nodes = indexed
+            .ids
+            .iter()
+            .zip(roles)
+            .map(|(id, role)| DriverNodeClass {
+                object_id: id.clone(),
+                object_name: self.object(id).map(|o| o.name.clone()).unwrap_or_else(|| id.clone()),
+                role,
+            })
+            .collect();
+
+        budget.wrap(ControllabilityReport {
+            driver_count: drivers.len(),
+            driver_fraction: if n > 0 { drivers.len() as f64 / n as f64 } else { 0.0 },
+            matching_size,
+            driver_nodes: drivers.iter().map(|&idx| indexed.ids[idx].clone()).collect(),
+            nodes,
+        })
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Minimalny zbiór węzłów sterujących
+#[wasm_bindgen]
+pub fn wasm_structural_controllability(
+    objects_json: &str,
+    correlations_json: &str,
+) -> Result<String, JsValue> {
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.controllability();
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Węzły sterujące z budżetem pracy
+///
+/// `budget_json` pusty = bez limitów. Wynik: `{ result: ControllabilityReport, completed, ... }`.
+#[wasm_bindgen]
+pub fn wasm_structural_controllability_budgeted(
+    objects_json: &str,
+    correlations_json: &str,
+    budget_json: &str,
+    clock: Option<js_sys::Function>,
+    token: &CancellationToken,
+) -> Result<String, JsValue> {
+    let mut budget = budget_from_js(budget_json, clock, Some(token))?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.controllability_budgeted(&mut budget);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::graph;
+
+    fn role(report: &ControllabilityReport, id: &str) -> DriverRole {
+        report.nodes.iter().find(|n| n.object_id == id).unwrap().role
+    }
+
+    #[test]
+    fn test_chain_needs_single_driver() {
+        let report = graph(&["a", "b", "c"], &[("a", "b", 1.0), ("b", "c", 1.0)]).controllability();
+
+        assert_eq!(report.driver_nodes, vec!["a".to_string()]);
+        assert_eq!(report.matching_size, 2);
+        assert_eq!(role(&report, "a"), DriverRole::Always);
+        assert_eq!(role(&report, "b"), DriverRole::Never);
+        assert_eq!(role(&report, "c"), DriverRole::Never);
+    }
+
+    #[test]
+    fn test_star_leaves_compete_for_matching() {
+        // Jeden ośrodek nie steruje niezależnie dwoma liśćmi
+        let report = graph(&["a", "b", "c"], &[("a", "b", 1.0), ("a", "c", 1.0)]).controllability();
+
+        assert_eq!(report.driver_count, 2);
+        assert!((report.driver_fraction - 2.0 / 3.0).abs() < 1e-12);
+        assert!(report.driver_nodes.contains(&"a".to_string()));
+        assert_eq!(role(&report, "a"), DriverRole::Always);
+        assert_eq!(role(&report, "b"), DriverRole::Sometimes);
+        assert_eq!(role(&report, "c"), DriverRole::Sometimes);
+    }
+
+    #[test]
+    fn test_cycle_and_zero_weight_edges() {
+        let cycle = graph(&["a", "b", "c"], &[("a", "b", 1.0), ("b", "c", 1.0), ("c", "a", 1.0)]);
+        let report = cycle.controllability();
+        assert_eq!(report.driver_count, 1);
+        assert!(report.nodes.iter().all(|n| n.role == DriverRole::Sometimes));
+
+        // Relacja o zerowym wpływie nie istnieje strukturalnie
+        let report = graph(&["a", "b"], &[("a", "b", 0.0)]).controllability();
+        assert_eq!(report.driver_count, 2);
+        assert_eq!(role(&report, "b"), DriverRole::Always);
+    }
+
+    #[test]
+    fn test_budget_yields_sufficient_driver_set() {
+        let chain = graph(&["a", "b", "c", "d"], &[("a", "b", 1.0), ("b", "c", 1.0), ("c", "d", 1.0)]);
+        let mut budget = WorkBudget::new(crate::budget::BudgetConfig {
+            max_expanded_nodes: Some(1),
+            ..Default::default()
+        });
+        let partial = chain.controllability_budgeted(&mut budget);
+
+        // Skojarzona tylko a → b: c i d sterowane z zewnątrz (więcej niż minimum 1)
+        assert!(!partial.outcome.completed);
+        assert_eq!(partial.result.matching_size, 1);
+        assert_eq!(partial.result.driver_nodes, vec!["a", "c", "d"]);
+        assert!(chain.controllability_budgeted(&mut WorkBudget::unlimited()).outcome.completed);
+    }
+}
@@ -0,0 +1,290 @@
+//! @fileoverview Wyjaśnienie dźwigni sterowniczej węzła
+//! @cybernetic Skąd bierze się Leverage = Power × Influence × Feedback × Certainty
+//!
+//! Dla każdego InfluentialNode podajemy:
+//! - rozkład na czynniki wzoru i dźwignię kontrfaktyczną (bez sprzężeń,
+//!   przy pełnej rzetelności),
+//! - ścieżki o największym udziale w średniej sile wpływu,
+//! - relacje "wąskie gardła" - najmniej pewne ogniwa ścieżek, z przyrostem
+//!   dźwigni, gdyby ich rzetelność potwierdzono (certainty = 1).
+
+use crate::{
+    build_graph_from_json, parse_steering_goal, Correlation, Graph, InfluentialNode, SteeringGoal,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// Liczba ścieżek o największym udziale w wyjaśnieniu
+pub const TOP_EXPLAINED_PATHS: usize = 3;
+
+/// Maksymalna liczba wąskich gardeł w wyjaśnieniu
+pub const MAX_BOTTLENECK_EDGES: usize = 5;
+
+// ============================================================================
+// WYNIK
+// ============================================================================
+
+/// @cybernetic Czynniki wzoru dźwigni
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeverageFactors {
+    pub available_power: f64,     // P - moc swobodna
+    pub influence_strength: f64,  // I - średnia siła ścieżek
+    pub feedback_multiplier: f64, // F - mnożnik sprzężeń
+    pub certainty_score: f64,     // C - średnia rzetelność ścieżek
+}
+
+/// @cybernetic Udział ścieżki w dźwigni
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathContribution {
+    pub path: Vec<String>,
+    pub path_names: Vec<String>,
+    pub correlation_ids: Vec<String>,
+    pub total_strength: f64,
+    pub certainty_score: f64,
+    pub share: f64,                  // Udział w Σ sił ścieżek węzła (0-1)
+    pub leverage_contribution: f64,  // share × control_leverage
+}
+
+/// @cybernetic Relacja ograniczająca rzetelność ścieżek
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BottleneckEdge {
+    pub correlation_id: String,
+    pub source_id: String,
+    pub target_id: String,
+    pub certainty_score: f64,
+    pub paths_limited: usize, // W ilu ścieżkach jest najsłabszym ogniwem
+    pub leverage_gain: f64,   // Przyrost dźwigni przy certainty = 1
+}
+
+/// @cybernetic Wyjaśnienie dźwigni sterowniczej
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeverageExplanation {
+    pub factors: LeverageFactors,
+    pub leverage_without_feedback: f64,  // P × I × C
+    pub leverage_at_full_certainty: f64, // P × I × F
+    pub top_paths: Vec<PathContribution>,
+    pub bottleneck_edges: Vec<BottleneckEdge>,
+}
+
+// ============================================================================
+// WYJAŚNIANIE
+// ============================================================================
+
+/// @cybernetic Buduje wyjaśnienie dla węzła (relacje wyszukiwane po ID)
+pub fn explain_node(node: &InfluentialNode, correlations: &HashMap<&str, &Correlation>) -> LeverageExplanation {
+    let power = node.available_power;
+    let influence = node.influence_strength;
+    let feedback = node.feedback_multiplier;
+    let certainty = node.certainty_score;
+
+    // Udziały ścieżek: I = średnia sił, więc udział = s_p / Σ s
+    let total_strength: f64 = node.paths.iter().map(|p| p.total_strength).sum();
+    let mut top_paths: Vec<PathContribution> = node
+        .paths
+        .iter()
+        .map(|p| {
+            let share = if total_strength > 0.0 { p.total_strength / total_strength } else { 0.0 };
+            PathContribution {
+                path: p.path.clone(),
+                path_names: p.path_names.clone(),
+                correlation_ids: p.correlation_ids.clone(),
+                total_strength: p.total_strength,
+                certainty_score: p.certainty_score,
+                share,
+                leverage_contribution: share * node.control_leverage,
+            }
+        })
+        .collect();
+    top_paths.sort_by(|a, b| b.share.partial_cmp(&a.share).unwrap_or(std::cmp::Ordering::Equal));
+    top_paths.truncate(TOP_EXPLAINED_PATHS);
+
+    // Wąskie gardła: najmniej pewna relacja każdej ścieżki.
+    // C = średnia po ścieżkach ze średniej po relacjach, więc podniesienie
+    // relacji e do 1.0 zwiększa C o Σ_p (wystąpienia_e × (1 - c_e) / len_p) / n.
+    let path_count = node.paths.len().max(1) as f64;
+    let mut bottlenecks: Vec<BottleneckEdge> = Vec::new();
+    for path in &node.paths {
+        let weakest = path
+            .correlation_ids
+            .iter()
+            .filter_map(|id| correlations.get(id.as_str()))
+            .min_by(|a, b| {
+                a.certainty_score
+                    .partial_cmp(&b.certainty_score)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        let Some(weakest) = weakest.filter(|c| c.certainty_score < 1.0) else {
+            continue;
+        };
+
+        match bottlenecks.iter_mut().find(|b| b.correlation_id == weakest.id) {
+            Some(existing) => existing.paths_limited += 1,
+            None => bottlenecks.push(BottleneckEdge {
+                correlation_id: weakest.id.clone(),
+                source_id: weakest.source_id.clone(),
+                target_id: weakest.target_id.clone(),
+                certainty_score: weakest.certainty_score,
+                paths_limited: 1,
+                leverage_gain: 0.0,
+            }),
+        }
+    }
+
+    for bottleneck in &mut bottlenecks {
+        let certainty_gain: f64 = node
+            .paths
+            .iter()
+            .filter(|p| !p.correlation_ids.is_empty())
+            .map(|p| {
+                let occurrences = p
+                    .correlation_ids
+                    .iter()
+                    .filter(|id| **id == bottleneck.correlation_id)
+                    .count() as f64;
+                occurrences * (1.0 - bottleneck.certainty_score) / p.correlation_ids.len() as f64
+            })
+            .sum::<f64>()
+            / path_count;
+        bottleneck.leverage_gain = power * influence * feedback * certainty_gain;
+    }
+    bottlenecks.sort_by(|a, b| {
+        b.leverage_gain
+            .partial_cmp(&a.leverage_gain)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    bottlenecks.truncate(MAX_BOTTLENECK_EDGES);
+
+    LeverageExplanation {
+        factors: LeverageFactors {
+            available_power: power,
+            influence_strength: influence,
+            feedback_multiplier: feedback,
+            certainty_score: certainty,
+        },
+        leverage_without_feedback: power * influence * certainty,
+        leverage_at_full_certainty: power * influence * feedback,
+        top_paths,
+        bottleneck_edges: bottlenecks,
+    }
+}
+
+impl Graph {
+    /// @cybernetic Indeks relacji po ID
+    pub(crate) fn correlation_index(&self) -> HashMap<&str, &Correlation> {
+        self.correlations.iter().map(|c| (c.id.as_str(), c)).collect()
+    }
+
+    /// @cybernetic Uzupełnia `explanation` w podanych węzłach
+    pub fn explain_nodes(&self, nodes: &mut [InfluentialNode]) {
+        let correlations = self.correlation_index();
+        for node in nodes {
+            node.explanation = Some(explain_node(node, &correlations));
+        }
+    }
+
+    /// @cybernetic Ranking węzłów wpływowych z wyjaśnieniem dźwigni
+    pub fn rank_explained_influential_nodes(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+    ) -> Vec<InfluentialNode> {
+        let mut nodes = self.rank_influential_nodes(target_id, goal);
+        self.explain_nodes(&mut nodes);
+        nodes
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Węzły wpływowe z wyjaśnieniem dźwigni
+#[wasm_bindgen]
+pub fn wasm_explain_influence(
+    objects_json: &str,
+    correlations_json: &str,
+    target_id: &str,
+    goal: &str,
+) -> Result<String, JsValue> {
+    let steering_goal = parse_steering_goal(goal)?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let nodes = graph.rank_explained_influential_nodes(target_id, &steering_goal);
+
+    serde_json::to_string(&nodes)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{correlation, object};
+    use crate::RelationType;
+
+    /// a → cel (pewna, silna) oraz a → b → cel (słaba pewność na b → cel)
+    fn graph() -> Graph {
+        Graph::new(
+            vec![object("a", 2.0), object("b", 1.0), object("cel", 1.0)],
+            vec![
+                correlation("a", "cel", RelationType::DirectControl, 0.9, 1.0),
+                correlation("a", "b", RelationType::PositiveFeedback, 0.5, 0.8),
+                correlation("b", "cel", RelationType::DirectControl, 0.4, 0.2),
+            ],
+        )
+    }
+
+    fn node_a(graph: &Graph) -> InfluentialNode {
+        graph
+            .rank_explained_influential_nodes("cel", &SteeringGoal::Strengthen)
+            .into_iter()
+            .find(|n| n.object_id == "a")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_factors_reproduce_leverage() {
+        let node = node_a(&graph());
+        let explanation = node.explanation.as_ref().unwrap();
+        let f = &explanation.factors;
+
+        let product = f.available_power * f.influence_strength * f.feedback_multiplier * f.certainty_score;
+        assert!((product - node.control_leverage).abs() < 1e-12);
+        assert_eq!(f.feedback_multiplier, 1.5);
+        assert!((explanation.leverage_without_feedback * 1.5 - node.control_leverage).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_top_paths_shares() {
+        let node = node_a(&graph());
+        let paths = &node.explanation.as_ref().unwrap().top_paths;
+
+        // Siły: 0.9 (bezpośrednia) i 0.5 × 0.4 = 0.2
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].correlation_ids, vec!["a-cel"]);
+        assert!((paths[0].share - 0.9 / 1.1).abs() < 1e-12);
+        let total: f64 = paths.iter().map(|p| p.leverage_contribution).sum();
+        assert!((total - node.control_leverage).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bottleneck_edge_and_gain() {
+        let g = graph();
+        let node = node_a(&g);
+        let bottlenecks = &node.explanation.as_ref().unwrap().bottleneck_edges;
+
+        assert_eq!(bottlenecks.len(), 1);
+        assert_eq!(bottlenecks[0].correlation_id, "b-cel");
+        assert_eq!(bottlenecks[0].paths_limited, 1);
+
+        // Weryfikacja: przelicz dźwignię z certainty b-cel = 1.0
+        let mut correlations = g.correlations().to_vec();
+        correlations[2].certainty_score = 1.0;
+        let objects: Vec<_> = ["a", "b", "cel"].iter().map(|id| g.object(id).unwrap().clone()).collect();
+        let verified = node_a(&Graph::new(objects, correlations));
+        assert!((verified.control_leverage - node.control_leverage - bottlenecks[0].leverage_gain).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,6 @@
+//! @fileoverview Moduł decyzyjny w rdzeniu - rekomendacje sterowania
+//! @cybernetic Odpowiednik decisions/ w TypeScript, liczony w całości w Rust
+
+pub mod explain;
+pub mod recommend;
+pub mod sensitivity;
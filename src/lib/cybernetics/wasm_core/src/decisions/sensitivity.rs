@@ -0,0 +1,492 @@
+//! @fileoverview Wrażliwość rankingu dźwigni na parametry relacji
+//! @cybernetic Którą niepewną relację zweryfikować najpierw?
+//!
+//! Dla każdej relacji, którą BFS rozważa (wchodzącej do celu lub do węzła
+//! leżącego na którejś ścieżce - także odciętej progiem MIN_INFLUENCE_THRESHOLD
+//! albo leżącej za limitem MAX_PATHS)
+//! i dla każdego z parametrów `impact_factor`, `certainty_score`:
+//! - pochodna dźwigni węzłów top-k (różnica centralna, krok `step`),
+//! - pochodna luki między rekomendacją główną a drugim węzłem i liniowa
+//!   odległość do jej zamknięcia,
+//! - dokładne przeliczenie na krańcach zakresu [0, 1]: czy rekomendacja
+//!   główna się zmienia (i na co) oraz czy zmienia się kolejność top-k.
+//!
+//! Relacje sortujemy od najbardziej zdolnych do odwrócenia rekomendacji.
+//!
+//! Zbiór badanych relacji wyznacza przeszukiwanie bez limitu MAX_PATHS, ale z
+//! limitem `max_explored_paths` (domyślnie 10 000) - na gęstym grafie liczba
+//! ścieżek rośnie wykładniczo z głębokością.
+
+use crate::budget::{budget_from_js, Budgeted, CancellationToken, WorkBudget};
+use crate::feedback::FeedbackModel;
+use crate::search::PathSearchState;
+use crate::{build_graph_from_json, parse_steering_goal, Correlation, Graph, SteeringGoal};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// KONFIGURACJA / WYNIK
+// ============================================================================
+
+/// @cybernetic Parametry analizy wrażliwości
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SensitivityConfig {
+    pub top_k: usize,
+    pub step: f64,                          // Krok różnicy skończonej
+    pub max_explored_paths: Option<usize>, // Limit ścieżek wyznaczających badane relacje (None = bez limitu)
+}
+
+/// Domyślny limit ścieżek przy wyznaczaniu badanych relacji
+pub const DEFAULT_MAX_EXPLORED_PATHS: usize = 10_000;
+
+impl Default for SensitivityConfig {
+    fn default() -> Self {
+        SensitivityConfig {
+            top_k: 5,
+            step: 1e-4,
+            max_explored_paths: Some(DEFAULT_MAX_EXPLORED_PATHS),
+        }
+    }
+}
+
+/// @cybernetic Badany parametr relacji
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeParameter {
+    ImpactFactor,
+    CertaintyScore,
+}
+
+/// @cybernetic Pochodna dźwigni jednego węzła top-k
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeverageDerivative {
+    pub object_id: String,
+    pub derivative: f64,
+}
+
+/// @cybernetic Wrażliwość rankingu na jeden parametr jednej relacji
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeSensitivity {
+    pub correlation_id: String,
+    pub source_id: String,
+    pub target_id: String,
+    pub parameter: EdgeParameter,
+    pub value: f64,
+    pub leverage_derivatives: Vec<LeverageDerivative>, // Węzły top-k
+    pub gap_derivative: f64,          // d(L1 - L2) / d parametr
+    pub flip_distance: Option<f64>,   // |Δ parametru| zamykające lukę liniowo
+    pub flips_primary: bool,          // Na krańcu zakresu zmienia się rekomendacja
+    pub flipped_to: Option<String>,   // Nowa rekomendacja główna (None: brak rekomendacji na krańcu)
+    pub top_k_changed: bool,          // Na krańcu zakresu zmienia się kolejność top-k
+}
+
+/// @cybernetic Raport wrażliwości
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitivityReport {
+    pub target_id: String,
+    pub top_k: Vec<String>,
+    pub primary_object_id: Option<String>,
+    pub runner_up_object_id: Option<String>,
+    pub leverage_gap: f64,
+    pub edges: Vec<EdgeSensitivity>,
+    pub exploration_truncated: bool, // Limit max_explored_paths - część relacji pominięta
+}
+
+// ============================================================================
+// ANALIZA
+// ============================================================================
+
+/// Ranking: kolejność ID i dźwignie
+struct Ranking {
+    order: Vec<String>,
+    leverage: HashMap<String, f64>,
+}
+
+impl Ranking {
+    fn of(graph: &Graph, target_id: &str, goal: &SteeringGoal, budget: &mut WorkBudget) -> Self {
+        let nodes = graph
+            .rank_influential_nodes_budgeted(target_id, goal, &FeedbackModel::default(), budget)
+            .result;
+        Ranking {
+            order: nodes.iter().map(|n| n.object_id.clone()).collect(),
+            leverage: nodes.into_iter().map(|n| (n.object_id, n.control_leverage)).collect(),
+        }
+    }
+
+    fn get(&self, id: Option<&String>) -> f64 {
+        id.and_then(|id| self.leverage.get(id)).copied().unwrap_or(0.0)
+    }
+
+    fn top(&self, k: usize) -> &[String] {
+        &self.order[..k.min(self.order.len())]
+    }
+}
+
+impl EdgeParameter {
+    fn get(self, corr: &Correlation) -> f64 {
+        match self {
+            EdgeParameter::ImpactFactor => corr.impact_factor,
+            EdgeParameter::CertaintyScore => corr.certainty_score,
+        }
+    }
+
+    fn set(self, corr: &mut Correlation, value: f64) {
+        match self {
+            EdgeParameter::ImpactFactor => corr.impact_factor = value,
+            EdgeParameter::CertaintyScore => corr.certainty_score = value,
+        }
+    }
+}
+
+impl Graph {
+    /// Zmienia parametr jednej relacji w miejscu (indeks BFS się nie zmienia)
+    fn set_edge_parameter(&mut self, correlation_idx: usize, parameter: EdgeParameter, value: f64) {
+        let corr = &mut self.correlations[correlation_idx];
+        parameter.set(corr, value);
+
+        // Kopia w liście sąsiedztwa: ta sama pozycja wśród relacji źródła
+        let source_id = corr.source_id.clone();
+        let position = self.correlations[..correlation_idx]
+            .iter()
+            .filter(|c| c.source_id == source_id)
+            .count();
+        if let Some(forward) = self.adjacency_list.get_mut(&source_id).and_then(|list| list.get_mut(position)) {
+            parameter.set(forward, value);
+        }
+    }
+
+    /// @cybernetic Wrażliwość rankingu dźwigni na parametry relacji
+    pub fn ranking_sensitivity(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+        config: &SensitivityConfig,
+    ) -> SensitivityReport {
+        self.ranking_sensitivity_budgeted(target_id, goal, config, &mut WorkBudget::unlimited())
+            .result
+    }
+
+    /// @cybernetic Wrażliwość w ramach budżetu (wspólnego dla wszystkich przeliczeń)
+    ///
+    /// Po wyczerpaniu budżetu raport zawiera tylko relacje policzone w całości.
+    pub fn ranking_sensitivity_budgeted(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+        config: &SensitivityConfig,
+        budget: &mut WorkBudget,
+    ) -> Budgeted<SensitivityReport> {
+        let baseline = Ranking::of(self, target_id, goal, budget);
+        let top_k: Vec<String> = baseline.top(config.top_k).to_vec();
+        let primary = top_k.first().cloned();
+        let runner_up = top_k.get(1).cloned();
+        let gap = baseline.get(primary.as_ref()) - baseline.get(runner_up.as_ref());
+
+        // Relacje rozważane przez BFS: wchodzące do celu lub węzłów ze ścieżek.
+        // Bez limitu MAX_PATHS - relacja za limitem może po zmianie parametru
+        // wejść do rankingu, więc też ją badamy (ogranicza nas budżet i max_explored_paths)
+        let mut explored: HashSet<String> = HashSet::from([target_id.to_string()]);
+        let mut search = PathSearchState::new(target_id);
+        let mut explored_paths = 0;
+        let mut exploration_truncated = false;
+        while let Some(path) = search.next_path_within(self, budget) {
+            explored.extend(path.path);
+            explored_paths += 1;
+            if config.max_explored_paths.is_some_and(|max| explored_paths >= max) {
+                exploration_truncated = !search.is_exhausted();
+                break;
+            }
+        }
+
+        // Jedna kopia robocza - parametr zmieniany w miejscu i przywracany
+        let mut work = self.clone();
+        let mut edges = Vec::new();
+        if primary.is_some() && !budget.is_exhausted() {
+            'edges: for (idx, corr) in self.correlations.iter().enumerate() {
+                if !explored.contains(corr.target_id.as_str()) || !self.objects.contains_key(&corr.source_id) {
+                    continue;
+                }
+
+                for parameter in [EdgeParameter::ImpactFactor, EdgeParameter::CertaintyScore] {
+                    let value = parameter.get(corr);
+                    let mut eval = |v: f64| {
+                        work.set_edge_parameter(idx, parameter, v);
+                        let ranking = Ranking::of(&work, target_id, goal, budget);
+                        work.set_edge_parameter(idx, parameter, value);
+                        ranking
+                    };
+
+                    // Różnica centralna (jednostronna na krańcach zakresu)
+                    let (lo, hi) = ((value - config.step).max(0.0), (value + config.step).min(1.0));
+                    if hi <= lo {
+                        continue;
+                    }
+                    let (below, above) = (eval(lo), eval(hi));
+                    let derivative = |id: Option<&String>| (above.get(id) - below.get(id)) / (hi - lo);
+
+                    let leverage_derivatives = top_k
+                        .iter()
+                        .map(|id| LeverageDerivative {
+                            object_id: id.clone(),
+                            derivative: derivative(Some(id)),
+                        })
+                        .collect();
+                    let gap_derivative = derivative(primary.as_ref()) - derivative(runner_up.as_ref());
+                    let flip_distance = (gap_derivative != 0.0).then(|| gap / gap_derivative.abs());
+
+                    // Dokładnie na krańcach zakresu
+                    let mut flips_primary = false;
+                    let mut flipped_to = None;
+                    let mut top_k_changed = false;
+                    for bound in [0.0, 1.0] {
+                        if bound == value {
+                            continue;
+                        }
+                        let ranking = eval(bound);
+                        if ranking.order.first() != primary.as_ref() {
+                            flips_primary = true;
+                            // Pusty ranking = brak rekomendacji, nie węzeł o pustym ID
+                            if flipped_to.is_none() {
+                                flipped_to = ranking.order.first().cloned();
+                            }
+                        }
+                        top_k_changed |= ranking.top(config.top_k) != top_k.as_slice();
+                    }
+
+                    // Przeliczenia przerwane budżetem są niewiarygodne
+                    if budget.is_exhausted() {
+                        break 'edges;
+                    }
+
+                    edges.push(EdgeSensitivity {
+                        correlation_id: corr.id.clone(),
+                        source_id: corr.source_id.clone(),
+                        target_id: corr.target_id.clone(),
+                        parameter,
+                        value,
+                        leverage_derivatives,
+                        gap_derivative,
+                        flip_distance,
+                        flips_primary,
+                        flipped_to,
+                        top_k_changed,
+                    });
+                }
+            }
+        }
+
+        // Najpierw odwracające rekomendację, potem najbliższe odwrócenia
+        edges.sort_by(|a, b| {
+            b.flips_primary
+                .cmp(&a.flips_primary)
+                .then_with(|| {
+                    let (da, db) = (a.flip_distance.unwrap_or(f64::INFINITY), b.flip_distance.unwrap_or(f64::INFINITY));
+                    da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .then_with(|| {
+                    b.gap_derivative
+                        .abs()
+                        .partial_cmp(&a.gap_derivative.abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+        });
+
+        budget.wrap(SensitivityReport {
+            target_id: target_id.to_string(),
+            top_k,
+            primary_object_id: primary,
+            runner_up_object_id: runner_up,
+            leverage_gap: gap,
+            edges,
+            exploration_truncated,
+        })
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// Parsuje SensitivityConfig (pusty JSON = domyślna)
+fn parse_sensitivity_config(config_json: &str) -> Result<SensitivityConfig, JsValue> {
+    if config_json.trim().is_empty() {
+        return Ok(SensitivityConfig::default());
+    }
+    serde_json::from_str(config_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse sensitivity config: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Wrażliwość rekomendacji na relacje
+///
+/// `config_json` pusty = top-5, krok 1e-4, najwyżej 10 000 ścieżek przy
+/// wyznaczaniu badanych relacji (`max_explored_paths: null` znosi limit).
+#[wasm_bindgen]
+pub fn wasm_ranking_sensitivity(
+    objects_json: &str,
+    correlations_json: &str,
+    target_id: &str,
+    goal: &str,
+    config_json: &str,
+) -> Result<String, JsValue> {
+    let steering_goal = parse_steering_goal(goal)?;
+    let config = parse_sensitivity_config(config_json)?;
+
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.ranking_sensitivity(target_id, &steering_goal, &config);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Wrażliwość z budżetem pracy i anulowaniem
+///
+/// Wynik: `{ result: SensitivityReport, completed, stop_reason, ... }`.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn wasm_ranking_sensitivity_budgeted(
+    objects_json: &str,
+    correlations_json: &str,
+    target_id: &str,
+    goal: &str,
+    config_json: &str,
+    budget_json: &str,
+    clock: Option<js_sys::Function>,
+    token: &CancellationToken,
+) -> Result<String, JsValue> {
+    let steering_goal = parse_steering_goal(goal)?;
+    let config = parse_sensitivity_config(config_json)?;
+    let mut budget = budget_from_js(budget_json, clock, Some(token))?;
+
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.ranking_sensitivity_budgeted(target_id, &steering_goal, &config, &mut budget);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{correlation, object};
+    use crate::RelationType;
+
+    /// a: L = 1 × 0.9 × 0.9 = 0.81, b: L = 0.5 × 0.8 × 0.9 = 0.36
+    fn graph(extra: Vec<crate::Correlation>) -> Graph {
+        let mut correlations = vec![
+            correlation("a", "cel", RelationType::DirectControl, 0.9, 0.9),
+            correlation("b", "cel", RelationType::DirectControl, 0.8, 0.9),
+        ];
+        correlations.extend(extra);
+        Graph::new(
+            vec![object("a", 1.0), object("b", 0.5), object("c", 100.0), object("cel", 1.0)],
+            correlations,
+        )
+    }
+
+    fn find<'a>(report: &'a SensitivityReport, id: &str, parameter: EdgeParameter) -> &'a EdgeSensitivity {
+        report
+            .edges
+            .iter()
+            .find(|e| e.correlation_id == id && e.parameter == parameter)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_primary_edge_derivatives_and_flip_distance() {
+        let report = graph(Vec::new()).ranking_sensitivity("cel", &SteeringGoal::Strengthen, &SensitivityConfig::default());
+
+        assert_eq!(report.primary_object_id.as_deref(), Some("a"));
+        assert!((report.leverage_gap - 0.45).abs() < 1e-12);
+        assert_eq!(report.edges.len(), 4);
+
+        let impact = find(&report, "a-cel", EdgeParameter::ImpactFactor);
+        assert!((impact.gap_derivative - 0.9).abs() < 1e-6);
+        assert!((impact.flip_distance.unwrap() - 0.5).abs() < 1e-6);
+        assert!(impact.flips_primary);
+        assert_eq!(impact.flipped_to.as_deref(), Some("b"));
+
+        // Relacje a-cel odwracają rekomendację, b-cel nie
+        assert!(report.edges[..2].iter().all(|e| e.correlation_id == "a-cel"));
+        assert!(!find(&report, "b-cel", EdgeParameter::CertaintyScore).flips_primary);
+    }
+
+    #[test]
+    fn test_pruned_edge_can_flip_at_bound() {
+        // c ma ogromną moc, ale relacja poniżej progu 0.1 - dziś niewidoczna
+        let report = graph(vec![correlation("c", "cel", RelationType::DirectControl, 0.05, 0.9)])
+            .ranking_sensitivity("cel", &SteeringGoal::Strengthen, &SensitivityConfig::default());
+
+        let hidden = find(&report, "c-cel", EdgeParameter::ImpactFactor);
+        assert_eq!(hidden.gap_derivative, 0.0);
+        assert!(hidden.flip_distance.is_none());
+        assert!(hidden.flips_primary && hidden.top_k_changed);
+        assert_eq!(hidden.flipped_to.as_deref(), Some("c"));
+    }
+
+    #[test]
+    fn test_edges_beyond_path_limit_are_explored() {
+        // Cel ma więcej bezpośrednich wpływowych niż MAX_PATHS - ścieżka x→s0→cel
+        // nie mieści się w rankingu, ale relacja x-s0 nadal jest badana
+        let mut objects = vec![object("cel", 1.0), object("x", 1.0)];
+        let mut correlations = vec![correlation("x", "s0", RelationType::DirectControl, 0.5, 1.0)];
+        for i in 0..=crate::MAX_PATHS {
+            let id = format!("s{}", i);
+            objects.push(object(&id, 0.1));
+            correlations.push(correlation(&id, "cel", RelationType::DirectControl, 0.5, 1.0));
+        }
+        let graph = Graph::new(objects, correlations);
+        let config = SensitivityConfig { top_k: 1, ..SensitivityConfig::default() };
+
+        let report = graph.ranking_sensitivity("cel", &SteeringGoal::Strengthen, &config);
+        assert!(report.edges.iter().any(|e| e.correlation_id == "x-s0"));
+    }
+
+    #[test]
+    fn test_flip_to_empty_ranking_has_no_successor() {
+        // Jedyny wpływowy: impact = 0 zostawia cel bez rekomendacji
+        let graph = Graph::new(
+            vec![object("a", 1.0), object("cel", 1.0)],
+            vec![correlation("a", "cel", RelationType::DirectControl, 0.9, 0.9)],
+        );
+        let report = graph.ranking_sensitivity("cel", &SteeringGoal::Strengthen, &SensitivityConfig::default());
+
+        let impact = find(&report, "a-cel", EdgeParameter::ImpactFactor);
+        assert!(impact.flips_primary);
+        assert!(impact.flipped_to.is_none());
+    }
+
+    #[test]
+    fn test_explored_paths_cap() {
+        // Łańcuch y→x→a→cel: relacja y-x wychodzi dopiero z drugiej ścieżki
+        let graph = Graph::new(
+            vec![object("y", 1.0), object("x", 1.0), object("a", 1.0), object("cel", 1.0)],
+            vec![
+                correlation("a", "cel", RelationType::DirectControl, 0.9, 0.9),
+                correlation("x", "a", RelationType::DirectControl, 0.9, 0.9),
+                correlation("y", "x", RelationType::DirectControl, 0.9, 0.9),
+            ],
+        );
+        let capped = SensitivityConfig { max_explored_paths: Some(1), ..SensitivityConfig::default() };
+
+        let report = graph.ranking_sensitivity("cel", &SteeringGoal::Strengthen, &capped);
+        assert!(report.exploration_truncated);
+        assert!(!report.edges.iter().any(|e| e.correlation_id == "y-x"));
+
+        let report = graph.ranking_sensitivity("cel", &SteeringGoal::Strengthen, &SensitivityConfig::default());
+        assert!(!report.exploration_truncated);
+        assert!(report.edges.iter().any(|e| e.correlation_id == "y-x"));
+    }
+
+    #[test]
+    fn test_no_influencers_no_edges() {
+        let report = graph(Vec::new()).ranking_sensitivity("a", &SteeringGoal::Weaken, &SensitivityConfig::default());
+        assert!(report.primary_object_id.is_none());
+        assert!(report.edges.is_empty());
+    }
+}
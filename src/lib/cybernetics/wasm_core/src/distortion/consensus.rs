@@ -0,0 +1,301 @@
+//! @fileoverview Szacowanie I_real z konsensusu wielu źródeł
+//! @cybernetic Gdy brak prawdy bazowej - odporny konsensus ważony rzetelnością
+//!
+//! `calculate_distortion(i_in, i_real)` zakłada znajomość I_real. W praktyce
+//! mamy kilka konkurujących relacji o tym samym fakcie. Szacujemy I_real jako
+//! ważoną medianę lub ważoną średnią obciętą (wagi = reliability_index źródła),
+//! podajemy przedział ufności i liczymy zniekształcenie każdego źródła
+//! względem konsensusu.
+
+use super::{analyze, DistortionConfig, DistortionReport};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// Stała skalująca MAD do odchylenia standardowego (rozkład normalny)
+const MAD_TO_STD: f64 = 1.4826;
+
+/// Względna wariancja mediany wobec średniej: sqrt(π/2)
+const MEDIAN_EFFICIENCY_FACTOR: f64 = 1.253_314;
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Metoda szacowania konsensusu
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsensusMethod {
+    WeightedMedian,
+    TrimmedMean,
+}
+
+/// @cybernetic Parametry estymatora konsensusu
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsensusConfig {
+    pub method: ConsensusMethod,
+    pub trim_fraction: f64, // Część wagi odcinana z każdego końca (TrimmedMean)
+    pub confidence_z: f64,  // Kwantyl rozkładu normalnego dla przedziału (1.96 = 95%)
+    pub distortion: DistortionConfig,
+}
+
+impl Default for ConsensusConfig {
+    fn default() -> Self {
+        ConsensusConfig {
+            method: ConsensusMethod::WeightedMedian,
+            trim_fraction: 0.2,
+            confidence_z: 1.96,
+            distortion: DistortionConfig::default(),
+        }
+    }
+}
+
+// ============================================================================
+// WEJŚCIE / WYNIK
+// ============================================================================
+
+/// @cybernetic Relacja jednego źródła o danym fakcie
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceReport {
+    pub source_name: String,
+    pub i_in: f64,        // Wartość informacji podana przez źródło
+    pub reliability: f64, // reliability_index z source_intelligence (0-1)
+}
+
+/// @cybernetic Zniekształcenie źródła względem konsensusu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceConsensusDistortion {
+    pub source_name: String,
+    pub i_in: f64,
+    pub weight: f64,
+    pub distortion: DistortionReport,
+}
+
+/// @cybernetic Konsensus: oszacowanie I_real z przedziałem ufności
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsensusEstimate {
+    pub method: ConsensusMethod,
+    pub i_real: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub effective_sources: f64, // Efektywna liczba źródeł (Kish): (Σw)² / Σw²
+    pub robust_spread: f64,     // Ważone MAD × 1.4826
+    pub sources: Vec<SourceConsensusDistortion>,
+}
+
+// ============================================================================
+// ESTYMACJA
+// ============================================================================
+
+/// @cybernetic Szacuje I_real z konsensusu i zniekształcenie każdego źródła
+///
+/// Zwraca `None`, gdy żadne źródło nie ma dodatniej wagi.
+pub fn estimate_consensus(
+    reports: &[SourceReport],
+    config: &ConsensusConfig,
+) -> Option<ConsensusEstimate> {
+    let mut weighted: Vec<(f64, f64)> = reports
+        .iter()
+        .map(|r| (r.i_in.max(0.0), r.reliability.clamp(0.0, 1.0)))
+        .filter(|(_, w)| *w > 0.0)
+        .collect();
+    if weighted.is_empty() {
+        return None;
+    }
+    weighted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let total_weight: f64 = weighted.iter().map(|(_, w)| w).sum();
+    let sum_sq_weight: f64 = weighted.iter().map(|(_, w)| w * w).sum();
+    let effective_sources = total_weight * total_weight / sum_sq_weight;
+
+    let i_real = match config.method {
+        ConsensusMethod::WeightedMedian => weighted_median(&weighted),
+        ConsensusMethod::TrimmedMean => weighted_trimmed_mean(&weighted, config.trim_fraction),
+    };
+
+    // Odporny rozrzut: ważona mediana odchyleń bezwzględnych
+    let mut deviations: Vec<(f64, f64)> = weighted
+        .iter()
+        .map(|(v, w)| ((v - i_real).abs(), *w))
+        .collect();
+    deviations.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    let robust_spread = MAD_TO_STD * weighted_median(&deviations);
+
+    let efficiency = match config.method {
+        ConsensusMethod::WeightedMedian => MEDIAN_EFFICIENCY_FACTOR,
+        ConsensusMethod::TrimmedMean => 1.0,
+    };
+    let half_width = config.confidence_z * efficiency * robust_spread / effective_sources.sqrt();
+
+    let sources = reports
+        .iter()
+        .map(|r| SourceConsensusDistortion {
+            source_name: r.source_name.clone(),
+            i_in: r.i_in,
+            weight: r.reliability.clamp(0.0, 1.0),
+            distortion: analyze(r.i_in, i_real, &config.distortion),
+        })
+        .collect();
+
+    Some(ConsensusEstimate {
+        method: config.method,
+        i_real,
+        lower: (i_real - half_width).max(0.0),
+        upper: i_real + half_width,
+        effective_sources,
+        robust_spread,
+        sources,
+    })
+}
+
+/// Ważona mediana posortowanych par (wartość, waga)
+///
+/// Gdy skumulowana waga trafia dokładnie w połowę, uśrednia dwie sąsiednie wartości.
+fn weighted_median(sorted: &[(f64, f64)]) -> f64 {
+    let half = sorted.iter().map(|(_, w)| w).sum::<f64>() / 2.0;
+    let mut cumulative = 0.0;
+
+    for (idx, (value, weight)) in sorted.iter().enumerate() {
+        cumulative += weight;
+        if (cumulative - half).abs() < 1e-12 {
+            return match sorted.get(idx + 1) {
+                Some((next, _)) => (value + next) / 2.0,
+                None => *value,
+            };
+        }
+        if cumulative > half {
+            return *value;
+        }
+    }
+
+    sorted.last().map(|(v, _)| *v).unwrap_or(0.0)
+}
+
+/// Ważona średnia obcięta - z każdego końca odcinamy `trim` całkowitej wagi
+fn weighted_trimmed_mean(sorted: &[(f64, f64)], trim: f64) -> f64 {
+    let total: f64 = sorted.iter().map(|(_, w)| w).sum();
+    let trim = trim.clamp(0.0, 0.49);
+    let (low, high) = (trim * total, (1.0 - trim) * total);
+
+    let mut cumulative = 0.0;
+    let mut weighted_sum = 0.0;
+    let mut kept_weight = 0.0;
+
+    for (value, weight) in sorted {
+        let start = cumulative;
+        cumulative += weight;
+        // Część wagi źródła mieszcząca się w [low, high]
+        let kept = (cumulative.min(high) - start.max(low)).max(0.0);
+        weighted_sum += value * kept;
+        kept_weight += kept;
+    }
+
+    if kept_weight > 0.0 {
+        weighted_sum / kept_weight
+    } else {
+        weighted_median(sorted)
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Konsensus I_real i zniekształcenia źródeł
+///
+/// Przyjmuje JSON `[{ source_name, i_in, reliability }]`; `config_json` pusty =
+/// ważona mediana z 95% przedziałem. Zwraca JSON ConsensusEstimate albo "null".
+#[wasm_bindgen]
+pub fn wasm_estimate_consensus(reports_json: &str, config_json: &str) -> Result<String, JsValue> {
+    let reports: Vec<SourceReport> = serde_json::from_str(reports_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse reports: {}", e)))?;
+
+    let config = if config_json.trim().is_empty() {
+        ConsensusConfig::default()
+    } else {
+        serde_json::from_str::<ConsensusConfig>(config_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse consensus config: {}", e)))?
+    };
+    config.distortion.validate().map_err(|e| JsValue::from_str(&e))?;
+
+    let result = estimate_consensus(&reports, &config);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distortion::DistortionType;
+
+    fn report(source: &str, i_in: f64, reliability: f64) -> SourceReport {
+        SourceReport {
+            source_name: source.to_string(),
+            i_in,
+            reliability,
+        }
+    }
+
+    #[test]
+    fn test_weighted_median_resists_outlier() {
+        let reports = vec![
+            report("agencja", 100.0, 0.9),
+            report("dziennik", 105.0, 0.8),
+            report("portal", 95.0, 0.7),
+            report("tabloid", 1000.0, 0.3),
+        ];
+
+        let estimate = estimate_consensus(&reports, &ConsensusConfig::default()).unwrap();
+        assert_eq!(estimate.i_real, 100.0);
+        assert!(estimate.lower <= 100.0 && estimate.upper >= 100.0);
+
+        let tabloid = estimate.sources.iter().find(|s| s.source_name == "tabloid").unwrap();
+        assert_eq!(tabloid.distortion.distortion_coefficient, 10.0);
+        assert_eq!(tabloid.distortion.distortion_type, DistortionType::Propaganda);
+
+        let agency = estimate.sources.iter().find(|s| s.source_name == "agencja").unwrap();
+        assert_eq!(agency.distortion.distortion_type, DistortionType::Neutral);
+    }
+
+    #[test]
+    fn test_reliability_weights_shift_median() {
+        let reports = vec![
+            report("a", 50.0, 0.1),
+            report("b", 100.0, 0.1),
+            report("c", 200.0, 0.9),
+        ];
+        let estimate = estimate_consensus(&reports, &ConsensusConfig::default()).unwrap();
+        assert_eq!(estimate.i_real, 200.0);
+    }
+
+    #[test]
+    fn test_trimmed_mean_and_band() {
+        let reports = vec![
+            report("a", 90.0, 1.0),
+            report("b", 100.0, 1.0),
+            report("c", 110.0, 1.0),
+            report("d", 100.0, 1.0),
+            report("e", 500.0, 1.0),
+        ];
+        let config = ConsensusConfig {
+            method: ConsensusMethod::TrimmedMean,
+            ..ConsensusConfig::default()
+        };
+
+        let estimate = estimate_consensus(&reports, &config).unwrap();
+        // Odcięte 20% wagi z każdej strony: zostają 100, 100, 110
+        assert!((estimate.i_real - 310.0 / 3.0).abs() < 1e-9);
+        assert_eq!(estimate.effective_sources, 5.0);
+        assert!(estimate.upper > estimate.i_real && estimate.lower < estimate.i_real);
+    }
+
+    #[test]
+    fn test_no_weighted_sources() {
+        assert!(estimate_consensus(&[report("x", 10.0, 0.0)], &ConsensusConfig::default()).is_none());
+    }
+}
@@ -0,0 +1,414 @@
+//! @fileoverview Analiza zniekształcenia informacji Z = I_in / I_real
+//! @cybernetic Skala logarytmiczna, stopnie nasilenia i kategoria fabrykacji
+//!
+//! Na skali liniowej przesada 2× (Z = 2.0) i przemilczenie połowy (Z = 0.5)
+//! wyglądają na różnie silne. Na skali log-ratio ln(Z) są symetryczne:
+//! ln(2) = 0.693, ln(0.5) = -0.693 - i tak je tu oceniamy.
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+pub mod consensus;
+pub mod tracker;
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Domyślna tolerancja (5%) - jak dotychczasowe TOLERANCE w analyze_distortion
+pub const DEFAULT_DISTORTION_TOLERANCE: f64 = 0.05;
+
+/// @cybernetic Granica |ln Z| - fabrykacja i całkowite przemilczenie dostają ±MAX_LOG_RATIO
+pub const MAX_LOG_RATIO: f64 = 10.0;
+
+/// @cybernetic Górna granica Z = e^MAX_LOG_RATIO (spójna z obciętym ln Z)
+///
+/// Iloraz I_in / I_real przekraczający granicę (także przy podnormalnym
+/// I_real, gdzie dzielenie daje `INFINITY`) jest do niej obcinany.
+pub const MAX_DISTORTION_COEFFICIENT: f64 = 22_026.465_794_806_718;
+
+/// @cybernetic Skończony zamiennik Z dla informacji "z niczego" (I_real = 0, I_in > 0)
+///
+/// Zastępuje `f64::INFINITY`, którego nie da się zserializować do JSON; jest
+/// na tyle mały, że dalsza arytmetyka w JS nie przepełnia się.
+pub const FABRICATION_COEFFICIENT: f64 = MAX_DISTORTION_COEFFICIENT;
+
+/// @cybernetic Parametry analizatora zniekształceń
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DistortionConfig {
+    pub tolerance: f64,     // Odchylenie uznawane za rzetelne (0.05 = ±5% na skali log)
+    pub strong_ratio: f64,  // Krotność od której zniekształcenie jest silne (2× / 0.5×)
+    pub extreme_ratio: f64, // Krotność od której zniekształcenie jest skrajne (5× / 0.2×)
+}
+
+impl Default for DistortionConfig {
+    fn default() -> Self {
+        DistortionConfig {
+            tolerance: DEFAULT_DISTORTION_TOLERANCE,
+            strong_ratio: 2.0,
+            extreme_ratio: 5.0,
+        }
+    }
+}
+
+impl DistortionConfig {
+    /// @cybernetic Wczytuje konfigurację z JSON (brakujące pola = domyślne) i sprawdza progi
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let config: DistortionConfig =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse distortion config: {}", e))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// @cybernetic Progi muszą tworzyć rosnące pasma: 1 + tolerance < strong < extreme
+    ///
+    /// Inaczej `ln` daje NaN (tolerance ≤ -1, ratio ≤ 0) albo pasma się odwracają.
+    pub fn validate(&self) -> Result<(), String> {
+        let finite = self.tolerance.is_finite() && self.strong_ratio.is_finite() && self.extreme_ratio.is_finite();
+        if !finite {
+            return Err("Invalid distortion config: thresholds must be finite".to_string());
+        }
+        if self.tolerance < 0.0 {
+            return Err(format!("Invalid distortion config: tolerance must be >= 0 (got {})", self.tolerance));
+        }
+        if self.strong_ratio <= 1.0 + self.tolerance {
+            return Err(format!(
+                "Invalid distortion config: strong_ratio must be > 1 + tolerance (got {})",
+                self.strong_ratio
+            ));
+        }
+        if self.extreme_ratio <= self.strong_ratio {
+            return Err(format!(
+                "Invalid distortion config: extreme_ratio must be > strong_ratio (got {})",
+                self.extreme_ratio
+            ));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// KATEGORIE I STOPNIE
+// ============================================================================
+
+/// @cybernetic Typ zniekształcenia
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DistortionType {
+    Neutral,     // Informacja rzetelna
+    Propaganda,  // Przesada (Z > 1)
+    Suppression, // Tuszowanie / przemilczenie (Z < 1)
+    Fabrication, // Informacja z niczego (I_real = 0, I_in > 0)
+}
+
+impl DistortionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistortionType::Neutral => "neutral",
+            DistortionType::Propaganda => "propaganda",
+            DistortionType::Suppression => "suppression",
+            DistortionType::Fabrication => "fabrication",
+        }
+    }
+}
+
+/// @cybernetic Stopień nasilenia zniekształcenia
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum DistortionSeverity {
+    None,
+    Mild,
+    Strong,
+    Extreme,
+}
+
+/// @cybernetic Wynik analizy pojedynczej obserwacji
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistortionReport {
+    pub distortion_coefficient: f64, // Z w [0, MAX_DISTORTION_COEFFICIENT] (fabrykacja = FABRICATION_COEFFICIENT)
+    pub log_ratio: f64,              // ln Z, obcięte do ±MAX_LOG_RATIO
+    pub is_distorted: bool,
+    pub distortion_type: DistortionType,
+    pub severity: DistortionSeverity,
+}
+
+// ============================================================================
+// ANALIZA
+// ============================================================================
+
+/// @cybernetic Analizuje zniekształcenie na symetrycznej skali log-ratio
+///
+/// Ujemne wartości informacji (i NaN) traktujemy jak 0, nieskończone jak `f64::MAX`.
+/// Z jest zawsze skończone: obcinane do MAX_DISTORTION_COEFFICIENT.
+pub fn analyze(i_in: f64, i_real: f64, config: &DistortionConfig) -> DistortionReport {
+    let sanitize = |value: f64| if value.is_nan() { 0.0 } else { value.clamp(0.0, f64::MAX) };
+    let i_in = sanitize(i_in);
+    let i_real = sanitize(i_real);
+
+    if i_real == 0.0 {
+        if i_in == 0.0 {
+            // Brak informacji = brak zniekształcenia
+            return report(1.0, 0.0, DistortionType::Neutral, DistortionSeverity::None);
+        }
+        // Informacja z niczego = fabrykacja
+        return report(
+            FABRICATION_COEFFICIENT,
+            MAX_LOG_RATIO,
+            DistortionType::Fabrication,
+            DistortionSeverity::Extreme,
+        );
+    }
+
+    let z = (i_in / i_real).min(MAX_DISTORTION_COEFFICIENT);
+    let log_ratio = if z == 0.0 {
+        -MAX_LOG_RATIO // Całkowite przemilczenie
+    } else {
+        z.ln().clamp(-MAX_LOG_RATIO, MAX_LOG_RATIO)
+    };
+
+    let magnitude = log_ratio.abs();
+    let severity = if magnitude <= config.tolerance.ln_1p() {
+        DistortionSeverity::None
+    } else if magnitude < config.strong_ratio.ln() {
+        DistortionSeverity::Mild
+    } else if magnitude < config.extreme_ratio.ln() {
+        DistortionSeverity::Strong
+    } else {
+        DistortionSeverity::Extreme
+    };
+
+    let distortion_type = match severity {
+        DistortionSeverity::None => DistortionType::Neutral,
+        _ if log_ratio > 0.0 => DistortionType::Propaganda,
+        _ => DistortionType::Suppression,
+    };
+
+    report(z, log_ratio, distortion_type, severity)
+}
+
+fn report(
+    distortion_coefficient: f64,
+    log_ratio: f64,
+    distortion_type: DistortionType,
+    severity: DistortionSeverity,
+) -> DistortionReport {
+    DistortionReport {
+        distortion_coefficient,
+        log_ratio,
+        is_distorted: severity != DistortionSeverity::None,
+        distortion_type,
+        severity,
+    }
+}
+
+// ============================================================================
+// ANALIZA WSADOWA (zbiór artykułów)
+// ============================================================================
+
+/// @cybernetic Obserwacja do analizy wsadowej (np. jeden artykuł)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistortionObservation {
+    pub id: Option<String>,
+    pub i_in: f64,
+    pub i_real: f64,
+}
+
+/// @cybernetic Wynik dla jednej obserwacji w partii
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistortionBatchItem {
+    pub id: Option<String>,
+    #[serde(flatten)]
+    pub report: DistortionReport,
+}
+
+/// @cybernetic Podsumowanie partii obserwacji
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistortionBatchReport {
+    pub items: Vec<DistortionBatchItem>,
+    pub total: usize,
+    pub neutral_count: usize,
+    pub propaganda_count: usize,
+    pub suppression_count: usize,
+    pub fabrication_count: usize,
+    pub mean_log_ratio: f64,       // Średnie ln Z (bez fabrykacji): > 0 przesada, < 0 tuszowanie
+    pub mean_abs_log_ratio: f64,   // Średnia siła zniekształcenia (bez fabrykacji)
+    pub max_severity: DistortionSeverity,
+}
+
+/// @cybernetic Analiza wsadowa zbioru artykułów
+pub fn analyze_batch(
+    observations: &[DistortionObservation],
+    config: &DistortionConfig,
+) -> DistortionBatchReport {
+    let items: Vec<DistortionBatchItem> = observations
+        .iter()
+        .map(|obs| DistortionBatchItem {
+            id: obs.id.clone(),
+            report: analyze(obs.i_in, obs.i_real, config),
+        })
+        .collect();
+
+    let count = |t: DistortionType| items.iter().filter(|i| i.report.distortion_type == t).count();
+
+    let measurable: Vec<f64> = items
+        .iter()
+        .filter(|i| i.report.distortion_type != DistortionType::Fabrication)
+        .map(|i| i.report.log_ratio)
+        .collect();
+    let (mean_log_ratio, mean_abs_log_ratio) = if measurable.is_empty() {
+        (0.0, 0.0)
+    } else {
+        let n = measurable.len() as f64;
+        (
+            measurable.iter().sum::<f64>() / n,
+            measurable.iter().map(|r| r.abs()).sum::<f64>() / n,
+        )
+    };
+
+    DistortionBatchReport {
+        total: items.len(),
+        neutral_count: count(DistortionType::Neutral),
+        propaganda_count: count(DistortionType::Propaganda),
+        suppression_count: count(DistortionType::Suppression),
+        fabrication_count: count(DistortionType::Fabrication),
+        mean_log_ratio,
+        mean_abs_log_ratio,
+        max_severity: items
+            .iter()
+            .map(|i| i.report.severity)
+            .max()
+            .unwrap_or(DistortionSeverity::None),
+        items,
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+fn parse_config_arg(config_json: &str) -> Result<DistortionConfig, JsValue> {
+    if config_json.trim().is_empty() {
+        return Ok(DistortionConfig::default());
+    }
+    DistortionConfig::from_json(config_json).map_err(|e| JsValue::from_str(&e))
+}
+
+/// @cybernetic WASM Entry Point - Analiza zniekształcenia z konfigurowalną tolerancją
+///
+/// `config_json` pusty = tolerancja 5%, silne od 2×, skrajne od 5×
+#[wasm_bindgen]
+pub fn wasm_analyze_distortion(i_in: f64, i_real: f64, config_json: &str) -> Result<String, JsValue> {
+    let config = parse_config_arg(config_json)?;
+    let result = analyze(i_in, i_real, &config);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Analiza wsadowa zbioru artykułów
+///
+/// Przyjmuje JSON `[{ id?, i_in, i_real }]`, zwraca JSON DistortionBatchReport.
+#[wasm_bindgen]
+pub fn wasm_analyze_distortion_batch(
+    observations_json: &str,
+    config_json: &str,
+) -> Result<String, JsValue> {
+    let config = parse_config_arg(config_json)?;
+    let observations: Vec<DistortionObservation> = serde_json::from_str(observations_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse observations: {}", e)))?;
+
+    let result = analyze_batch(&observations, &config);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetric_log_scale() {
+        let config = DistortionConfig::default();
+        let exaggeration = analyze(200.0, 100.0, &config);
+        let suppression = analyze(50.0, 100.0, &config);
+
+        assert_eq!(exaggeration.log_ratio, -suppression.log_ratio);
+        assert_eq!(exaggeration.severity, DistortionSeverity::Strong);
+        assert_eq!(suppression.severity, DistortionSeverity::Strong);
+        assert_eq!(exaggeration.distortion_type, DistortionType::Propaganda);
+        assert_eq!(suppression.distortion_type, DistortionType::Suppression);
+    }
+
+    #[test]
+    fn test_severity_grades_and_tolerance() {
+        let config = DistortionConfig::default();
+        assert_eq!(analyze(104.0, 100.0, &config).severity, DistortionSeverity::None);
+        assert_eq!(analyze(150.0, 100.0, &config).severity, DistortionSeverity::Mild);
+        assert_eq!(analyze(10.0, 100.0, &config).severity, DistortionSeverity::Extreme);
+
+        let strict = DistortionConfig {
+            tolerance: 0.01,
+            ..DistortionConfig::default()
+        };
+        assert_eq!(analyze(104.0, 100.0, &strict).severity, DistortionSeverity::Mild);
+    }
+
+    #[test]
+    fn test_fabrication_is_finite_and_serializable() {
+        let result = analyze(10.0, 0.0, &DistortionConfig::default());
+        assert_eq!(result.distortion_type, DistortionType::Fabrication);
+        assert_eq!(result.severity, DistortionSeverity::Extreme);
+        assert!(result.distortion_coefficient.is_finite());
+        assert!(serde_json::to_string(&result).is_ok());
+
+        let empty = analyze(0.0, 0.0, &DistortionConfig::default());
+        assert_eq!(empty.distortion_type, DistortionType::Neutral);
+
+        // Podnormalne I_real: iloraz = INFINITY, po obcięciu skończony
+        for (i_in, i_real) in [(1.0, 1e-320), (f64::INFINITY, 1.0), (f64::INFINITY, f64::INFINITY)] {
+            let result = analyze(i_in, i_real, &DistortionConfig::default());
+            assert!(result.distortion_coefficient.is_finite(), "{} / {}", i_in, i_real);
+            assert!(result.distortion_coefficient <= MAX_DISTORTION_COEFFICIENT);
+            assert!(!serde_json::to_string(&result).unwrap().contains("null"));
+        }
+        assert_eq!(analyze(1.0, 1e-320, &DistortionConfig::default()).log_ratio, MAX_LOG_RATIO);
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        for json in [
+            r#"{"tolerance": -0.1}"#,
+            r#"{"strong_ratio": 1.0}"#,
+            r#"{"tolerance": 2.0}"#,
+            r#"{"extreme_ratio": 1.5}"#,
+        ] {
+            assert!(DistortionConfig::from_json(json).is_err(), "{}", json);
+        }
+        assert!(DistortionConfig::from_json(r#"{"strong_ratio": 3, "extreme_ratio": 10}"#).is_ok());
+    }
+
+    #[test]
+    fn test_batch_summary() {
+        let observations = vec![
+            DistortionObservation { id: Some("a1".to_string()), i_in: 100.0, i_real: 100.0 },
+            DistortionObservation { id: Some("a2".to_string()), i_in: 200.0, i_real: 100.0 },
+            DistortionObservation { id: Some("a3".to_string()), i_in: 50.0, i_real: 100.0 },
+            DistortionObservation { id: None, i_in: 5.0, i_real: 0.0 },
+        ];
+
+        let report = analyze_batch(&observations, &DistortionConfig::default());
+        assert_eq!(report.total, 4);
+        assert_eq!(report.neutral_count, 1);
+        assert_eq!(report.propaganda_count, 1);
+        assert_eq!(report.suppression_count, 1);
+        assert_eq!(report.fabrication_count, 1);
+        assert!(report.mean_log_ratio.abs() < 1e-12); // 2× i 0.5× się znoszą
+        assert_eq!(report.max_severity, DistortionSeverity::Extreme);
+    }
+}
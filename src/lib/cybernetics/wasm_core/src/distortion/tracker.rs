@@ -0,0 +1,466 @@
+//! @fileoverview Szereg czasowy zniekształceń i detekcja dryfu źródła
+//! @cybernetic Pamięć Homeostatu: jak zmienia się rzetelność źródła w czasie
+//!
+//! Każda obserwacja (źródło, czas, I_in, I_real) jest sprowadzana do ln Z
+//! (moduł `distortion`). Dla każdego źródła utrzymujemy EWMA i wariancję
+//! wykładniczą oraz dwustronny CUSUM względem poziomu bazowego z okresu
+//! rozgrzewki. Przekroczenie progu CUSUM = punkt zmiany, np. rzetelne
+//! dotąd medium zaczyna systematycznie przesadzać.
+
+use super::{analyze, DistortionConfig};
+use crate::homeostat::{AlertStatus, AlertType, SystemAlert};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Parametry trackera zniekształceń
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrackerConfig {
+    pub ewma_alpha: f64,      // Waga nowej obserwacji w EWMA (0-1)
+    pub warmup: usize,        // Liczba obserwacji do wyznaczenia poziomu bazowego
+    pub cusum_slack: f64,     // k - tolerowane odchylenie (w jednostkach σ)
+    pub cusum_threshold: f64, // h - próg alarmu CUSUM (w jednostkach σ)
+    pub min_std: f64,         // Dolna granica σ (źródła idealnie stabilne)
+    pub distortion: DistortionConfig,
+}
+
+impl Default for TrackerConfig {
+    fn default() -> Self {
+        TrackerConfig {
+            ewma_alpha: 0.3,
+            warmup: 5,
+            cusum_slack: 0.5,
+            cusum_threshold: 5.0,
+            min_std: 0.2,
+            distortion: DistortionConfig::default(),
+        }
+    }
+}
+
+impl TrackerConfig {
+    /// @cybernetic Parametry EWMA/CUSUM muszą być skończone i w swoich zakresach
+    ///
+    /// Inaczej statystyki stają się NaN (α spoza (0, 1], σ = 0) albo alarm nie
+    /// może się nigdy włączyć / włącza się przy każdej obserwacji.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.ewma_alpha > 0.0 && self.ewma_alpha <= 1.0) {
+            return Err(format!("Invalid tracker config: ewma_alpha must be in (0, 1] (got {})", self.ewma_alpha));
+        }
+        if self.warmup == 0 {
+            return Err("Invalid tracker config: warmup must be >= 1".to_string());
+        }
+        if !(self.cusum_slack.is_finite() && self.cusum_slack >= 0.0) {
+            return Err(format!(
+                "Invalid tracker config: cusum_slack must be a finite value >= 0 (got {})",
+                self.cusum_slack
+            ));
+        }
+        let positive = [("cusum_threshold", self.cusum_threshold), ("min_std", self.min_std)];
+        if let Some((name, value)) = positive.iter().find(|(_, v)| !(v.is_finite() && *v > 0.0)) {
+            return Err(format!("Invalid tracker config: {} must be a finite value > 0 (got {})", name, value));
+        }
+        self.distortion.validate()
+    }
+}
+
+// ============================================================================
+// OBSERWACJE I STATYSTYKI
+// ============================================================================
+
+/// @cybernetic Pojedyncza obserwacja zniekształcenia
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedObservation {
+    pub source_name: String,
+    pub timestamp: String,
+    pub i_in: f64,
+    pub i_real: f64,
+}
+
+/// @cybernetic Kierunek wykrytego dryfu
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DriftDirection {
+    Exaggeration, // ln Z rośnie - źródło zaczyna przesadzać
+    Suppression,  // ln Z maleje - źródło zaczyna tuszować
+}
+
+/// @cybernetic Statystyki kroczące jednego źródła
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceDistortionStats {
+    pub source_name: String,
+    pub observation_count: usize,
+    pub ewma: f64,             // EWMA ln Z
+    pub variance: f64,         // Wariancja wykładnicza ln Z
+    pub baseline_mean: f64,    // Poziom bazowy ln Z (rozgrzewka / ostatni punkt zmiany)
+    pub baseline_std: f64,
+    pub cusum_positive: f64,   // S+ (kierunek przesady)
+    pub cusum_negative: f64,   // S- (kierunek tuszowania)
+    pub change_point_count: usize,
+    pub last_timestamp: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct SourceState {
+    stats: SourceDistortionStats,
+    // Rozgrzewka (algorytm Welforda)
+    warmup_count: usize,
+    warmup_mean: f64,
+    warmup_m2: f64,
+}
+
+impl SourceState {
+    fn new(source_name: &str) -> Self {
+        SourceState {
+            stats: SourceDistortionStats {
+                source_name: source_name.to_string(),
+                observation_count: 0,
+                ewma: 0.0,
+                variance: 0.0,
+                baseline_mean: 0.0,
+                baseline_std: 0.0,
+                cusum_positive: 0.0,
+                cusum_negative: 0.0,
+                change_point_count: 0,
+                last_timestamp: None,
+            },
+            warmup_count: 0,
+            warmup_mean: 0.0,
+            warmup_m2: 0.0,
+        }
+    }
+}
+
+// ============================================================================
+// TRACKER
+// ============================================================================
+
+/// @cybernetic Tracker zniekształceń per źródło z detekcją punktów zmiany
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct DistortionTracker {
+    config: TrackerConfig,
+    sources: HashMap<String, SourceState>,
+}
+
+impl DistortionTracker {
+    pub fn new(config: TrackerConfig) -> Result<Self, String> {
+        config.validate()?;
+        Ok(DistortionTracker {
+            config,
+            sources: HashMap::new(),
+        })
+    }
+
+    /// @cybernetic Dodaje obserwację; zwraca alert Homeostatu przy punkcie zmiany
+    ///
+    /// Obserwacje jednego źródła należy podawać chronologicznie.
+    pub fn ingest(&mut self, observation: &TrackedObservation) -> Option<SystemAlert> {
+        let config = self.config;
+        let x = analyze(observation.i_in, observation.i_real, &config.distortion).log_ratio;
+
+        let state = self
+            .sources
+            .entry(observation.source_name.clone())
+            .or_insert_with(|| SourceState::new(&observation.source_name));
+
+        // EWMA + wariancja wykładnicza
+        let stats = &mut state.stats;
+        if stats.observation_count == 0 {
+            stats.ewma = x;
+            stats.variance = 0.0;
+        } else {
+            let diff = x - stats.ewma;
+            stats.ewma += config.ewma_alpha * diff;
+            stats.variance = (1.0 - config.ewma_alpha) * (stats.variance + config.ewma_alpha * diff * diff);
+        }
+        stats.observation_count += 1;
+        stats.last_timestamp = Some(observation.timestamp.clone());
+
+        // Rozgrzewka - wyznaczanie poziomu bazowego
+        if state.warmup_count < config.warmup {
+            state.warmup_count += 1;
+            let delta = x - state.warmup_mean;
+            state.warmup_mean += delta / state.warmup_count as f64;
+            state.warmup_m2 += delta * (x - state.warmup_mean);
+
+            stats.baseline_mean = state.warmup_mean;
+            stats.baseline_std = if state.warmup_count > 1 {
+                (state.warmup_m2 / (state.warmup_count - 1) as f64).sqrt()
+            } else {
+                0.0
+            };
+            return None;
+        }
+
+        // Dwustronny CUSUM na wartościach standaryzowanych
+        let sigma = stats.baseline_std.max(config.min_std);
+        let z = (x - stats.baseline_mean) / sigma;
+        stats.cusum_positive = (stats.cusum_positive + z - config.cusum_slack).max(0.0);
+        stats.cusum_negative = (stats.cusum_negative - z - config.cusum_slack).max(0.0);
+
+        let direction = if stats.cusum_positive > config.cusum_threshold {
+            DriftDirection::Exaggeration
+        } else if stats.cusum_negative > config.cusum_threshold {
+            DriftDirection::Suppression
+        } else {
+            return None;
+        };
+
+        let alert = drift_alert(stats, direction, x, &config, &observation.timestamp);
+
+        // Nowy reżim: zerujemy sumy i wyznaczamy poziom bazowy od nowa
+        stats.change_point_count += 1;
+        stats.cusum_positive = 0.0;
+        stats.cusum_negative = 0.0;
+        state.warmup_count = 0;
+        state.warmup_mean = 0.0;
+        state.warmup_m2 = 0.0;
+
+        Some(alert)
+    }
+
+    /// @cybernetic Dodaje partię obserwacji (sortowaną chronologicznie); zwraca alerty
+    pub fn ingest_batch(&mut self, observations: &[TrackedObservation]) -> Vec<SystemAlert> {
+        let mut ordered: Vec<&TrackedObservation> = observations.iter().collect();
+        ordered.sort_by_key(|obs| crate::temporal::parse_timestamp(&obs.timestamp));
+
+        ordered
+            .into_iter()
+            .filter_map(|obs| self.ingest(obs))
+            .collect()
+    }
+
+    /// @cybernetic Statystyki jednego źródła
+    pub fn stats(&self, source_name: &str) -> Option<&SourceDistortionStats> {
+        self.sources.get(source_name).map(|state| &state.stats)
+    }
+
+    /// @cybernetic Statystyki wszystkich źródeł (posortowane po nazwie)
+    pub fn all_stats(&self) -> Vec<&SourceDistortionStats> {
+        let mut stats: Vec<&SourceDistortionStats> =
+            self.sources.values().map(|state| &state.stats).collect();
+        stats.sort_by(|a, b| a.source_name.cmp(&b.source_name));
+        stats
+    }
+}
+
+/// Buduje alert 'narrative_shift' w kształcie SystemAlert
+fn drift_alert(
+    stats: &SourceDistortionStats,
+    direction: DriftDirection,
+    last_log_ratio: f64,
+    config: &TrackerConfig,
+    timestamp: &str,
+) -> SystemAlert {
+    let shift = stats.ewma - stats.baseline_mean;
+    let was_reliable = stats.baseline_mean.abs() <= config.distortion.tolerance.ln_1p();
+
+    let (title, behaviour) = match direction {
+        DriftDirection::Exaggeration => ("zaczyna przesadzać", "przesady"),
+        DriftDirection::Suppression => ("zaczyna tuszować", "tuszowania"),
+    };
+    let history = if was_reliable {
+        "Źródło dotąd rzetelne"
+    } else {
+        "Źródło"
+    };
+
+    // Powaga rośnie z wielkością przesunięcia; przesunięcie o krotność
+    // "skrajną" (domyślnie 5×) daje 1.0
+    let severity = (0.5 + 0.5 * shift.abs() / config.distortion.extreme_ratio.ln()).min(1.0);
+
+    SystemAlert {
+        id: None,
+        alert_type: AlertType::NarrativeShift,
+        severity,
+        title: format!("Dryf zniekształceń: {} {}", stats.source_name, title),
+        description: format!(
+            "{} przesunęło się w kierunku {}: EWMA ln Z z {:.3} do {:.3} (Z ≈ {:.2} → {:.2}), ostatnia obserwacja Z = {:.2}.",
+            history,
+            behaviour,
+            stats.baseline_mean,
+            stats.ewma,
+            stats.baseline_mean.exp(),
+            stats.ewma.exp(),
+            last_log_ratio.exp()
+        ),
+        conflicting_relation_ids: Vec::new(),
+        affected_object_ids: Vec::new(),
+        source_name: Some(stats.source_name.clone()),
+        metadata: serde_json::json!({
+            "detector": "distortion_cusum",
+            "direction": direction,
+            "baseline_log_ratio": stats.baseline_mean,
+            "ewma_log_ratio": stats.ewma,
+            "last_log_ratio": last_log_ratio,
+            "ewma_variance": stats.variance,
+            "cusum_positive": stats.cusum_positive,
+            "cusum_negative": stats.cusum_negative,
+            "observation_count": stats.observation_count,
+            "previously_reliable": was_reliable,
+        }),
+        status: AlertStatus::Active,
+        resolved_at: None,
+        resolved_by: None,
+        created_at: timestamp.to_string(),
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+#[wasm_bindgen]
+impl DistortionTracker {
+    /// @cybernetic Konstruktor dla JS - `config_json` pusty = domyślne parametry
+    #[wasm_bindgen(constructor)]
+    pub fn wasm_new(config_json: &str) -> Result<DistortionTracker, JsValue> {
+        let config = if config_json.trim().is_empty() {
+            TrackerConfig::default()
+        } else {
+            serde_json::from_str::<TrackerConfig>(config_json)
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse tracker config: {}", e)))?
+        };
+        DistortionTracker::new(config).map_err(|e| JsValue::from_str(&e))
+    }
+
+    /// @cybernetic Dodaje obserwację; zwraca JSON SystemAlert albo "null"
+    #[wasm_bindgen(js_name = ingest)]
+    pub fn wasm_ingest(
+        &mut self,
+        source_name: &str,
+        timestamp: &str,
+        i_in: f64,
+        i_real: f64,
+    ) -> Result<String, JsValue> {
+        let alert = self.ingest(&TrackedObservation {
+            source_name: source_name.to_string(),
+            timestamp: timestamp.to_string(),
+            i_in,
+            i_real,
+        });
+
+        serde_json::to_string(&alert)
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+    }
+
+    /// @cybernetic Dodaje partię obserwacji (JSON), zwraca JSON SystemAlert[]
+    #[wasm_bindgen(js_name = ingestBatch)]
+    pub fn wasm_ingest_batch(&mut self, observations_json: &str) -> Result<String, JsValue> {
+        let observations: Vec<TrackedObservation> = serde_json::from_str(observations_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse observations: {}", e)))?;
+
+        let alerts = self.ingest_batch(&observations);
+
+        serde_json::to_string(&alerts)
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+    }
+
+    /// @cybernetic Statystyki wszystkich źródeł jako JSON
+    #[wasm_bindgen(js_name = statsJson)]
+    pub fn wasm_stats_json(&self) -> Result<String, JsValue> {
+        serde_json::to_string(&self.all_stats())
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+    }
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(source: &str, day: usize, i_in: f64) -> TrackedObservation {
+        TrackedObservation {
+            source_name: source.to_string(),
+            timestamp: format!("2025-01-{:02}T12:00:00Z", day),
+            i_in,
+            i_real: 100.0,
+        }
+    }
+
+    #[test]
+    fn test_reliable_source_raises_no_alerts() {
+        let mut tracker = DistortionTracker::default();
+        let readings = [100.0, 101.0, 99.0, 100.5, 99.5, 100.0, 101.0, 99.0, 100.0, 100.5];
+
+        for (day, i_in) in readings.iter().enumerate() {
+            assert!(tracker.ingest(&observation("pap", day + 1, *i_in)).is_none());
+        }
+
+        let stats = tracker.stats("pap").unwrap();
+        assert_eq!(stats.observation_count, 10);
+        assert!(stats.ewma.abs() < 0.02);
+        assert_eq!(stats.change_point_count, 0);
+    }
+
+    #[test]
+    fn test_detects_outlet_starting_to_exaggerate() {
+        let mut tracker = DistortionTracker::default();
+        let mut alerts = Vec::new();
+
+        for day in 1..=5 {
+            alerts.extend(tracker.ingest(&observation("portal", day, 100.0)));
+        }
+        for day in 6..=12 {
+            alerts.extend(tracker.ingest(&observation("portal", day, 200.0)));
+        }
+
+        assert_eq!(alerts.len(), 1);
+        let alert = &alerts[0];
+        assert_eq!(alert.alert_type, AlertType::NarrativeShift);
+        assert_eq!(alert.status, AlertStatus::Active);
+        assert_eq!(alert.source_name.as_deref(), Some("portal"));
+        assert!(alert.title.contains("zaczyna przesadzać"));
+        assert!(alert.description.starts_with("Źródło dotąd rzetelne"));
+        assert!(alert.severity > 0.5 && alert.severity <= 1.0);
+        assert_eq!(alert.metadata["direction"], "exaggeration");
+
+        // Po punkcie zmiany nowy poziom bazowy - stała przesada nie generuje kolejnych alertów
+        assert_eq!(tracker.stats("portal").unwrap().change_point_count, 1);
+    }
+
+    #[test]
+    fn test_batch_sorts_by_time_and_tracks_sources_separately() {
+        let mut tracker = DistortionTracker::default();
+        let mut batch: Vec<TrackedObservation> = (1..=12)
+            .map(|day| observation("tv", day, if day > 5 { 40.0 } else { 100.0 }))
+            .collect();
+        batch.reverse();
+        batch.push(observation("radio", 1, 100.0));
+
+        let alerts = tracker.ingest_batch(&batch);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].metadata["direction"], "suppression");
+        assert_eq!(tracker.all_stats().len(), 2);
+        assert!(serde_json::to_string(&alerts).is_ok());
+    }
+    #[test]
+    fn test_rejects_invalid_config() {
+        let invalid = [
+            TrackerConfig { ewma_alpha: 0.0, ..TrackerConfig::default() },
+            TrackerConfig { ewma_alpha: 1.5, ..TrackerConfig::default() },
+            TrackerConfig { ewma_alpha: f64::NAN, ..TrackerConfig::default() },
+            TrackerConfig { warmup: 0, ..TrackerConfig::default() },
+            TrackerConfig { cusum_slack: -0.5, ..TrackerConfig::default() },
+            TrackerConfig { cusum_threshold: 0.0, ..TrackerConfig::default() },
+            TrackerConfig { cusum_threshold: f64::INFINITY, ..TrackerConfig::default() },
+            TrackerConfig { min_std: -0.2, ..TrackerConfig::default() },
+            TrackerConfig {
+                distortion: DistortionConfig { tolerance: -1.0, ..DistortionConfig::default() },
+                ..TrackerConfig::default()
+            },
+        ];
+        for config in invalid {
+            assert!(DistortionTracker::new(config).is_err(), "{:?}", config);
+        }
+        assert!(DistortionTracker::new(TrackerConfig::default()).is_ok());
+    }
+}
@@ -0,0 +1,198 @@
+//! @fileoverview Model mnożnika sprzężeń zwrotnych
+//! @cybernetic Jak pętle sprzężeń wzmacniają lub tłumią wpływ węzła
+//!
+//! Historyczny wzór (types.ts calculateFeedbackMultiplier) mnoży 1.5 za każde
+//! sprzężenie dodatnie i 0.7 za każde ujemne - po spłaszczeniu typów ze
+//! WSZYSTKICH ścieżek węzła. Węzeł z 20 ścieżkami dostaje więc mnożnik
+//! rzędu 1.5^20. `FeedbackModel` pozwala:
+//! - liczyć mnożnik per ścieżka i uśredniać go po ścieżkach (`mean`),
+//! - ustawić wzmocnienie dla każdego typu relacji,
+//! - nasycić mnożnik: wynik w [1 / saturation_cap, saturation_cap].
+//!
+//! Domyślny model (`product`, 1.5 / 0.7, bez nasycenia) odtwarza stare liczby.
+
+use crate::{InfluencePath, RelationType};
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Sposób łączenia mnożników ścieżek węzła
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PathAggregation {
+    Product, // Zgodność wsteczna: iloczyn po wszystkich relacjach wszystkich ścieżek
+    Mean,    // Średnia z mnożników poszczególnych ścieżek
+}
+
+/// @cybernetic Wzmocnienia typów relacji i sposób agregacji
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeedbackModel {
+    pub direct_control_gain: f64,
+    pub positive_feedback_gain: f64,
+    pub negative_feedback_gain: f64,
+    pub supply_gain: f64,
+    pub aggregation: PathAggregation,
+    pub saturation_cap: Option<f64>, // None = bez nasycenia; wymaga wartości >= 1
+}
+
+impl Default for FeedbackModel {
+    fn default() -> Self {
+        FeedbackModel {
+            direct_control_gain: 1.0,
+            positive_feedback_gain: 1.5, // Wzmacnia
+            negative_feedback_gain: 0.7, // Osłabia
+            supply_gain: 1.0,
+            aggregation: PathAggregation::Product,
+            saturation_cap: None,
+        }
+    }
+}
+
+impl FeedbackModel {
+    /// @cybernetic Wczytuje model z JSON (brakujące pola = domyślne) i sprawdza parametry
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let model: FeedbackModel =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse feedback model: {}", e))?;
+        model.validate()?;
+        Ok(model)
+    }
+
+    /// @cybernetic Odrzuca parametry, których `saturate` nie potrafi zastosować
+    ///
+    /// `saturation_cap` < 1 dawałby pusty przedział [1 / cap, cap] - zamiast
+    /// po cichu pominąć nasycenie, zgłaszamy błąd.
+    pub fn validate(&self) -> Result<(), String> {
+        let gains = [
+            ("direct_control_gain", self.direct_control_gain),
+            ("positive_feedback_gain", self.positive_feedback_gain),
+            ("negative_feedback_gain", self.negative_feedback_gain),
+            ("supply_gain", self.supply_gain),
+        ];
+        if let Some((name, gain)) = gains.iter().find(|(_, g)| !g.is_finite() || *g < 0.0) {
+            return Err(format!("Invalid feedback model: {} must be a finite value >= 0 (got {})", name, gain));
+        }
+        match self.saturation_cap {
+            Some(cap) if !(cap >= 1.0 && cap.is_finite()) => Err(format!(
+                "Invalid feedback model: saturation_cap must be >= 1 (got {})",
+                cap
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// @cybernetic Wzmocnienie pojedynczej relacji
+    pub fn gain(&self, relation_type: &RelationType) -> f64 {
+        match relation_type {
+            RelationType::DirectControl => self.direct_control_gain,
+            RelationType::PositiveFeedback => self.positive_feedback_gain,
+            RelationType::NegativeFeedback => self.negative_feedback_gain,
+            RelationType::Supply => self.supply_gain,
+        }
+    }
+
+    /// Nasycenie mnożnika do [1 / cap, cap]
+    ///
+    /// Modele spoza `from_json` powinny przejść `validate` - tu cap < 1
+    /// oznaczałby odwrócony przedział, więc jest pomijany.
+    fn saturate(&self, multiplier: f64) -> f64 {
+        match self.saturation_cap {
+            Some(cap) if cap >= 1.0 => multiplier.clamp(1.0 / cap, cap),
+            _ => multiplier,
+        }
+    }
+
+    /// @cybernetic Mnożnik ciągu relacji (iloczyn wzmocnień, nasycony)
+    pub fn multiplier(&self, feedback_types: &[RelationType]) -> f64 {
+        self.saturate(feedback_types.iter().map(|t| self.gain(t)).product())
+    }
+
+    /// @cybernetic Mnożnik węzła ze wszystkich jego ścieżek
+    pub fn node_multiplier(&self, paths: &[InfluencePath]) -> f64 {
+        match self.aggregation {
+            PathAggregation::Product => {
+                let all_types: Vec<RelationType> =
+                    paths.iter().flat_map(|p| p.feedback_types.iter().cloned()).collect();
+                self.multiplier(&all_types)
+            }
+            PathAggregation::Mean => {
+                if paths.is_empty() {
+                    return 1.0;
+                }
+                paths.iter().map(|p| self.multiplier(&p.feedback_types)).sum::<f64>() / paths.len() as f64
+            }
+        }
+    }
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(types: Vec<RelationType>) -> InfluencePath {
+        InfluencePath {
+            path: Vec::new(),
+            path_names: Vec::new(),
+            total_strength: 1.0,
+            depth: types.len(),
+            feedback_types: types,
+            certainty_score: 1.0,
+            is_feedback_loop: false,
+            correlation_ids: Vec::new(),
+            polarity: Default::default(),
+            signed_strength: 1.0,
+        }
+    }
+
+    fn paths() -> Vec<InfluencePath> {
+        vec![
+            path(vec![RelationType::PositiveFeedback, RelationType::PositiveFeedback]),
+            path(vec![RelationType::PositiveFeedback]),
+            path(vec![RelationType::NegativeFeedback, RelationType::DirectControl]),
+        ]
+    }
+
+    #[test]
+    fn test_default_reproduces_legacy_product() {
+        let legacy = 1.5 * 1.5 * 1.5 * 0.7;
+        assert!((FeedbackModel::default().node_multiplier(&paths()) - legacy).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_mean_aggregation_and_saturation() {
+        let mean = FeedbackModel {
+            aggregation: PathAggregation::Mean,
+            ..FeedbackModel::default()
+        };
+        assert!((mean.node_multiplier(&paths()) - (2.25 + 1.5 + 0.7) / 3.0).abs() < 1e-12);
+
+        let capped = FeedbackModel {
+            saturation_cap: Some(2.0),
+            ..FeedbackModel::default()
+        };
+        let many: Vec<InfluencePath> = (0..20).map(|_| path(vec![RelationType::PositiveFeedback])).collect();
+        assert_eq!(capped.node_multiplier(&many), 2.0);
+        assert_eq!(capped.multiplier(&vec![RelationType::NegativeFeedback; 5]), 0.5);
+    }
+
+    #[test]
+    fn test_invalid_saturation_cap_is_rejected() {
+        let error = FeedbackModel::from_json(r#"{"saturation_cap": 0.5}"#).unwrap_err();
+        assert!(error.contains("saturation_cap"), "{}", error);
+        assert!(FeedbackModel::from_json(r#"{"supply_gain": -1}"#).is_err());
+        assert!(FeedbackModel::from_json(r#"{"saturation_cap": 1}"#).is_ok());
+    }
+
+    #[test]
+    fn test_custom_gains() {
+        let model: FeedbackModel = serde_json::from_str(r#"{"supply_gain": 1.2, "aggregation": "mean"}"#).unwrap();
+        assert_eq!(model.positive_feedback_gain, 1.5);
+        assert!((model.multiplier(&[RelationType::Supply, RelationType::DirectControl]) - 1.2).abs() < 1e-12);
+    }
+}
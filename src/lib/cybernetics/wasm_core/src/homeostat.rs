@@ -0,0 +1,47 @@
+//! @fileoverview Typy Homeostatu po stronie Rust
+//! @cybernetic Alert systemowy w kształcie tabeli `system_alerts` (homeostat/types.ts)
+//!
+//! Rdzeń nie zapisuje alertów do bazy - zwraca je w formacie gotowym
+//! do `supabase.from('system_alerts').insert(alert)`.
+
+use serde::{Deserialize, Serialize};
+
+/// @cybernetic Typ alertu systemowego (CHECK w schema-homeostat-alerts.sql)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertType {
+    Contradiction,   // Sprzeczność w relacjach
+    NarrativeShift,  // Zmiana narracji tego samego źródła
+    LowCertainty,    // Niska rzetelność
+    IdeologicalFlag, // Wykryto ideologię
+}
+
+/// @cybernetic Status alertu
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertStatus {
+    Active,    // Aktywny, wymaga uwagi
+    Resolved,  // Rozwiązany
+    Dismissed, // Odrzucony jako fałszywy alarm
+}
+
+/// @cybernetic Alert systemowy (odpowiednik SystemAlert z homeostat/types.ts)
+///
+/// `id` nadaje baza (gen_random_uuid), więc przed zapisem jest puste.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemAlert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub alert_type: AlertType,
+    pub severity: f64, // 0-1, gdzie 1 = krytyczne
+    pub title: String,
+    pub description: String,
+    pub conflicting_relation_ids: Vec<String>,
+    pub affected_object_ids: Vec<String>,
+    pub source_name: Option<String>,
+    pub metadata: serde_json::Value,
+    pub status: AlertStatus,
+    pub resolved_at: Option<String>,
+    pub resolved_by: Option<String>,
+    pub created_at: String,
+}
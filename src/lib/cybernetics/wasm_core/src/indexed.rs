@@ -0,0 +1,83 @@
+//! @fileoverview Indeksowana reprezentacja grafu dla algorytmów globalnych
+//! @cybernetic Węzły jako indeksy 0..n, krawędzie z wagą impact_factor × certainty
+//!
+//! Algorytmy operujące na całym grafie (centralność, sterowalność, SCC,
+//! społeczności, layout) potrzebują gęstych indeksów zamiast kluczy String.
+//! Kolejność węzłów = posortowane ID, więc wyniki są deterministyczne.
+
+use crate::{Correlation, Graph};
+use std::collections::HashMap;
+
+/// @cybernetic Waga krawędzi sterowniczej: impact_factor × certainty_score
+pub fn edge_weight(correlation: &Correlation) -> f64 {
+    correlation.impact_factor * correlation.certainty_score
+}
+
+/// @cybernetic Krawędź w grafie indeksowanym
+#[derive(Debug, Clone, Copy)]
+pub struct IndexedEdge {
+    pub source: usize,
+    pub target: usize,
+    pub weight: f64,
+    pub correlation: usize, // Indeks w `Graph::correlations()`
+}
+
+/// @cybernetic Graf z gęstymi indeksami węzłów
+#[derive(Debug, Clone)]
+pub struct IndexedGraph {
+    pub ids: Vec<String>,
+    pub index: HashMap<String, usize>,
+    pub edges: Vec<IndexedEdge>,
+    pub out_edges: Vec<Vec<usize>>, // Indeksy w `edges` wychodzące z węzła
+    pub in_edges: Vec<Vec<usize>>,  // Indeksy w `edges` wchodzące do węzła
+}
+
+impl IndexedGraph {
+    pub fn node_count(&self) -> usize {
+        self.ids.len()
+    }
+}
+
+impl Graph {
+    /// @cybernetic Buduje indeksowaną kopię struktury grafu
+    ///
+    /// Pomija relacje, których źródło lub cel nie jest obiektem grafu.
+    pub fn indexed(&self) -> IndexedGraph {
+        let mut ids: Vec<String> = self.objects().map(|obj| obj.id.clone()).collect();
+        ids.sort();
+
+        let index: HashMap<String, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(idx, id)| (id.clone(), idx))
+            .collect();
+
+        let mut edges = Vec::new();
+        let mut out_edges = vec![Vec::new(); ids.len()];
+        let mut in_edges = vec![Vec::new(); ids.len()];
+
+        for (correlation_idx, corr) in self.correlations().iter().enumerate() {
+            let (Some(&source), Some(&target)) = (index.get(&corr.source_id), index.get(&corr.target_id))
+            else {
+                continue;
+            };
+
+            out_edges[source].push(edges.len());
+            in_edges[target].push(edges.len());
+            edges.push(IndexedEdge {
+                source,
+                target,
+                weight: edge_weight(corr),
+                correlation: correlation_idx,
+            });
+        }
+
+        IndexedGraph {
+            ids,
+            index,
+            edges,
+            out_edges,
+            in_edges,
+        }
+    }
+}
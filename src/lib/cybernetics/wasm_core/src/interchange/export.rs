@@ -0,0 +1,523 @@
+//! @fileoverview Eksport grafu do GraphML, GEXF i DOT
+//! @cybernetic Graf KMS w Gephi, yEd i Graphviz - ze wszystkimi atrybutami
+//!
+//! Węzły niosą wszystkie pola CyberneticObject (plus total_power), krawędzie
+//! wszystkie pola Correlation. Nakładki (`ExportOverlays`) dopisują wyniki
+//! obliczeń: dźwignię sterowniczą względem wybranego celu oraz wyróżnioną
+//! ścieżkę wpływu. Relacje z końcem spoza grafu są pomijane.
+
+use super::{serde_name, AttrType, EDGE_ATTRIBUTES, NODE_ATTRIBUTES};
+use crate::{build_graph_from_json, calculate_total_power, Correlation, CyberneticObject, Graph, SteeringGoal};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Format pliku wynikowego
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Graphml,
+    Gexf,
+    Dot,
+}
+
+impl ExportFormat {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "graphml" => Some(ExportFormat::Graphml),
+            "gexf" => Some(ExportFormat::Gexf),
+            "dot" | "gv" => Some(ExportFormat::Dot),
+            _ => None,
+        }
+    }
+}
+
+/// @cybernetic Nakładki z wynikami obliczeń
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportOverlays {
+    pub leverage_target: Option<String>, // Dźwignia każdego węzła względem celu
+    pub goal: Option<SteeringGoal>,      // Domyślnie strengthen
+    pub highlight_path: Vec<String>,     // ID obiektów ścieżki (source → ... → target)
+}
+
+// ============================================================================
+// TABELA ATRYBUTÓW
+// ============================================================================
+
+#[derive(Debug, Clone)]
+enum AttrValue {
+    Str(String),
+    Num(f64),
+    Bool(bool),
+}
+
+impl AttrValue {
+    fn text(&self) -> String {
+        match self {
+            AttrValue::Str(s) => s.clone(),
+            AttrValue::Num(n) => n.to_string(),
+            AttrValue::Bool(b) => b.to_string(),
+        }
+    }
+}
+
+struct Row {
+    id: String,
+    label: String,
+    source: String, // Tylko krawędzie
+    target: String,
+    weight: f64,
+    values: Vec<Option<AttrValue>>, // Zgodnie z kolejnością definicji
+    highlighted: bool,
+    is_target: bool,
+}
+
+/// Graf spłaszczony do definicji atrybutów i wierszy
+struct Table {
+    node_attrs: Vec<(&'static str, AttrType)>,
+    edge_attrs: Vec<(&'static str, AttrType)>,
+    nodes: Vec<Row>,
+    edges: Vec<Row>,
+}
+
+fn text(value: &str) -> Option<AttrValue> {
+    Some(AttrValue::Str(value.to_string()))
+}
+
+fn optional(value: &Option<String>) -> Option<AttrValue> {
+    value.as_deref().and_then(text)
+}
+
+fn num(value: f64) -> Option<AttrValue> {
+    Some(AttrValue::Num(value))
+}
+
+fn node_values(o: &CyberneticObject) -> Vec<Option<AttrValue>> {
+    vec![
+        text(&o.name),
+        optional(&o.description),
+        text(&serde_name(&o.system_class)),
+        text(&serde_name(&o.control_system_type)),
+        num(o.power_v),
+        num(o.quality_a),
+        num(o.mass_c),
+        num(calculate_total_power(o.power_v, o.quality_a, o.mass_c)),
+        num(o.energy_params.working_power),
+        num(o.energy_params.idle_power),
+        num(o.energy_params.available_power),
+        text(&serde_name(&o.civilization_code)),
+        text(&serde_name(&o.motivation_type)),
+        text(&o.created_at),
+    ]
+}
+
+fn edge_values(c: &Correlation) -> Vec<Option<AttrValue>> {
+    vec![
+        text(&serde_name(&c.relation_type)),
+        num(c.certainty_score),
+        num(c.impact_factor),
+        optional(&c.source_name),
+        optional(&c.superseded_at),
+        optional(&c.superseded_by),
+        text(&c.created_at),
+    ]
+}
+
+impl Graph {
+    fn export_table(&self, overlays: &ExportOverlays) -> Table {
+        let mut objects: Vec<&CyberneticObject> = self.objects().collect();
+        objects.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut node_attrs = NODE_ATTRIBUTES.to_vec();
+        let mut edge_attrs = EDGE_ATTRIBUTES.to_vec();
+
+        // Nakładka dźwigni
+        let leverage: Option<HashMap<String, f64>> = overlays.leverage_target.as_ref().map(|target| {
+            let goal = overlays.goal.clone().unwrap_or(SteeringGoal::Strengthen);
+            self.rank_influential_nodes(target, &goal)
+                .into_iter()
+                .map(|n| (n.object_id, n.control_leverage))
+                .collect()
+        });
+        if leverage.is_some() {
+            node_attrs.push(("leverage", AttrType::Double));
+            node_attrs.push(("is_target", AttrType::Boolean));
+        }
+
+        // Nakładka ścieżki: kolejne pary (source, target)
+        let path_nodes: HashSet<&str> = overlays.highlight_path.iter().map(|s| s.as_str()).collect();
+        let path_edges: HashSet<(&str, &str)> = overlays
+            .highlight_path
+            .windows(2)
+            .map(|w| (w[0].as_str(), w[1].as_str()))
+            .collect();
+        let highlight = !overlays.highlight_path.is_empty();
+        if highlight {
+            node_attrs.push(("on_path", AttrType::Boolean));
+            edge_attrs.push(("on_path", AttrType::Boolean));
+        }
+
+        let nodes = objects
+            .iter()
+            .map(|o| {
+                let mut values = node_values(o);
+                let is_target = overlays.leverage_target.as_deref() == Some(o.id.as_str());
+                if let Some(leverage) = &leverage {
+                    values.push(num(leverage.get(&o.id).copied().unwrap_or(0.0)));
+                    values.push(Some(AttrValue::Bool(is_target)));
+                }
+                let highlighted = path_nodes.contains(o.id.as_str());
+                if highlight {
+                    values.push(Some(AttrValue::Bool(highlighted)));
+                }
+                Row {
+                    id: o.id.clone(),
+                    label: o.name.clone(),
+                    source: String::new(),
+                    target: String::new(),
+                    weight: 0.0,
+                    values,
+                    highlighted,
+                    is_target,
+                }
+            })
+            .collect();
+
+        let edges = self
+            .correlations()
+            .iter()
+            .filter(|c| self.object(&c.source_id).is_some() && self.object(&c.target_id).is_some())
+            .map(|c| {
+                let mut values = edge_values(c);
+                let highlighted = path_edges.contains(&(c.source_id.as_str(), c.target_id.as_str()));
+                if highlight {
+                    values.push(Some(AttrValue::Bool(highlighted)));
+                }
+                Row {
+                    id: c.id.clone(),
+                    label: serde_name(&c.relation_type),
+                    source: c.source_id.clone(),
+                    target: c.target_id.clone(),
+                    weight: c.impact_factor,
+                    values,
+                    highlighted,
+                    is_target: false,
+                }
+            })
+            .collect();
+
+        Table {
+            node_attrs,
+            edge_attrs,
+            nodes,
+            edges,
+        }
+    }
+
+    /// @cybernetic Eksport do wybranego formatu
+    pub fn export(&self, format: ExportFormat, overlays: &ExportOverlays) -> String {
+        let table = self.export_table(overlays);
+        match format {
+            ExportFormat::Graphml => write_graphml(&table),
+            ExportFormat::Gexf => write_gexf(&table),
+            ExportFormat::Dot => write_dot(&table),
+        }
+    }
+
+    pub fn to_graphml(&self, overlays: &ExportOverlays) -> String {
+        self.export(ExportFormat::Graphml, overlays)
+    }
+
+    pub fn to_gexf(&self, overlays: &ExportOverlays) -> String {
+        self.export(ExportFormat::Gexf, overlays)
+    }
+
+    pub fn to_dot(&self, overlays: &ExportOverlays) -> String {
+        self.export(ExportFormat::Dot, overlays)
+    }
+}
+
+// ============================================================================
+// ZAPIS FORMATÓW
+// ============================================================================
+
+/// Escapowanie tekstu i atrybutów XML
+pub(crate) fn xml_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Napis DOT w cudzysłowach
+fn dot_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+fn write_graphml(table: &Table) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    for (domain, prefix, attrs) in [("node", "n_", &table.node_attrs), ("edge", "e_", &table.edge_attrs)] {
+        for (name, ty) in attrs.iter() {
+            let _ = writeln!(
+                out,
+                "  <key id=\"{}{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+                prefix,
+                name,
+                domain,
+                name,
+                ty.graphml_name()
+            );
+        }
+    }
+    out.push_str("  <graph id=\"kms\" edgedefault=\"directed\">\n");
+
+    let data = |out: &mut String, prefix: &str, attrs: &[(&str, AttrType)], row: &Row| {
+        for ((name, _), value) in attrs.iter().zip(&row.values) {
+            if let Some(value) = value {
+                let _ = writeln!(out, "      <data key=\"{}{}\">{}</data>", prefix, name, xml_escape(&value.text()));
+            }
+        }
+    };
+    for node in &table.nodes {
+        let _ = writeln!(out, "    <node id=\"{}\">", xml_escape(&node.id));
+        data(&mut out, "n_", &table.node_attrs, node);
+        out.push_str("    </node>\n");
+    }
+    for edge in &table.edges {
+        let _ = writeln!(
+            out,
+            "    <edge id=\"{}\" source=\"{}\" target=\"{}\">",
+            xml_escape(&edge.id),
+            xml_escape(&edge.source),
+            xml_escape(&edge.target)
+        );
+        data(&mut out, "e_", &table.edge_attrs, edge);
+        out.push_str("    </edge>\n");
+    }
+
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn write_gexf(table: &Table) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n");
+    out.push_str("  <graph defaultedgetype=\"directed\" mode=\"static\">\n");
+    for (class, attrs) in [("node", &table.node_attrs), ("edge", &table.edge_attrs)] {
+        let _ = writeln!(out, "    <attributes class=\"{}\">", class);
+        for (name, ty) in attrs.iter() {
+            let _ = writeln!(
+                out,
+                "      <attribute id=\"{}\" title=\"{}\" type=\"{}\"/>",
+                name,
+                name,
+                ty.graphml_name()
+            );
+        }
+        out.push_str("    </attributes>\n");
+    }
+
+    let attvalues = |out: &mut String, attrs: &[(&str, AttrType)], row: &Row| {
+        out.push_str("        <attvalues>\n");
+        for ((name, _), value) in attrs.iter().zip(&row.values) {
+            if let Some(value) = value {
+                let _ = writeln!(
+                    out,
+                    "          <attvalue for=\"{}\" value=\"{}\"/>",
+                    name,
+                    xml_escape(&value.text())
+                );
+            }
+        }
+        out.push_str("        </attvalues>\n");
+    };
+
+    out.push_str("    <nodes>\n");
+    for node in &table.nodes {
+        let _ = writeln!(
+            out,
+            "      <node id=\"{}\" label=\"{}\">",
+            xml_escape(&node.id),
+            xml_escape(&node.label)
+        );
+        attvalues(&mut out, &table.node_attrs, node);
+        out.push_str("      </node>\n");
+    }
+    out.push_str("    </nodes>\n    <edges>\n");
+    for edge in &table.edges {
+        let _ = writeln!(
+            out,
+            "      <edge id=\"{}\" source=\"{}\" target=\"{}\" weight=\"{}\" label=\"{}\">",
+            xml_escape(&edge.id),
+            xml_escape(&edge.source),
+            xml_escape(&edge.target),
+            edge.weight,
+            xml_escape(&edge.label)
+        );
+        attvalues(&mut out, &table.edge_attrs, edge);
+        out.push_str("      </edge>\n");
+    }
+    out.push_str("    </edges>\n  </graph>\n</gexf>\n");
+    out
+}
+
+fn write_dot(table: &Table) -> String {
+    let attributes = |attrs: &[(&str, AttrType)], row: &Row, extra: &mut Vec<String>| {
+        for ((name, _), value) in attrs.iter().zip(&row.values) {
+            if let Some(value) = value {
+                extra.push(format!("{}={}", name, dot_quote(&value.text())));
+            }
+        }
+        extra.join(", ")
+    };
+
+    let mut out = String::from("digraph kms {\n");
+    for node in &table.nodes {
+        let mut extra = vec![format!("label={}", dot_quote(&node.label))];
+        if node.is_target {
+            extra.push("shape=doublecircle".to_string());
+        }
+        if node.highlighted {
+            extra.push("color=\"red\"".to_string());
+            extra.push("penwidth=2".to_string());
+        }
+        let _ = writeln!(out, "  {} [{}];", dot_quote(&node.id), attributes(&table.node_attrs, node, &mut extra));
+    }
+    for edge in &table.edges {
+        let mut extra = vec![format!("id={}", dot_quote(&edge.id)), format!("label={}", dot_quote(&edge.label))];
+        if edge.highlighted {
+            extra.push("color=\"red\"".to_string());
+            extra.push("penwidth=3".to_string());
+        }
+        let _ = writeln!(
+            out,
+            "  {} -> {} [{}];",
+            dot_quote(&edge.source),
+            dot_quote(&edge.target),
+            attributes(&table.edge_attrs, edge, &mut extra)
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Eksport grafu ("graphml" | "gexf" | "dot")
+///
+/// `overlays_json` pusty = bez nakładek, np.
+/// `{ "leverage_target": "x", "goal": "weaken", "highlight_path": ["a", "b", "x"] }`.
+#[wasm_bindgen]
+pub fn wasm_export_graph(
+    objects_json: &str,
+    correlations_json: &str,
+    format: &str,
+    overlays_json: &str,
+) -> Result<String, JsValue> {
+    let format = ExportFormat::parse(format)
+        .ok_or_else(|| JsValue::from_str("Invalid format: must be 'graphml', 'gexf' or 'dot'"))?;
+    let overlays = if overlays_json.trim().is_empty() {
+        ExportOverlays::default()
+    } else {
+        serde_json::from_str(overlays_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse export overlays: {}", e)))?
+    };
+
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    Ok(graph.export(format, &overlays))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{correlation, object};
+    use crate::RelationType;
+
+    fn sample() -> Graph {
+        let mut a = object("a", 2.0);
+        a.name = "A & <Co>".to_string();
+        a.power_v = 2.0;
+        a.quality_a = 0.5;
+        a.mass_c = 3.0;
+        Graph::new(
+            vec![a, object("b", 1.0), object("cel", 1.0)],
+            vec![
+                correlation("a", "b", RelationType::PositiveFeedback, 0.8, 0.9),
+                correlation("b", "cel", RelationType::DirectControl, 0.5, 1.0),
+                correlation("a", "ghost", RelationType::Supply, 0.5, 1.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_graphml_keeps_all_attributes() {
+        let xml = sample().to_graphml(&ExportOverlays::default());
+
+        assert!(xml.contains("<key id=\"n_total_power\" for=\"node\" attr.name=\"total_power\" attr.type=\"double\"/>"));
+        assert!(xml.contains("<data key=\"n_name\">A &amp; &lt;Co&gt;</data>"));
+        assert!(xml.contains("<data key=\"n_total_power\">3</data>"));
+        assert!(xml.contains("<data key=\"e_relation_type\">positive_feedback</data>"));
+        assert!(xml.contains("<data key=\"e_certainty_score\">0.9</data>"));
+        assert!(!xml.contains("<data key=\"n_description\">")); // None = brak <data>
+        assert_eq!(xml.matches("<edge ").count(), 2); // Relacja do "ghost" pominięta
+        assert!(!xml.contains("leverage"));
+    }
+
+    #[test]
+    fn test_gexf_with_leverage_overlay() {
+        let overlays = ExportOverlays {
+            leverage_target: Some("cel".to_string()),
+            ..Default::default()
+        };
+        let xml = sample().to_gexf(&overlays);
+
+        assert!(xml.contains("<attribute id=\"leverage\" title=\"leverage\" type=\"double\"/>"));
+        assert!(xml.contains("<node id=\"a\" label=\"A &amp; &lt;Co&gt;\">"));
+        assert!(xml.contains("weight=\"0.8\" label=\"positive_feedback\""));
+        // b: P=1 × I=0.5 × F=1 × C=1
+        assert!(xml.contains("<node id=\"b\" label=\"B\">\n        <attvalues>"));
+        let b = &xml[xml.find("<node id=\"b\"").unwrap()..];
+        assert!(b.contains("<attvalue for=\"leverage\" value=\"0.5\"/>"));
+        let target = &xml[xml.find("<node id=\"cel\"").unwrap()..];
+        assert!(target.contains("<attvalue for=\"is_target\" value=\"true\"/>"));
+    }
+
+    #[test]
+    fn test_dot_highlights_path() {
+        let overlays = ExportOverlays {
+            highlight_path: vec!["a".to_string(), "b".to_string(), "cel".to_string()],
+            ..Default::default()
+        };
+        let dot = sample().to_dot(&overlays);
+
+        assert!(dot.starts_with("digraph kms {"));
+        assert!(dot.contains("\"a\" -> \"b\" [id=\"a-b\", label=\"positive_feedback\", color=\"red\", penwidth=3"));
+        assert!(dot.contains("\"b\" -> \"cel\" [id=\"b-cel\", label=\"direct_control\", color=\"red\""));
+        assert!(dot.contains("on_path=\"true\""));
+        assert_eq!(ExportFormat::parse("GV"), Some(ExportFormat::Dot));
+    }
+}
@@ -0,0 +1,713 @@
+//! @fileoverview Import grafu z CSV i GraphML
+//! @cybernetic Arkusze i pliki Gephi partnerów → CyberneticObject / Correlation
+//!
+//! Wartości brakujące uzupełniamy domyślnymi z schema.sql: power_v = quality_a
+//! = mass_c = 1, energy_params = 0, civilization_code = unknown, motivation_type
+//! = informational, certainty_score = 0, impact_factor = 1. Kolumny bez
+//! domyślnej w schemacie (system_class, control_system_type, created_at)
+//! biorą wartość z `ImportConfig`.
+//!
+//! Wiersz z błędem jest pomijany i raportowany w `errors` (z numerem wiersza
+//! i kolumną) - reszta pliku wczytuje się normalnie. Nazwy kolumn / atrybutów
+//! to nazwy pól (jak w eksporcie), z aliasami Gephi: label → name,
+//! source → source_id, target → target_id, weight → impact_factor.
+
+use super::xml::{self, XmlEvent};
+use crate::{
+    CivilizationCode, ControlSystemType, Correlation, CyberneticObject, EnergyParams, Graph,
+    MotivationType, RelationType, SystemClass,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use wasm_bindgen::prelude::*;
+
+/// Pola obiektu rozpoznawane przy imporcie
+pub const OBJECT_FIELDS: &[&str] = &[
+    "id",
+    "name",
+    "description",
+    "system_class",
+    "control_system_type",
+    "power_v",
+    "quality_a",
+    "mass_c",
+    "working_power",
+    "idle_power",
+    "available_power",
+    "civilization_code",
+    "motivation_type",
+    "created_at",
+];
+
+/// Pola relacji rozpoznawane przy imporcie
+pub const CORRELATION_FIELDS: &[&str] = &[
+    "id",
+    "source_id",
+    "target_id",
+    "relation_type",
+    "certainty_score",
+    "impact_factor",
+    "source_name",
+    "superseded_at",
+    "superseded_by",
+    "created_at",
+];
+
+/// Aliasy nazw kolumn (Gephi) → pole
+const FIELD_ALIASES: &[(&str, &str)] = &[
+    ("label", "name"),
+    ("source", "source_id"),
+    ("target", "target_id"),
+    ("weight", "impact_factor"),
+];
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Mapowanie kolumn i wartości domyślne spoza schema.sql
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportConfig {
+    pub delimiter: char,
+    pub object_columns: HashMap<String, String>,      // pole → nagłówek kolumny
+    pub correlation_columns: HashMap<String, String>, // pole → nagłówek kolumny
+    pub default_system_class: SystemClass,
+    pub default_control_system_type: ControlSystemType,
+    pub default_relation_type: Option<RelationType>, // None = relation_type wymagane (NOT NULL)
+    pub default_created_at: String,
+}
+
+impl Default for ImportConfig {
+    fn default() -> Self {
+        ImportConfig {
+            delimiter: ',',
+            object_columns: HashMap::new(),
+            correlation_columns: HashMap::new(),
+            default_system_class: SystemClass::AutonomousSystem,
+            default_control_system_type: ControlSystemType::Cognitive,
+            default_relation_type: None,
+            default_created_at: "1970-01-01T00:00:00Z".to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// WYNIK
+// ============================================================================
+
+/// @cybernetic Która tabela zawiera błędny wiersz
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportTable {
+    Objects,
+    Correlations,
+}
+
+/// @cybernetic Błąd pojedynczego wiersza (wiersz pominięty)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportRowError {
+    pub table: ImportTable,
+    pub row: usize,             // CSV: numer linii (nagłówek = 1); GraphML: numer elementu (od 1)
+    pub id: Option<String>,
+    pub field: Option<String>,
+    pub message: String,
+}
+
+/// @cybernetic Wynik importu
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub objects: Vec<CyberneticObject>,
+    pub correlations: Vec<Correlation>,
+    pub errors: Vec<ImportRowError>,
+}
+
+impl ImportReport {
+    pub fn into_graph(self) -> Graph {
+        Graph::new(self.objects, self.correlations)
+    }
+}
+
+// ============================================================================
+// MAPOWANIE PÓL
+// ============================================================================
+
+/// Wartości jednego wiersza: pole → surowy tekst (niepusty, przycięty)
+type Record = HashMap<&'static str, String>;
+
+/// Błąd pola: (pole, komunikat)
+type FieldError = (Option<&'static str>, String);
+
+/// Kanoniczna nazwa pola dla nagłówka / attr.name
+fn canonical_field(name: &str, fields: &[&'static str]) -> Option<&'static str> {
+    let name = name.trim().to_ascii_lowercase();
+    fields.iter().copied().find(|f| *f == name).or_else(|| {
+        FIELD_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == name)
+            .and_then(|(_, field)| fields.iter().copied().find(|f| f == field))
+    })
+}
+
+fn number(record: &Record, field: &'static str, default: f64, errors: &mut Vec<FieldError>) -> f64 {
+    match record.get(field) {
+        None => default,
+        Some(raw) => match raw.replace(',', ".").parse::<f64>() {
+            Ok(v) if v.is_finite() => v,
+            _ => {
+                errors.push((Some(field), format!("'{}' is not a number", raw)));
+                default
+            }
+        },
+    }
+}
+
+/// Enum z nazwy serde (wielkość liter bez znaczenia)
+fn enumeration<T: DeserializeOwned>(record: &Record, field: &'static str, errors: &mut Vec<FieldError>) -> Option<T> {
+    let raw = record.get(field)?;
+    let value = serde_json::Value::String(raw.to_ascii_lowercase().replace([' ', '-'], "_"));
+    match serde_json::from_value(value) {
+        Ok(v) => Some(v),
+        Err(_) => {
+            errors.push((Some(field), format!("unknown value '{}'", raw)));
+            None
+        }
+    }
+}
+
+/// Sprawdzenie zakresu jak CHECK w schema.sql
+fn check_range(value: f64, field: &'static str, min: f64, max: f64, errors: &mut Vec<FieldError>) {
+    if value < min || value > max {
+        errors.push((Some(field), format!("{} is outside [{}, {}]", value, min, max)));
+    }
+}
+
+fn build_object(record: &Record, config: &ImportConfig) -> Result<CyberneticObject, Vec<FieldError>> {
+    let mut errors = Vec::new();
+    let id = record.get("id").cloned();
+    if id.is_none() {
+        errors.push((Some("id"), "missing id".to_string()));
+    }
+
+    let power_v = number(record, "power_v", 1.0, &mut errors);
+    let quality_a = number(record, "quality_a", 1.0, &mut errors);
+    let mass_c = number(record, "mass_c", 1.0, &mut errors);
+    check_range(power_v, "power_v", 0.0, f64::INFINITY, &mut errors);
+    check_range(quality_a, "quality_a", 0.0, 1.0, &mut errors);
+    check_range(mass_c, "mass_c", 0.0, f64::INFINITY, &mut errors);
+
+    let object = CyberneticObject {
+        name: record.get("name").or(id.as_ref()).cloned().unwrap_or_default(),
+        id: id.unwrap_or_default(),
+        description: record.get("description").cloned(),
+        system_class: enumeration(record, "system_class", &mut errors)
+            .unwrap_or_else(|| config.default_system_class.clone()),
+        control_system_type: enumeration(record, "control_system_type", &mut errors)
+            .unwrap_or_else(|| config.default_control_system_type.clone()),
+        energy_params: EnergyParams {
+            working_power: number(record, "working_power", 0.0, &mut errors),
+            idle_power: number(record, "idle_power", 0.0, &mut errors),
+            available_power: number(record, "available_power", 0.0, &mut errors),
+        },
+        power_v,
+        quality_a,
+        mass_c,
+        civilization_code: enumeration(record, "civilization_code", &mut errors).unwrap_or(CivilizationCode::Unknown),
+        motivation_type: enumeration(record, "motivation_type", &mut errors).unwrap_or(MotivationType::Informational),
+        created_at: record.get("created_at").cloned().unwrap_or_else(|| config.default_created_at.clone()),
+    };
+
+    if errors.is_empty() {
+        Ok(object)
+    } else {
+        Err(errors)
+    }
+}
+
+fn build_correlation(record: &Record, row: usize, config: &ImportConfig) -> Result<Correlation, Vec<FieldError>> {
+    let mut errors = Vec::new();
+    let source_id = record.get("source_id").cloned();
+    let target_id = record.get("target_id").cloned();
+    if source_id.is_none() {
+        errors.push((Some("source_id"), "missing source_id".to_string()));
+    }
+    if target_id.is_none() {
+        errors.push((Some("target_id"), "missing target_id".to_string()));
+    }
+
+    let relation_type = enumeration(record, "relation_type", &mut errors).or_else(|| config.default_relation_type.clone());
+    if relation_type.is_none() && !record.contains_key("relation_type") {
+        errors.push((Some("relation_type"), "missing relation_type".to_string()));
+    }
+
+    let certainty_score = number(record, "certainty_score", 0.0, &mut errors);
+    check_range(certainty_score, "certainty_score", 0.0, 1.0, &mut errors);
+
+    let correlation = Correlation {
+        id: record.get("id").cloned().unwrap_or_else(|| format!("row-{}", row)),
+        source_id: source_id.unwrap_or_default(),
+        target_id: target_id.unwrap_or_default(),
+        relation_type: relation_type.unwrap_or(RelationType::DirectControl),
+        certainty_score,
+        impact_factor: number(record, "impact_factor", 1.0, &mut errors),
+        source_name: record.get("source_name").cloned(),
+        superseded_at: record.get("superseded_at").cloned(),
+        superseded_by: record.get("superseded_by").cloned(),
+        created_at: record.get("created_at").cloned().unwrap_or_else(|| config.default_created_at.clone()),
+    };
+
+    if errors.is_empty() {
+        Ok(correlation)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Zbiera poprawne wiersze i błędy (duplikaty ID, nieznane końce relacji)
+#[derive(Default)]
+struct Collector {
+    report: ImportReport,
+    object_ids: HashSet<String>,
+    correlation_ids: HashSet<String>,
+}
+
+impl Collector {
+    fn fail(&mut self, table: ImportTable, row: usize, id: Option<&String>, errors: Vec<FieldError>) {
+        for (field, message) in errors {
+            self.report.errors.push(ImportRowError {
+                table,
+                row,
+                id: id.cloned(),
+                field: field.map(str::to_string),
+                message,
+            });
+        }
+    }
+
+    fn object(&mut self, record: &Record, row: usize, config: &ImportConfig) {
+        match build_object(record, config) {
+            Ok(object) if self.object_ids.contains(&object.id) => {
+                let message = format!("duplicate object id '{}'", object.id);
+                self.fail(ImportTable::Objects, row, Some(&object.id), vec![(Some("id"), message)]);
+            }
+            Ok(object) => {
+                self.object_ids.insert(object.id.clone());
+                self.report.objects.push(object);
+            }
+            Err(errors) => self.fail(ImportTable::Objects, row, record.get("id"), errors),
+        }
+    }
+
+    fn correlation(&mut self, record: &Record, row: usize, config: &ImportConfig) {
+        let table = ImportTable::Correlations;
+        let correlation = match build_correlation(record, row, config) {
+            Ok(correlation) => correlation,
+            Err(errors) => return self.fail(table, row, record.get("id"), errors),
+        };
+
+        let mut errors = Vec::new();
+        for (field, endpoint) in [("source_id", &correlation.source_id), ("target_id", &correlation.target_id)] {
+            if !self.object_ids.contains(endpoint) {
+                errors.push((Some(field), format!("unknown object '{}'", endpoint)));
+            }
+        }
+        if self.correlation_ids.contains(&correlation.id) {
+            errors.push((Some("id"), format!("duplicate correlation id '{}'", correlation.id)));
+        }
+
+        if errors.is_empty() {
+            self.correlation_ids.insert(correlation.id.clone());
+            self.report.correlations.push(correlation);
+        } else {
+            self.fail(table, row, Some(&correlation.id), errors);
+        }
+    }
+}
+
+// ============================================================================
+// CSV
+// ============================================================================
+
+/// @cybernetic Parser CSV (RFC 4180): cudzysłowy, "" w polu, nowe linie w polach
+///
+/// Zwraca rekordy z numerem linii, w której się zaczynają.
+pub fn parse_csv(input: &str, delimiter: char) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = input.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if ch == '\n' {
+                        line += 1;
+                    }
+                    field.push(ch);
+                }
+            }
+            continue;
+        }
+
+        match ch {
+            '"' if field.is_empty() => in_quotes = true,
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.trim().is_empty()) {
+                    records.push((record_line, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(ch),
+        }
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.trim().is_empty()) {
+        records.push((record_line, record));
+    }
+    records
+}
+
+/// Rekordy CSV → wiersze pól (mapowanie: konfiguracja, potem nazwa pola / alias)
+fn csv_records(
+    input: &str,
+    fields: &[&'static str],
+    mapping: &HashMap<String, String>,
+    delimiter: char,
+) -> Vec<(usize, Record)> {
+    let mut rows = parse_csv(input, delimiter).into_iter();
+    let Some((_, header)) = rows.next() else {
+        return Vec::new();
+    };
+
+    let columns: Vec<(usize, &'static str)> = header
+        .iter()
+        .enumerate()
+        .filter_map(|(i, name)| {
+            let name = name.trim();
+            let mapped = mapping
+                .iter()
+                .find(|(_, column)| column.trim().eq_ignore_ascii_case(name))
+                .and_then(|(field, _)| fields.iter().copied().find(|f| f == field));
+            mapped.or_else(|| canonical_field(name, fields)).map(|f| (i, f))
+        })
+        .collect();
+
+    rows.map(|(line, values)| {
+        let mut record = Record::new();
+        for &(i, field) in &columns {
+            if let Some(value) = values.get(i).map(|v| v.trim()).filter(|v| !v.is_empty()) {
+                record.entry(field).or_insert_with(|| value.to_string());
+            }
+        }
+        (line, record)
+    })
+    .collect()
+}
+
+/// @cybernetic Import obiektów i relacji z dwóch plików CSV
+pub fn import_csv(objects_csv: &str, correlations_csv: &str, config: &ImportConfig) -> ImportReport {
+    let mut collector = Collector::default();
+    for (line, record) in csv_records(objects_csv, OBJECT_FIELDS, &config.object_columns, config.delimiter) {
+        collector.object(&record, line, config);
+    }
+    for (line, record) in csv_records(
+        correlations_csv,
+        CORRELATION_FIELDS,
+        &config.correlation_columns,
+        config.delimiter,
+    ) {
+        collector.correlation(&record, line, config);
+    }
+    collector.report
+}
+
+// ============================================================================
+// GRAPHML
+// ============================================================================
+
+fn attribute<'a>(attributes: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attributes.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+}
+
+/// @cybernetic Import z GraphML (Gephi, yEd, eksport rdzenia)
+///
+/// Atrybuty `<key>` mapowane są po `attr.name`; `<default>` klucza uzupełnia
+/// brakujące `<data>`. Błąd składni XML przerywa import całego pliku.
+pub fn import_graphml(input: &str, config: &ImportConfig) -> Result<ImportReport, xml::XmlError> {
+    let events = xml::parse(input)?;
+
+    // Klucze: id → (domena, pole), wartości domyślne
+    let mut keys: HashMap<String, (String, &'static str)> = HashMap::new();
+    let mut defaults: Vec<(String, &'static str, String)> = Vec::new();
+
+    let mut nodes: Vec<Record> = Vec::new();
+    let mut edges: Vec<Record> = Vec::new();
+    let mut current: Option<(bool, Record)> = None; // (czy krawędź, pola)
+    let mut current_key: Option<String> = None;
+    let mut data_key: Option<String> = None;
+    let mut text = String::new();
+
+    for event in events {
+        match event {
+            XmlEvent::Start { name, attributes, self_closing } => match name.as_str() {
+                "key" => {
+                    let id = attribute(&attributes, "id").unwrap_or_default().to_string();
+                    let domain = attribute(&attributes, "for").unwrap_or("all").to_string();
+                    let attr_name = attribute(&attributes, "attr.name").unwrap_or(&id);
+                    let fields = if domain == "edge" { CORRELATION_FIELDS } else { OBJECT_FIELDS };
+                    if let Some(field) = canonical_field(attr_name, fields) {
+                        keys.insert(id.clone(), (domain, field));
+                    }
+                    current_key = (!self_closing).then_some(id);
+                }
+                "default" => text.clear(),
+                "node" | "edge" => {
+                    let is_edge = name == "edge";
+                    let mut record = Record::new();
+                    let structural: &[(&str, &'static str)] = if is_edge {
+                        &[("id", "id"), ("source", "source_id"), ("target", "target_id")]
+                    } else {
+                        &[("id", "id")]
+                    };
+                    for (attr, field) in structural {
+                        if let Some(value) = attribute(&attributes, attr).filter(|v| !v.trim().is_empty()) {
+                            record.insert(field, value.trim().to_string());
+                        }
+                    }
+                    if self_closing {
+                        if is_edge { edges.push(record) } else { nodes.push(record) }
+                    } else if current.is_none() {
+                        current = Some((is_edge, record));
+                    }
+                }
+                "data" if current.is_some() => {
+                    data_key = attribute(&attributes, "key").map(str::to_string);
+                    text.clear();
+                }
+                _ => {}
+            },
+            XmlEvent::Text(t) => text.push_str(&t),
+            XmlEvent::End { name } => match name.as_str() {
+                "default" => {
+                    if let Some((domain, field)) = current_key.as_ref().and_then(|k| keys.get(k)) {
+                        defaults.push((domain.clone(), field, text.trim().to_string()));
+                    }
+                }
+                "key" => current_key = None,
+                "data" => {
+                    if let (Some(key), Some((is_edge, record))) = (data_key.take(), current.as_mut()) {
+                        let domain_ok = |d: &str| d == "all" || d == if *is_edge { "edge" } else { "node" };
+                        if let Some((_, field)) = keys.get(&key).filter(|(d, _)| domain_ok(d)) {
+                            let value = text.trim();
+                            // Atrybuty strukturalne (id, source, target) mają pierwszeństwo
+                            if !value.is_empty() && !(*is_edge && (*field == "source_id" || *field == "target_id")) {
+                                record.entry(field).or_insert_with(|| value.to_string());
+                            }
+                        }
+                    }
+                }
+                "node" | "edge" => {
+                    if let Some((is_edge, record)) = current.take() {
+                        if is_edge { edges.push(record) } else { nodes.push(record) }
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    let apply_defaults = |record: &mut Record, domain: &str| {
+        for (d, field, value) in &defaults {
+            if (d == domain || d == "all") && !value.is_empty() {
+                record.entry(field).or_insert_with(|| value.clone());
+            }
+        }
+    };
+
+    let mut collector = Collector::default();
+    for (i, mut record) in nodes.into_iter().enumerate() {
+        apply_defaults(&mut record, "node");
+        collector.object(&record, i + 1, config);
+    }
+    for (i, mut record) in edges.into_iter().enumerate() {
+        apply_defaults(&mut record, "edge");
+        collector.correlation(&record, i + 1, config);
+    }
+    Ok(collector.report)
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// Parsuje ImportConfig (pusty = domyślna, created_at = teraz)
+fn parse_import_config(config_json: &str) -> Result<ImportConfig, JsValue> {
+    let now = || String::from(js_sys::Date::new_0().to_iso_string());
+    if config_json.trim().is_empty() {
+        return Ok(ImportConfig {
+            default_created_at: now(),
+            ..ImportConfig::default()
+        });
+    }
+
+    let raw: serde_json::Value = serde_json::from_str(config_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse import config: {}", e)))?;
+    let has_created_at = raw.get("default_created_at").is_some();
+    let mut config: ImportConfig = serde_json::from_value(raw)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse import config: {}", e)))?;
+    if !has_created_at {
+        config.default_created_at = now();
+    }
+    Ok(config)
+}
+
+/// @cybernetic WASM Entry Point - Import CSV → JSON ImportReport
+#[wasm_bindgen]
+pub fn wasm_import_csv(objects_csv: &str, correlations_csv: &str, config_json: &str) -> Result<String, JsValue> {
+    let config = parse_import_config(config_json)?;
+    let report = import_csv(objects_csv, correlations_csv, &config);
+
+    serde_json::to_string(&report)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Import GraphML → JSON ImportReport
+#[wasm_bindgen]
+pub fn wasm_import_graphml(graphml: &str, config_json: &str) -> Result<String, JsValue> {
+    let config = parse_import_config(config_json)?;
+    let report = import_graphml(graphml, &config)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse GraphML: {}", e)))?;
+
+    serde_json::to_string(&report)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interchange::export::ExportOverlays;
+    use crate::test_fixtures::{correlation, object};
+
+    #[test]
+    fn test_csv_defaults_and_row_errors() {
+        let objects = "Id;Label;quality_a;System Class\n\
+                       a;\"Agencja; \"\"A\"\"\";0,5;tool\n\
+                       b;;1.5;\n\
+                       ;bez id;;\n\
+                       c;C;;planet\n\
+                       d;D;;\n";
+        let correlations = "Source;Target;Typ;certainty_score\n\
+                            a;d;positive_feedback;0.8\n\
+                            a;zzz;direct_control;\n\
+                            d;a;;\n";
+        let config = ImportConfig {
+            delimiter: ';',
+            object_columns: HashMap::from([("system_class".to_string(), "System Class".to_string())]),
+            correlation_columns: HashMap::from([("relation_type".to_string(), "typ".to_string())]),
+            ..ImportConfig::default()
+        };
+        let report = import_csv(objects, correlations, &config);
+
+        let ids: Vec<&str> = report.objects.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "d"]);
+        let a = &report.objects[0];
+        assert_eq!(a.name, "Agencja; \"A\"");
+        assert_eq!(a.quality_a, 0.5);
+        assert_eq!((a.power_v, a.mass_c), (1.0, 1.0));
+        assert!(matches!(a.system_class, SystemClass::Tool));
+        assert_eq!(report.objects[1].name, "D");
+        assert_eq!(a.motivation_type, MotivationType::Informational);
+
+        assert_eq!(report.correlations.len(), 1);
+        assert_eq!(report.correlations[0].id, "row-2");
+        assert_eq!(report.correlations[0].impact_factor, 1.0);
+
+        let summary: Vec<(ImportTable, usize, Option<&str>)> = report
+            .errors
+            .iter()
+            .map(|e| (e.table, e.row, e.field.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (ImportTable::Objects, 3, Some("quality_a")),
+                (ImportTable::Objects, 4, Some("id")),
+                (ImportTable::Objects, 5, Some("system_class")),
+                (ImportTable::Correlations, 3, Some("target_id")),
+                (ImportTable::Correlations, 4, Some("relation_type")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_graphml_roundtrip_through_export() {
+        let mut a = object("a", 2.0);
+        a.description = Some("Opis <&>".to_string());
+        a.civilization_code = CivilizationCode::Byzantine;
+        let graph = Graph::new(
+            vec![a, object("b", 1.0)],
+            vec![correlation("a", "b", RelationType::NegativeFeedback, 0.7, 0.6)],
+        );
+        let xml = graph.to_graphml(&ExportOverlays {
+            leverage_target: Some("b".to_string()),
+            ..Default::default()
+        });
+
+        let report = import_graphml(&xml, &ImportConfig::default()).unwrap();
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+
+        let original = serde_json::to_value((graph.object("a"), graph.correlations())).unwrap();
+        let restored = report.into_graph();
+        let imported = serde_json::to_value((restored.object("a"), restored.correlations())).unwrap();
+        assert_eq!(imported, original);
+    }
+
+    #[test]
+    fn test_gephi_graphml_with_key_defaults() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <!-- Gephi 0.10 -->
+            <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+              <key id="label" for="node" attr.name="label" attr.type="string"/>
+              <key id="weight" for="edge" attr.name="weight" attr.type="double"/>
+              <key id="rt" for="edge" attr.name="relation_type" attr.type="string">
+                <default>supply</default>
+              </key>
+              <graph edgedefault="directed">
+                <node id="n0"><data key="label">Rząd &amp; Sejm</data></node>
+                <node id="n1"/>
+                <edge source="n0" target="n1"><data key="weight">0.4</data></edge>
+                <edge id="e1" source="n1" target="n0"><data key="rt">bogus</data></edge>
+              </graph>
+            </graphml>"#;
+        let report = import_graphml(xml, &ImportConfig::default()).unwrap();
+
+        assert_eq!(report.objects[0].name, "Rząd & Sejm");
+        assert_eq!(report.objects[1].name, "n1");
+        assert_eq!(report.correlations.len(), 1);
+        assert_eq!(report.correlations[0].relation_type, RelationType::Supply);
+        assert_eq!(report.correlations[0].impact_factor, 0.4);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].id.as_deref(), Some("e1"));
+
+        assert!(import_graphml("<graphml><graph>", &ImportConfig::default()).is_err());
+    }
+}
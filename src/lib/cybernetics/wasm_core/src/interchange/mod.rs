@@ -0,0 +1,64 @@
+//! @fileoverview Wymiana grafu z narzędziami zewnętrznymi (Gephi, yEd, Graphviz)
+//! @cybernetic Eksport i import obiektów oraz relacji w formatach analityków
+//!
+//! Nazwy atrybutów są wspólne dla eksportu i importu, dzięki czemu plik
+//! wyeksportowany z rdzenia wczytuje się z powrotem bez mapowania kolumn.
+
+pub mod export;
+pub mod import;
+pub mod xml;
+
+/// @cybernetic Typ wartości atrybutu (GraphML `attr.type`, GEXF `type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttrType {
+    String,
+    Double,
+    Boolean,
+}
+
+impl AttrType {
+    pub fn graphml_name(&self) -> &'static str {
+        match self {
+            AttrType::String => "string",
+            AttrType::Double => "double",
+            AttrType::Boolean => "boolean",
+        }
+    }
+}
+
+/// Atrybuty obiektu (węzła) w kolejności eksportu
+pub const NODE_ATTRIBUTES: &[(&str, AttrType)] = &[
+    ("name", AttrType::String),
+    ("description", AttrType::String),
+    ("system_class", AttrType::String),
+    ("control_system_type", AttrType::String),
+    ("power_v", AttrType::Double),
+    ("quality_a", AttrType::Double),
+    ("mass_c", AttrType::Double),
+    ("total_power", AttrType::Double), // P = v × a × c (tylko eksport)
+    ("working_power", AttrType::Double),
+    ("idle_power", AttrType::Double),
+    ("available_power", AttrType::Double),
+    ("civilization_code", AttrType::String),
+    ("motivation_type", AttrType::String),
+    ("created_at", AttrType::String),
+];
+
+/// Atrybuty relacji (krawędzi) w kolejności eksportu
+pub const EDGE_ATTRIBUTES: &[(&str, AttrType)] = &[
+    ("relation_type", AttrType::String),
+    ("certainty_score", AttrType::Double),
+    ("impact_factor", AttrType::Double),
+    ("source_name", AttrType::String),
+    ("superseded_at", AttrType::String),
+    ("superseded_by", AttrType::String),
+    ("created_at", AttrType::String),
+];
+
+/// Nazwa wartości enuma taka jak w JSON (serde), np. "direct_control"
+pub(crate) fn serde_name<T: serde::Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        _ => String::new(),
+    }
+}
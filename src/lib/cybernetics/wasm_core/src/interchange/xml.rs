@@ -0,0 +1,199 @@
+//! @fileoverview Minimalny czytnik XML dla importu GraphML
+//! @cybernetic Strumień zdarzeń: otwarcie / zamknięcie elementu, tekst
+//!
+//! Obsługuje deklarację, komentarze, DOCTYPE, CDATA, atrybuty w ' i " oraz
+//! encje predefiniowane i numeryczne. Przestrzenie nazw są zdejmowane
+//! z nazw (`y:ShapeNode` → `ShapeNode`) - GraphML z yEd ma ich wiele.
+
+use std::fmt;
+
+/// @cybernetic Zdarzenie czytnika
+#[derive(Debug, Clone, PartialEq)]
+pub enum XmlEvent {
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+        self_closing: bool,
+    },
+    End {
+        name: String,
+    },
+    Text(String),
+}
+
+/// @cybernetic Błąd składni XML (pozycja = offset bajtowy)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for XmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for XmlError {}
+
+fn error(position: usize, message: &str) -> XmlError {
+    XmlError {
+        position,
+        message: message.to_string(),
+    }
+}
+
+/// Nazwa bez prefiksu przestrzeni nazw
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Dekoduje encje XML
+pub fn unescape(text: &str, position: usize) -> Result<String, XmlError> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let semi = rest[amp..].find(';').ok_or_else(|| error(position, "unterminated entity"))?;
+        let entity = &rest[amp + 1..amp + semi];
+        let decoded = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(dec) = entity.strip_prefix('#') {
+                    dec.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(char::from_u32).ok_or_else(|| error(position, "unknown entity"))?
+            }
+        };
+        out.push(decoded);
+        rest = &rest[amp + semi + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// @cybernetic Parsuje dokument do listy zdarzeń (sprawdza parowanie tagów)
+pub fn parse(input: &str) -> Result<Vec<XmlEvent>, XmlError> {
+    let mut events = Vec::new();
+    let mut open: Vec<String> = Vec::new();
+    let mut pos = 0;
+
+    while pos < input.len() {
+        let rest = &input[pos..];
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = &rest[..end];
+            if !text.trim().is_empty() {
+                events.push(XmlEvent::Text(unescape(text, pos)?));
+            }
+            pos += end;
+            continue;
+        }
+
+        // Konstrukcje pomijane / specjalne
+        let skip_until = |marker: &str, what: &str| -> Result<usize, XmlError> {
+            rest.find(marker).map(|i| i + marker.len()).ok_or_else(|| error(pos, what))
+        };
+        if rest.starts_with("<?") {
+            pos += skip_until("?>", "unterminated processing instruction")?;
+            continue;
+        }
+        if rest.starts_with("<!--") {
+            pos += skip_until("-->", "unterminated comment")?;
+            continue;
+        }
+        if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").ok_or_else(|| error(pos, "unterminated CDATA"))?;
+            events.push(XmlEvent::Text(cdata[..end].to_string()));
+            pos += "<![CDATA[".len() + end + 3;
+            continue;
+        }
+        if rest.starts_with("<!") {
+            pos += skip_until(">", "unterminated declaration")?;
+            continue;
+        }
+
+        // Tag zamykający
+        if let Some(closing) = rest.strip_prefix("</") {
+            let end = closing.find('>').ok_or_else(|| error(pos, "unterminated end tag"))?;
+            let name = local_name(closing[..end].trim()).to_string();
+            match open.pop() {
+                Some(expected) if expected == name => {}
+                _ => return Err(error(pos, &format!("unexpected </{}>", name))),
+            }
+            events.push(XmlEvent::End { name });
+            pos += 2 + end + 1;
+            continue;
+        }
+
+        // Tag otwierający: nazwa, atrybuty, opcjonalne "/>"
+        let tag_start = pos;
+        let mut i = 1;
+        let bytes = rest.as_bytes();
+        while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' && bytes[i] != b'/' {
+            i += 1;
+        }
+        let name = local_name(&rest[1..i]).to_string();
+        if name.is_empty() {
+            return Err(error(tag_start, "empty tag name"));
+        }
+
+        let mut attributes = Vec::new();
+        let self_closing = loop {
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            match bytes.get(i) {
+                None => return Err(error(tag_start, "unterminated start tag")),
+                Some(b'>') => {
+                    i += 1;
+                    break false;
+                }
+                Some(b'/') if bytes.get(i + 1) == Some(&b'>') => {
+                    i += 2;
+                    break true;
+                }
+                Some(_) => {
+                    let eq = rest[i..].find('=').ok_or_else(|| error(pos + i, "attribute without value"))?;
+                    let key = local_name(rest[i..i + eq].trim()).to_string();
+                    i += eq + 1;
+                    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                        i += 1;
+                    }
+                    let quote = match bytes.get(i) {
+                        Some(&q) if q == b'"' || q == b'\'' => q as char,
+                        _ => return Err(error(pos + i, "attribute value must be quoted")),
+                    };
+                    let close = rest[i + 1..]
+                        .find(quote)
+                        .ok_or_else(|| error(pos + i, "unterminated attribute value"))?;
+                    attributes.push((key, unescape(&rest[i + 1..i + 1 + close], pos + i)?));
+                    i += close + 2;
+                }
+            }
+        };
+
+        if !self_closing {
+            open.push(name.clone());
+        }
+        events.push(XmlEvent::Start {
+            name,
+            attributes,
+            self_closing,
+        });
+        pos += i;
+    }
+
+    match open.last() {
+        Some(name) => Err(error(input.len(), &format!("unclosed <{}>", name))),
+        None => Ok(events),
+    }
+}
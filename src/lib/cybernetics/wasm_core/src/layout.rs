@@ -0,0 +1,563 @@
+//! @fileoverview Układ siłowy grafu dla widoku efektora
+//! @cybernetic Fruchterman–Reingold z przybliżeniem Barnes–Hut (O(n log n))
+//!
+//! Wejście to `GraphData` i `GraphConfig` z efektor/types.ts (czytamy tylko
+//! potrzebne pola, resztę ignorujemy). Silnik liczy w krokach, więc UI może
+//! animować: `step(n)` wykonuje n iteracji, `positions()` zwraca
+//! Float64Array [x0, y0, x1, y1, ...] w kolejności `nodes`. Współrzędne są
+//! wyśrodkowane w (0, 0) i ograniczone do width × height.
+//!
+//! `dagMode` pozostaje po stronie renderera - układ go nie wymusza.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// Minimalny bok kwadratu drzewa - niżej węzły traktujemy jako jeden punkt
+const MIN_QUAD_SIZE: f64 = 1e-6;
+
+/// Minimalna odległość w obliczeniach sił (unika dzielenia przez zero)
+const MIN_DISTANCE: f64 = 1e-3;
+
+// ============================================================================
+// WEJŚCIE (podzbiór efektor/types.ts)
+// ============================================================================
+
+/// @cybernetic Węzeł GraphNode - pola istotne dla układu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutNode {
+    pub id: String,
+    pub x: Option<f64>, // Pozycja z poprzedniego układu (kontynuacja)
+    pub y: Option<f64>,
+}
+
+/// @cybernetic Krawędź GraphLink - pola istotne dla układu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutLink {
+    pub source: String,
+    pub target: String,
+    #[serde(default = "default_link_value")]
+    pub value: f64, // impact_factor - skaluje przyciąganie
+}
+
+fn default_link_value() -> f64 {
+    1.0
+}
+
+/// @cybernetic GraphData
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutGraphData {
+    pub nodes: Vec<LayoutNode>,
+    pub links: Vec<LayoutLink>,
+}
+
+/// @cybernetic GraphConfig - wymiary obszaru rysowania
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutViewport {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl LayoutViewport {
+    /// @cybernetic Wymiary muszą być skończone i dodatnie
+    ///
+    /// Inaczej granice `clamp` w iteracji się odwracają (panika) albo są NaN.
+    pub fn validate(&self) -> Result<(), String> {
+        let valid = |v: f64| v.is_finite() && v > 0.0;
+        if !valid(self.width) || !valid(self.height) {
+            return Err(format!(
+                "Invalid graph config: width and height must be finite and > 0 (got {} × {})",
+                self.width, self.height
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// @cybernetic Parametry symulacji
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutParams {
+    pub theta: f64,                    // Barnes–Hut: 0 = dokładnie, więcej = szybciej
+    pub gravity: f64,                  // Przyciąganie do środka (rozłączne składowe)
+    pub cooling: f64,                  // Mnożnik temperatury po iteracji
+    pub initial_temperature: Option<f64>, // None = width / 10
+    pub min_temperature: f64,          // Poniżej - układ zbieżny
+}
+
+impl Default for LayoutParams {
+    fn default() -> Self {
+        LayoutParams {
+            theta: 0.8,
+            gravity: 0.05,
+            cooling: 0.97,
+            initial_temperature: None,
+            min_temperature: 0.05,
+        }
+    }
+}
+
+// ============================================================================
+// DRZEWO CZWÓRKOWE (BARNES–HUT)
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct Quad {
+    x0: f64,
+    y0: f64,
+    size: f64,
+    mass: f64,
+    sum_x: f64,
+    sum_y: f64,
+    body: Option<usize>,
+    children: Option<[usize; 4]>,
+}
+
+impl Quad {
+    fn new(x0: f64, y0: f64, size: f64) -> Self {
+        Quad {
+            x0,
+            y0,
+            size,
+            mass: 0.0,
+            sum_x: 0.0,
+            sum_y: 0.0,
+            body: None,
+            children: None,
+        }
+    }
+
+    fn quadrant(&self, x: f64, y: f64) -> usize {
+        let half = self.size / 2.0;
+        let right = (x >= self.x0 + half) as usize;
+        let bottom = (y >= self.y0 + half) as usize;
+        right + 2 * bottom
+    }
+}
+
+struct QuadTree {
+    quads: Vec<Quad>,
+}
+
+impl QuadTree {
+    fn build(positions: &[f64]) -> Self {
+        let n = positions.len() / 2;
+        let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+        let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for i in 0..n {
+            min_x = min_x.min(positions[2 * i]);
+            max_x = max_x.max(positions[2 * i]);
+            min_y = min_y.min(positions[2 * i + 1]);
+            max_y = max_y.max(positions[2 * i + 1]);
+        }
+        let size = (max_x - min_x).max(max_y - min_y).max(MIN_QUAD_SIZE) * 1.0001;
+
+        let mut tree = QuadTree {
+            quads: vec![Quad::new(min_x, min_y, size)],
+        };
+        for i in 0..n {
+            tree.insert(i, positions[2 * i], positions[2 * i + 1], positions);
+        }
+        tree
+    }
+
+    fn insert(&mut self, body: usize, x: f64, y: f64, positions: &[f64]) {
+        let mut q = 0;
+        loop {
+            let quad = &mut self.quads[q];
+            let was_empty = quad.mass == 0.0;
+            quad.mass += 1.0;
+            quad.sum_x += x;
+            quad.sum_y += y;
+
+            if let Some(children) = quad.children {
+                q = children[quad.quadrant(x, y)];
+                continue;
+            }
+            if was_empty {
+                quad.body = Some(body);
+                return;
+            }
+            if quad.size < MIN_QUAD_SIZE {
+                return; // Punkty praktycznie pokrywające się - jeden liść
+            }
+
+            // Podział liścia: dotychczasowe ciało schodzi do dziecka
+            let (x0, y0, half) = (quad.x0, quad.y0, quad.size / 2.0);
+            let existing = quad.body.take();
+            let first = self.quads.len();
+            self.quads.push(Quad::new(x0, y0, half));
+            self.quads.push(Quad::new(x0 + half, y0, half));
+            self.quads.push(Quad::new(x0, y0 + half, half));
+            self.quads.push(Quad::new(x0 + half, y0 + half, half));
+            self.quads[q].children = Some([first, first + 1, first + 2, first + 3]);
+
+            if let Some(b) = existing {
+                let (bx, by) = (positions[2 * b], positions[2 * b + 1]);
+                let child = first + self.quads[q].quadrant(bx, by);
+                let c = &mut self.quads[child];
+                c.mass = 1.0;
+                c.sum_x = bx;
+                c.sum_y = by;
+                c.body = Some(b);
+            }
+            // Nowe ciało jest już policzone w rodzicu - dziecko doliczy je w pętli
+            q = first + self.quads[q].quadrant(x, y);
+        }
+    }
+
+    /// Odpychanie k² · m / d działające na węzeł `i`
+    fn repulsion(&self, i: usize, x: f64, y: f64, k2: f64, theta: f64) -> (f64, f64) {
+        let (mut fx, mut fy) = (0.0, 0.0);
+        let mut stack = vec![0];
+
+        while let Some(q) = stack.pop() {
+            let quad = &self.quads[q];
+            if quad.mass == 0.0 || (quad.mass == 1.0 && quad.body == Some(i)) {
+                continue;
+            }
+
+            let (cx, cy) = (quad.sum_x / quad.mass, quad.sum_y / quad.mass);
+            let (dx, dy) = (x - cx, y - cy);
+            let distance = (dx * dx + dy * dy).sqrt();
+
+            match quad.children {
+                Some(children) if quad.size / distance.max(MIN_DISTANCE) >= theta => {
+                    stack.extend_from_slice(&children);
+                }
+                _ => {
+                    let (ux, uy, d) = unit_or_jitter(dx, dy, distance, i);
+                    let force = k2 * quad.mass / d;
+                    fx += ux * force;
+                    fy += uy * force;
+                }
+            }
+        }
+
+        (fx, fy)
+    }
+}
+
+/// Wektor jednostkowy; dla pokrywających się punktów - deterministyczny kierunek
+fn unit_or_jitter(dx: f64, dy: f64, distance: f64, seed: usize) -> (f64, f64, f64) {
+    if distance < MIN_DISTANCE {
+        let angle = seed as f64 * 2.399_963; // Kąt złoty
+        (angle.cos(), angle.sin(), MIN_DISTANCE)
+    } else {
+        (dx / distance, dy / distance, distance)
+    }
+}
+
+// ============================================================================
+// SILNIK UKŁADU
+// ============================================================================
+
+/// @cybernetic Inkrementalny układ siłowy
+#[wasm_bindgen]
+pub struct ForceLayout {
+    positions: Vec<f64>,
+    pinned: Vec<bool>,
+    links: Vec<(usize, usize, f64)>,
+    viewport: LayoutViewport,
+    params: LayoutParams,
+    k: f64, // Optymalna odległość: sqrt(pole / n)
+    temperature: f64,
+    iterations: usize,
+}
+
+impl ForceLayout {
+    pub fn new(graph: &LayoutGraphData, viewport: LayoutViewport, params: LayoutParams) -> Result<Self, String> {
+        viewport.validate()?;
+        let n = graph.nodes.len();
+        let index: HashMap<&str, usize> = graph
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect();
+
+        // Pozycje startowe: zapamiętane albo spirala Fermata (deterministycznie)
+        let radius = viewport.width.min(viewport.height) / 2.0;
+        let mut positions = Vec::with_capacity(2 * n);
+        for (i, node) in graph.nodes.iter().enumerate() {
+            let r = radius * ((i as f64 + 0.5) / n as f64).sqrt();
+            let angle = i as f64 * 2.399_963;
+            positions.push(node.x.unwrap_or(r * angle.cos()));
+            positions.push(node.y.unwrap_or(r * angle.sin()));
+        }
+
+        let links = graph
+            .links
+            .iter()
+            .filter_map(|link| {
+                let s = *index.get(link.source.as_str())?;
+                let t = *index.get(link.target.as_str())?;
+                (s != t).then_some((s, t, link.value.max(0.0)))
+            })
+            .collect();
+
+        let area = viewport.width * viewport.height;
+        Ok(ForceLayout {
+            positions,
+            pinned: vec![false; n],
+            links,
+            viewport,
+            params,
+            k: (area / n.max(1) as f64).sqrt(),
+            temperature: params.initial_temperature.unwrap_or(viewport.width / 10.0),
+            iterations: 0,
+        })
+    }
+
+    pub fn positions(&self) -> &[f64] {
+        &self.positions
+    }
+
+    pub fn is_converged(&self) -> bool {
+        self.temperature < self.params.min_temperature
+    }
+
+    /// @cybernetic Ustawia pozycję węzła (np. przeciąganie w UI)
+    pub fn set_node_position(&mut self, index: usize, x: f64, y: f64, pinned: bool) {
+        if index < self.pinned.len() {
+            self.positions[2 * index] = x;
+            self.positions[2 * index + 1] = y;
+            self.pinned[index] = pinned;
+        }
+    }
+
+    /// @cybernetic Wykonuje `iterations` kroków; zwraca true, gdy układ zbieżny
+    pub fn step(&mut self, iterations: usize) -> bool {
+        for _ in 0..iterations {
+            if self.is_converged() {
+                break;
+            }
+            self.iterate();
+        }
+        self.is_converged()
+    }
+
+    fn displacements(&self, theta: f64) -> Vec<f64> {
+        let n = self.pinned.len();
+        let k2 = self.k * self.k;
+        let mut disp = vec![0.0; 2 * n];
+
+        // Odpychanie wszystkich par (Barnes–Hut)
+        let tree = QuadTree::build(&self.positions);
+        for i in 0..n {
+            let (fx, fy) = tree.repulsion(i, self.positions[2 * i], self.positions[2 * i + 1], k2, theta);
+            disp[2 * i] += fx;
+            disp[2 * i + 1] += fy;
+        }
+
+        // Przyciąganie wzdłuż relacji: d² / k × value
+        for &(s, t, value) in &self.links {
+            let dx = self.positions[2 * s] - self.positions[2 * t];
+            let dy = self.positions[2 * s + 1] - self.positions[2 * t + 1];
+            let distance = (dx * dx + dy * dy).sqrt();
+            if distance < MIN_DISTANCE {
+                continue;
+            }
+            let force = distance * distance / self.k * value;
+            let (ux, uy) = (dx / distance, dy / distance);
+            disp[2 * s] -= ux * force;
+            disp[2 * s + 1] -= uy * force;
+            disp[2 * t] += ux * force;
+            disp[2 * t + 1] += uy * force;
+        }
+
+        // Grawitacja do środka
+        for i in 0..n {
+            disp[2 * i] -= self.params.gravity * self.positions[2 * i];
+            disp[2 * i + 1] -= self.params.gravity * self.positions[2 * i + 1];
+        }
+
+        disp
+    }
+
+    fn iterate(&mut self) {
+        let disp = self.displacements(self.params.theta);
+        let (half_w, half_h) = (self.viewport.width / 2.0, self.viewport.height / 2.0);
+
+        for i in 0..self.pinned.len() {
+            if self.pinned[i] {
+                continue;
+            }
+            let (dx, dy) = (disp[2 * i], disp[2 * i + 1]);
+            let length = (dx * dx + dy * dy).sqrt();
+            if length > 0.0 {
+                let step = length.min(self.temperature);
+                self.positions[2 * i] = (self.positions[2 * i] + dx / length * step).clamp(-half_w, half_w);
+                self.positions[2 * i + 1] =
+                    (self.positions[2 * i + 1] + dy / length * step).clamp(-half_h, half_h);
+            }
+        }
+
+        self.temperature *= self.params.cooling;
+        self.iterations += 1;
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+#[wasm_bindgen]
+impl ForceLayout {
+    /// @cybernetic Konstruktor dla JS: GraphData, GraphConfig, parametry (pusty = domyślne)
+    #[wasm_bindgen(constructor)]
+    pub fn wasm_new(
+        graph_json: &str,
+        config_json: &str,
+        params_json: &str,
+    ) -> Result<ForceLayout, JsValue> {
+        let graph: LayoutGraphData = serde_json::from_str(graph_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse graph data: {}", e)))?;
+        let viewport: LayoutViewport = serde_json::from_str(config_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse graph config: {}", e)))?;
+        let params = if params_json.trim().is_empty() {
+            LayoutParams::default()
+        } else {
+            serde_json::from_str(params_json)
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse layout params: {}", e)))?
+        };
+
+        ForceLayout::new(&graph, viewport, params).map_err(|e| JsValue::from_str(&e))
+    }
+
+    /// @cybernetic Kroki symulacji (do animacji); true = zbieżny
+    #[wasm_bindgen(js_name = step)]
+    pub fn wasm_step(&mut self, iterations: u32) -> bool {
+        self.step(iterations as usize)
+    }
+
+    /// @cybernetic Współrzędne [x0, y0, x1, y1, ...] w kolejności `nodes`
+    #[wasm_bindgen(js_name = positions)]
+    pub fn wasm_positions(&self) -> js_sys::Float64Array {
+        js_sys::Float64Array::from(self.positions.as_slice())
+    }
+
+    /// @cybernetic Przesuwa (i opcjonalnie przypina) węzeł; ogrzewa układ
+    #[wasm_bindgen(js_name = setNodePosition)]
+    pub fn wasm_set_node_position(&mut self, index: u32, x: f64, y: f64, pinned: bool) {
+        self.set_node_position(index as usize, x, y, pinned);
+        let reheat = self.params.initial_temperature.unwrap_or(self.viewport.width / 10.0) / 4.0;
+        self.temperature = self.temperature.max(reheat);
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn iterations(&self) -> u32 {
+        self.iterations as u32
+    }
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(ids: &[&str], links: &[(&str, &str)]) -> LayoutGraphData {
+        LayoutGraphData {
+            nodes: ids
+                .iter()
+                .map(|id| LayoutNode {
+                    id: id.to_string(),
+                    x: None,
+                    y: None,
+                })
+                .collect(),
+            links: links
+                .iter()
+                .map(|(s, t)| LayoutLink {
+                    source: s.to_string(),
+                    target: t.to_string(),
+                    value: 1.0,
+                })
+                .collect(),
+        }
+    }
+
+    const VIEWPORT: LayoutViewport = LayoutViewport {
+        width: 800.0,
+        height: 600.0,
+    };
+
+    fn distance(layout: &ForceLayout, a: usize, b: usize) -> f64 {
+        let p = layout.positions();
+        ((p[2 * a] - p[2 * b]).powi(2) + (p[2 * a + 1] - p[2 * b + 1]).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn test_barnes_hut_exact_with_zero_theta() {
+        let ids: Vec<String> = (0..40).map(|i| format!("n{}", i)).collect();
+        let refs: Vec<&str> = ids.iter().map(|s| s.as_str()).collect();
+        let layout = ForceLayout::new(&data(&refs, &[]), VIEWPORT, LayoutParams::default()).unwrap();
+
+        let k2 = layout.k * layout.k;
+        let tree = QuadTree::build(layout.positions());
+        let p = layout.positions();
+        for i in 0..refs.len() {
+            let (mut ex, mut ey) = (0.0, 0.0);
+            for j in (0..refs.len()).filter(|&j| j != i) {
+                let (dx, dy) = (p[2 * i] - p[2 * j], p[2 * i + 1] - p[2 * j + 1]);
+                let d2 = dx * dx + dy * dy;
+                ex += dx * k2 / d2;
+                ey += dy * k2 / d2;
+            }
+            let (fx, fy) = tree.repulsion(i, p[2 * i], p[2 * i + 1], k2, 0.0);
+            assert!((fx - ex).abs() < 1e-6 * ex.abs().max(1.0));
+            assert!((fy - ey).abs() < 1e-6 * ey.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn test_linked_nodes_end_closer_and_stay_in_bounds() {
+        let graph = data(&["a", "b", "c", "d"], &[("a", "b"), ("c", "d")]);
+        let mut layout = ForceLayout::new(&graph, VIEWPORT, LayoutParams::default()).unwrap();
+
+        let converged = layout.step(10_000);
+        assert!(converged);
+        assert_eq!(layout.positions().len(), 8);
+        assert!(distance(&layout, 0, 1) < distance(&layout, 0, 2));
+        assert!(layout
+            .positions()
+            .chunks(2)
+            .all(|p| p[0].abs() <= 400.0 && p[1].abs() <= 300.0));
+    }
+
+    #[test]
+    fn test_incremental_steps_and_pinning() {
+        let graph = data(&["a", "b", "c"], &[("a", "b"), ("b", "c")]);
+        let mut stepped = ForceLayout::new(&graph, VIEWPORT, LayoutParams::default()).unwrap();
+        let mut batch = ForceLayout::new(&graph, VIEWPORT, LayoutParams::default()).unwrap();
+
+        for _ in 0..10 {
+            stepped.step(5);
+        }
+        batch.step(50);
+        assert_eq!(stepped.positions(), batch.positions());
+        assert_eq!(stepped.iterations, 50);
+
+        stepped.set_node_position(0, 10.0, -20.0, true);
+        stepped.temperature = 50.0;
+        stepped.step(20);
+        assert_eq!(&stepped.positions()[0..2], &[10.0, -20.0]);
+    }
+    #[test]
+    fn test_rejects_invalid_viewport() {
+        let graph = data(&["a", "b"], &[("a", "b")]);
+        for (width, height) in [(-800.0, 600.0), (0.0, 600.0), (800.0, f64::NAN), (f64::INFINITY, 600.0)] {
+            let viewport = LayoutViewport { width, height };
+            assert!(ForceLayout::new(&graph, viewport, LayoutParams::default()).is_err());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-/**
+/*!
  * @fileoverview KOSSECKI METASYSTEM - Rust/Wasm Core
  * @cybernetic Rdzeń obliczeniowy dla algorytmów grafowych
  *
@@ -8,7 +8,35 @@
 
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
+
+pub mod budget;
+pub mod community;
+pub mod contradictions;
+pub mod controllability;
+pub mod decisions;
+pub mod distortion;
+pub mod feedback;
+pub mod homeostat;
+pub mod indexed;
+pub mod interchange;
+pub mod layout;
+pub mod metrics;
+pub mod protocol;
+pub mod receptor;
+pub mod scc;
+pub mod search;
+pub mod snapshot;
+pub mod temporal;
+
+#[cfg(test)]
+mod test_fixtures;
+
+use budget::{budget_from_js, Budgeted, CancellationToken, WorkBudget};
+use decisions::explain::LeverageExplanation;
+use feedback::FeedbackModel;
+use search::PathIndex;
+use temporal::ValidityInterval;
 
 // ============================================================================
 // TYPY DANYCH (Mapowane z TypeScript)
@@ -36,6 +64,43 @@ pub enum ControlSystemType {
     Economic,
 }
 
+/// @cybernetic Kod cywilizacyjny (Metacybernetyka 2015)
+///
+/// Zgodny z CHECK w schema.sql: latin | byzantine | turandot | mixed | unknown
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CivilizationCode {
+    Latin,     // Cywilizacja łacińska (nauka, prawo)
+    Byzantine, // Cywilizacja bizantyjska (religia, tradycja)
+    #[serde(alias = "turanian")]
+    Turandot,  // Cywilizacja turandot (ideologia, totalitaryzm)
+    Mixed,
+    #[default]
+    Unknown,
+}
+
+/// @cybernetic Typ motywacji systemu (Metacybernetyka 2015)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum MotivationType {
+    Vital,         // Motywacje witalne (przeżycie biologiczne)
+    #[default]
+    Informational, // Motywacje informacyjne (poznanie)
+    Mixed,
+}
+
+impl MotivationType {
+    pub const ALL: [MotivationType; 3] = [MotivationType::Vital, MotivationType::Informational, MotivationType::Mixed];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            MotivationType::Vital => "vital",
+            MotivationType::Informational => "informational",
+            MotivationType::Mixed => "mixed",
+        }
+    }
+}
+
 /// @cybernetic Typ relacji sterowniczej
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -46,6 +111,47 @@ pub enum RelationType {
     Supply,
 }
 
+impl RelationType {
+    /// @cybernetic Znak relacji: sprzężenie ujemne hamuje cel (-1), pozostałe wzmacniają (+1)
+    pub fn sign(&self) -> Polarity {
+        match self {
+            RelationType::NegativeFeedback => Polarity::Negative,
+            _ => Polarity::Positive,
+        }
+    }
+}
+
+/// @cybernetic Biegunowość łańcucha relacji: czy wzrost źródła zwiększa cel
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Polarity {
+    #[default]
+    Positive,
+    Negative,
+}
+
+impl Polarity {
+    /// @cybernetic Iloczyn znaków relacji ścieżki
+    pub fn of_path(feedback_types: &[RelationType]) -> Self {
+        let negatives = feedback_types
+            .iter()
+            .filter(|t| t.sign() == Polarity::Negative)
+            .count();
+        if negatives % 2 == 0 {
+            Polarity::Positive
+        } else {
+            Polarity::Negative
+        }
+    }
+
+    pub fn factor(&self) -> f64 {
+        match self {
+            Polarity::Positive => 1.0,
+            Polarity::Negative => -1.0,
+        }
+    }
+}
+
 /// @cybernetic Parametry energetyczne systemu
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnergyParams {
@@ -71,6 +177,12 @@ pub struct CyberneticObject {
     pub quality_a: f64,            // a - Jakość/sprawność (0-1)
     pub mass_c: f64,               // c - Ilość/masa
 
+    // METACYBERNETYKA 2015: Klasyfikacja cywilizacyjna (domyślne jak w schema.sql)
+    #[serde(default)]
+    pub civilization_code: CivilizationCode,
+    #[serde(default)]
+    pub motivation_type: MotivationType,
+
     pub created_at: String,
 }
 
@@ -84,6 +196,8 @@ pub struct Correlation {
     pub certainty_score: f64,
     pub impact_factor: f64,
     pub source_name: Option<String>,
+    pub superseded_at: Option<String>, // Kiedy relacja została wycofana
+    pub superseded_by: Option<String>, // ID relacji, która ją zastąpiła
     pub created_at: String,
 }
 
@@ -105,6 +219,12 @@ pub struct InfluencePath {
     pub certainty_score: f64,
     pub depth: usize,
     pub is_feedback_loop: bool,
+    #[serde(default)]
+    pub correlation_ids: Vec<String>, // ID relacji wzdłuż ścieżki (source → target)
+    #[serde(default)]
+    pub polarity: Polarity,           // Iloczyn znaków relacji
+    #[serde(default)]
+    pub signed_strength: f64,         // polarity × total_strength
 }
 
 /// @cybernetic Węzeł wpływowy
@@ -119,25 +239,38 @@ pub struct InfluentialNode {
     pub certainty_score: f64,
     pub control_leverage: f64,
     pub paths: Vec<InfluencePath>,
+    // Rozbicie influence_strength wg biegunowości ścieżek (średnie po ścieżkach)
+    #[serde(default)]
+    pub positive_influence: f64,
+    #[serde(default)]
+    pub negative_influence: f64,
+    #[serde(default)]
+    pub net_influence: f64, // > 0: pchnięcie węzła wzmacnia cel, < 0: osłabia
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<LeverageExplanation>,
 }
 
 // ============================================================================
 // KONFIGURACJA BFS
 // ============================================================================
 
-const MAX_DEPTH: usize = 5;
-const MAX_PATHS: usize = 100;
-const MIN_INFLUENCE_THRESHOLD: f64 = 0.1;
+pub(crate) const MAX_DEPTH: usize = 5;
+pub(crate) const MAX_PATHS: usize = 100;
+pub(crate) const MIN_INFLUENCE_THRESHOLD: f64 = 0.1;
 
 // ============================================================================
 // STRUKTURA GRAFU
 // ============================================================================
 
 /// @cybernetic Graf obiektów i relacji
+#[derive(Clone)]
 pub struct Graph {
     objects: HashMap<String, CyberneticObject>,
     adjacency_list: HashMap<String, Vec<Correlation>>,
-    reverse_adjacency_list: HashMap<String, Vec<Correlation>>,
+    path_index: PathIndex, // Węzły u32 + relacje wchodzące dla BFS ścieżek wpływu
+    // Relacje w kolejności wejściowej + przedziały ważności (indeksy zgodne)
+    correlations: Vec<Correlation>,
+    validity: Vec<ValidityInterval>,
 }
 
 impl Graph {
@@ -146,14 +279,15 @@ impl Graph {
         let mut graph = Graph {
             objects: HashMap::new(),
             adjacency_list: HashMap::new(),
-            reverse_adjacency_list: HashMap::new(),
+            path_index: PathIndex::default(),
+            correlations: Vec::new(),
+            validity: Vec::new(),
         };
 
         // Dodaj obiekty
         for obj in objects {
             graph.objects.insert(obj.id.clone(), obj.clone());
             graph.adjacency_list.insert(obj.id.clone(), Vec::new());
-            graph.reverse_adjacency_list.insert(obj.id.clone(), Vec::new());
         }
 
         // Dodaj relacje
@@ -163,137 +297,112 @@ impl Graph {
                 forward.push(corr.clone());
             }
 
-            // Reverse (target ← source)
-            if let Some(reverse) = graph.reverse_adjacency_list.get_mut(&corr.target_id) {
-                reverse.push(corr);
-            }
+            graph.validity.push(ValidityInterval::from_correlation(&corr));
+            graph.correlations.push(corr);
         }
 
+        // Reverse (target ← source) jako indeksy relacji
+        graph.path_index = PathIndex::new(graph.objects.keys(), &graph.correlations);
         graph
     }
 
-    /// @cybernetic Znajduje wszystkie ścieżki wpływu do celu (BFS)
-    ///
-    /// Szuka ścieżek prowadzących DO targetId (idąc wstecz po grafie).
-    /// Algorytm identyczny z TypeScript findInfluencePaths (pathfinder.ts:215-300)
-    pub fn find_influence_paths(
+    /// @cybernetic Obiekt po ID
+    pub fn object(&self, id: &str) -> Option<&CyberneticObject> {
+        self.objects.get(id)
+    }
+
+    /// @cybernetic Wszystkie obiekty grafu (kolejność nieokreślona)
+    pub fn objects(&self) -> impl Iterator<Item = &CyberneticObject> {
+        self.objects.values()
+    }
+
+    /// @cybernetic Relacje grafu w kolejności wejściowej
+    pub fn correlations(&self) -> &[Correlation] {
+        &self.correlations
+    }
+
+    /// @cybernetic Pełna analiza wpływu: BFS + agregacja + sortowanie po dźwigni (DESC)
+    pub fn rank_influential_nodes(
         &self,
         target_id: &str,
-        _goal: &SteeringGoal,
-    ) -> Vec<InfluencePath> {
-        let mut paths = Vec::new();
-
-        #[derive(Clone)]
-        struct QueueItem {
-            current_id: String,
-            path: Vec<String>,
-            total_strength: f64,
-            feedback_types: Vec<RelationType>,
-            certainties: Vec<f64>,
-            depth: usize,
-        }
+        goal: &SteeringGoal,
+    ) -> Vec<InfluentialNode> {
+        self.rank_influential_nodes_with(target_id, goal, &FeedbackModel::default())
+    }
 
-        let mut queue = VecDeque::new();
-        queue.push_back(QueueItem {
-            current_id: target_id.to_string(),
-            path: vec![target_id.to_string()],
-            total_strength: 1.0,
-            feedback_types: Vec::new(),
-            certainties: Vec::new(),
-            depth: 0,
-        });
+    /// @cybernetic Pełna analiza wpływu z wybranym modelem sprzężeń zwrotnych
+    pub fn rank_influential_nodes_with(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+        model: &FeedbackModel,
+    ) -> Vec<InfluentialNode> {
+        self.rank_influential_nodes_budgeted(target_id, goal, model, &mut WorkBudget::unlimited())
+            .result
+    }
 
-        while !queue.is_empty() && paths.len() < MAX_PATHS {
-            let current = queue.pop_front().unwrap();
+    /// @cybernetic Ranking w ramach budżetu pracy
+    ///
+    /// Po wyczerpaniu budżetu ranking liczony jest ze ścieżek znalezionych
+    /// do tej pory (`completed: false`).
+    pub fn rank_influential_nodes_budgeted(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+        model: &FeedbackModel,
+        budget: &mut WorkBudget,
+    ) -> Budgeted<Vec<InfluentialNode>> {
+        let (paths, _) = self.find_influence_paths_within(target_id, goal, budget);
+        budget.wrap(self.rank_paths(paths, model))
+    }
 
-            if current.depth >= MAX_DEPTH {
-                continue;
-            }
+    /// @cybernetic Węzły wpływowe z gotowych ścieżek, posortowane malejąco po dźwigni
+    pub fn rank_paths(&self, paths: Vec<InfluencePath>, model: &FeedbackModel) -> Vec<InfluentialNode> {
+        let mut influential_nodes = self.calculate_node_influences_with(paths, model);
 
-            // Pobierz relacje wpływające na current (reverse)
-            let incoming_relations = self
-                .reverse_adjacency_list
-                .get(&current.current_id)
-                .cloned()
-                .unwrap_or_default();
-
-            for relation in incoming_relations {
-                let source_id = &relation.source_id;
-
-                // Unikaj cykli (chyba że to pętla sprzężenia zwrotnego)
-                if current.path.contains(source_id) && source_id != target_id {
-                    continue;
-                }
-
-                // Oblicz siłę wpływu
-                let impact_factor = relation.impact_factor;
-                let new_strength = current.total_strength * impact_factor;
-
-                // Filtruj słabe wpływy
-                if new_strength < MIN_INFLUENCE_THRESHOLD {
-                    continue;
-                }
-
-                let mut new_path = vec![source_id.clone()];
-                new_path.extend_from_slice(&current.path);
-
-                let mut new_feedback_types = vec![relation.relation_type.clone()];
-                new_feedback_types.extend_from_slice(&current.feedback_types);
-
-                let mut new_certainties = vec![relation.certainty_score];
-                new_certainties.extend_from_slice(&current.certainties);
-
-                // Zapisz ścieżkę
-                let path_names: Vec<String> = new_path
-                    .iter()
-                    .map(|id| {
-                        self.objects
-                            .get(id)
-                            .map(|obj| obj.name.clone())
-                            .unwrap_or_else(|| id.clone())
-                    })
-                    .collect();
-
-                let avg_certainty = if !new_certainties.is_empty() {
-                    new_certainties.iter().sum::<f64>() / new_certainties.len() as f64
-                } else {
-                    0.0
-                };
-
-                paths.push(InfluencePath {
-                    path: new_path.clone(),
-                    path_names,
-                    total_strength: new_strength,
-                    feedback_types: new_feedback_types.clone(),
-                    certainty_score: avg_certainty,
-                    depth: current.depth + 1,
-                    is_feedback_loop: source_id == target_id,
-                });
+        influential_nodes.sort_by(|a, b| {
+            b.control_leverage
+                .partial_cmp(&a.control_leverage)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
 
-                // Kontynuuj przeszukiwanie
-                queue.push_back(QueueItem {
-                    current_id: source_id.clone(),
-                    path: new_path,
-                    total_strength: new_strength,
-                    feedback_types: new_feedback_types,
-                    certainties: new_certainties,
-                    depth: current.depth + 1,
-                });
-            }
-        }
+        influential_nodes
+    }
 
-        paths
+    /// @cybernetic Znajduje wszystkie ścieżki wpływu do celu (BFS)
+    ///
+    /// Szuka ścieżek prowadzących DO targetId (idąc wstecz po grafie).
+    /// Algorytm identyczny z TypeScript findInfluencePaths (pathfinder.ts:215-300)
+    /// Limit MAX_PATHS - szczegóły i liczniki w `find_influence_paths_with_stats`.
+    pub fn find_influence_paths(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+    ) -> Vec<InfluencePath> {
+        self.find_influence_paths_with_stats(target_id, goal).0
     }
 
     /// @cybernetic Oblicza wpływ każdego węzła na cel
     ///
     /// Agreguje ścieżki według pierwszego węzła i oblicza dźwignię sterowniczą.
     /// Identyczny algorytm z TypeScript calculateNodeInfluences (pathfinder.ts:305-372)
-    pub fn calculate_node_influences(&self, paths: &[InfluencePath]) -> Vec<InfluentialNode> {
+    /// Ścieżki są przenoszone do węzłów bez kopiowania.
+    pub fn calculate_node_influences(&self, paths: Vec<InfluencePath>) -> Vec<InfluentialNode> {
+        self.calculate_node_influences_with(paths, &FeedbackModel::default())
+    }
+
+    /// @cybernetic Wpływ każdego węzła na cel z wybranym modelem sprzężeń zwrotnych
+    pub fn calculate_node_influences_with(
+        &self,
+        paths: Vec<InfluencePath>,
+        model: &FeedbackModel,
+    ) -> Vec<InfluentialNode> {
         let mut node_influence: HashMap<
             String,
             (Vec<InfluencePath>, f64, f64),
         > = HashMap::new();
+        // Kolejność pierwszego wystąpienia (jak Map w TS) - stabilne remisy przy sortowaniu
+        let mut order: Vec<String> = Vec::new();
 
         // Agreguj ścieżki według pierwszego węzła (najbliższego wpływu)
         for path in paths {
@@ -303,30 +412,38 @@ impl Graph {
 
             let influencer_id = &path.path[0]; // Pierwszy węzeł w ścieżce
 
-            let entry = node_influence
-                .entry(influencer_id.clone())
-                .or_insert((Vec::new(), 0.0, 0.0));
+            let entry = node_influence.entry(influencer_id.clone()).or_insert_with(|| {
+                order.push(influencer_id.clone());
+                (Vec::new(), 0.0, 0.0)
+            });
 
-            entry.0.push(path.clone());
             entry.1 += path.total_strength;
             entry.2 += path.certainty_score;
+            entry.0.push(path);
         }
 
         // Przekształć na InfluentialNode[]
         let mut result = Vec::new();
 
-        for (object_id, (paths, total_strength, total_certainty)) in node_influence {
+        for object_id in order {
+            let (paths, total_strength, total_certainty) = node_influence
+                .remove(&object_id)
+                .expect("order tracks inserted keys");
             if let Some(obj) = self.objects.get(&object_id) {
                 let path_count = paths.len();
                 let avg_influence = total_strength / path_count as f64;
                 let avg_certainty = total_certainty / path_count as f64;
 
+                // Rozbicie wpływu wg biegunowości ścieżek
+                let (positive, negative) = paths.iter().fold((0.0, 0.0), |(pos, neg), p| match p.polarity {
+                    Polarity::Positive => (pos + p.total_strength, neg),
+                    Polarity::Negative => (pos, neg + p.total_strength),
+                });
+                let positive_influence = positive / path_count as f64;
+                let negative_influence = negative / path_count as f64;
+
                 // Oblicz mnożnik sprzężeń zwrotnych
-                let all_feedback_types: Vec<RelationType> = paths
-                    .iter()
-                    .flat_map(|p| p.feedback_types.clone())
-                    .collect();
-                let feedback_multiplier = calculate_feedback_multiplier(&all_feedback_types);
+                let feedback_multiplier = model.node_multiplier(&paths);
 
                 // Oblicz dźwignię sterowniczą
                 let available_power = obj.energy_params.available_power;
@@ -346,6 +463,10 @@ impl Graph {
                     certainty_score: avg_certainty,
                     control_leverage,
                     paths,
+                    positive_influence,
+                    negative_influence,
+                    net_influence: positive_influence - negative_influence,
+                    explanation: None,
                 });
             }
         }
@@ -361,18 +482,9 @@ impl Graph {
 /// @cybernetic Oblicza mnożnik sprzężenia zwrotnego
 ///
 /// Identyczny z TypeScript calculateFeedbackMultiplier (types.ts:111-123)
-fn calculate_feedback_multiplier(feedback_types: &[RelationType]) -> f64 {
-    let mut multiplier = 1.0;
-
-    for feedback_type in feedback_types {
-        match feedback_type {
-            RelationType::PositiveFeedback => multiplier *= 1.5, // Wzmacnia
-            RelationType::NegativeFeedback => multiplier *= 0.7, // Osłabia
-            _ => {}
-        }
-    }
-
-    multiplier
+/// - domyślny FeedbackModel: 1.5 za dodatnie, 0.7 za ujemne
+pub fn calculate_feedback_multiplier(feedback_types: &[RelationType]) -> f64 {
+    FeedbackModel::default().multiplier(feedback_types)
 }
 
 /// @cybernetic Oblicza dźwignię sterowniczą
@@ -454,7 +566,7 @@ pub fn calculate_distortion(i_in: f64, i_real: f64) -> f64 {
 pub struct DistortionAnalysis {
     pub distortion_coefficient: f64,
     pub is_distorted: bool,
-    distortion_type: String, // "neutral", "propaganda", "suppression" - private, accessible via getter
+    distortion_type: String, // "neutral", "propaganda", "suppression", "fabrication" - private, accessible via getter
 }
 
 #[wasm_bindgen]
@@ -468,26 +580,17 @@ impl DistortionAnalysis {
 
 /// @cybernetic Analizuje zniekształcenie informacji z flagami
 ///
-/// Rozszerzona wersja calculate_distortion z automatyczną detekcją typu zniekształcenia
+/// Rozszerzona wersja calculate_distortion z automatyczną detekcją typu zniekształcenia.
+/// Ocena na symetrycznej skali log-ratio z tolerancją 5% (zob. moduł `distortion`);
+/// dla I_real = 0 zwraca typ "fabrication" ze skończonym współczynnikiem.
 #[wasm_bindgen]
 pub fn analyze_distortion(i_in: f64, i_real: f64) -> DistortionAnalysis {
-    let z = calculate_distortion(i_in, i_real);
-
-    const TOLERANCE: f64 = 0.05; // 5% tolerancji
-
-    let is_distorted = (z - 1.0).abs() > TOLERANCE;
-    let distortion_type = if !is_distorted {
-        "neutral".to_string()
-    } else if z > 1.0 {
-        "propaganda".to_string() // Przesada
-    } else {
-        "suppression".to_string() // Tuszowanie
-    };
+    let report = distortion::analyze(i_in, i_real, &distortion::DistortionConfig::default());
 
     DistortionAnalysis {
-        distortion_coefficient: z,
-        is_distorted,
-        distortion_type,
+        distortion_coefficient: report.distortion_coefficient,
+        is_distorted: report.is_distorted,
+        distortion_type: report.distortion_type.as_str().to_string(),
     }
 }
 
@@ -506,40 +609,92 @@ pub fn wasm_find_influence_paths(
     target_id: &str,
     goal: &str,
 ) -> Result<String, JsValue> {
-    // Parse input
-    let objects: Vec<CyberneticObject> = serde_json::from_str(objects_json)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse objects: {}", e)))?;
+    let steering_goal = parse_steering_goal(goal)?;
 
-    let correlations: Vec<Correlation> = serde_json::from_str(correlations_json)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse correlations: {}", e)))?;
+    // Parse input + buduj graf
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+
+    // BFS + dźwignia sterownicza, posortowane DESC
+    let influential_nodes = graph.rank_influential_nodes(target_id, &steering_goal);
+
+    // Serialize do JSON
+    let result_json = serde_json::to_string(&influential_nodes)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))?;
 
-    let steering_goal: SteeringGoal = match goal {
-        "strengthen" => SteeringGoal::Strengthen,
-        "weaken" => SteeringGoal::Weaken,
-        _ => return Err(JsValue::from_str("Invalid goal: must be 'strengthen' or 'weaken'")),
+    Ok(result_json)
+}
+
+/// @cybernetic WASM Entry Point - BFS z konfigurowalnym modelem sprzężeń zwrotnych
+///
+/// `model_json` pusty = domyślny FeedbackModel (wyniki jak wasm_find_influence_paths).
+#[wasm_bindgen]
+pub fn wasm_find_influence_paths_with_model(
+    objects_json: &str,
+    correlations_json: &str,
+    target_id: &str,
+    goal: &str,
+    model_json: &str,
+) -> Result<String, JsValue> {
+    let steering_goal = parse_steering_goal(goal)?;
+    let model = if model_json.trim().is_empty() {
+        FeedbackModel::default()
+    } else {
+        FeedbackModel::from_json(model_json).map_err(|e| JsValue::from_str(&e))?
     };
 
-    // Buduj graf
-    let graph = Graph::new(objects, correlations);
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let influential_nodes = graph.rank_influential_nodes_with(target_id, &steering_goal, &model);
 
-    // Znajdź ścieżki wpływu (BFS)
-    let paths = graph.find_influence_paths(target_id, &steering_goal);
+    serde_json::to_string(&influential_nodes)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
 
-    // Oblicz wpływy węzłów
-    let mut influential_nodes = graph.calculate_node_influences(&paths);
+/// @cybernetic WASM Entry Point - BFS z budżetem pracy i anulowaniem
+///
+/// `budget_json` pusty = bez limitów; `clock` zwraca czas w ms (np. `performance.now`);
+/// `token` anulowany z JS (np. z callbacku zegara) przerywa BFS.
+/// Wynik: `{ result: InfluentialNode[], completed, stop_reason, expanded_nodes, elapsed_millis }`.
+#[wasm_bindgen]
+pub fn wasm_find_influence_paths_budgeted(
+    objects_json: &str,
+    correlations_json: &str,
+    target_id: &str,
+    goal: &str,
+    budget_json: &str,
+    clock: Option<js_sys::Function>,
+    token: &CancellationToken,
+) -> Result<String, JsValue> {
+    let steering_goal = parse_steering_goal(goal)?;
+    let mut budget = budget_from_js(budget_json, clock, Some(token))?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let ranked =
+        graph.rank_influential_nodes_budgeted(target_id, &steering_goal, &FeedbackModel::default(), &mut budget);
+
+    serde_json::to_string(&ranked)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
 
-    // Sortuj według dźwigni sterowniczej (DESC)
-    influential_nodes.sort_by(|a, b| {
-        b.control_leverage
-            .partial_cmp(&a.control_leverage)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
+/// @cybernetic Parsuje cel sterowania przekazany z TypeScript
+pub(crate) fn parse_steering_goal(goal: &str) -> Result<SteeringGoal, JsValue> {
+    match goal {
+        "strengthen" => Ok(SteeringGoal::Strengthen),
+        "weaken" => Ok(SteeringGoal::Weaken),
+        _ => Err(JsValue::from_str("Invalid goal: must be 'strengthen' or 'weaken'")),
+    }
+}
 
-    // Serialize do JSON
-    let result_json = serde_json::to_string(&influential_nodes)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))?;
+/// @cybernetic Buduje graf z tablic JSON obiektów i relacji (wejście z TypeScript)
+pub(crate) fn build_graph_from_json(
+    objects_json: &str,
+    correlations_json: &str,
+) -> Result<Graph, JsValue> {
+    let objects: Vec<CyberneticObject> = serde_json::from_str(objects_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse objects: {}", e)))?;
 
-    Ok(result_json)
+    let correlations: Vec<Correlation> = serde_json::from_str(correlations_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse correlations: {}", e)))?;
+
+    Ok(Graph::new(objects, correlations))
 }
 
 /// @cybernetic WASM Entry Point - Obliczanie mocy P = v × a × c
@@ -589,6 +744,8 @@ mod tests {
                 power_v: 100.0,
                 quality_a: 0.8,
                 mass_c: 10.0,
+                civilization_code: CivilizationCode::Unknown,
+                motivation_type: MotivationType::Informational,
                 created_at: "2025-01-01T00:00:00Z".to_string(),
             },
         ];
@@ -662,4 +819,40 @@ mod tests {
         assert!(!analysis2.is_distorted);
         assert_eq!(analysis2.distortion_type, "neutral");
     }
+
+    #[test]
+    fn test_analyze_distortion_fabrication() {
+        // Informacja z niczego - skończony współczynnik zamiast INFINITY
+        let analysis = analyze_distortion(10.0, 0.0);
+        assert!(analysis.distortion_coefficient.is_finite());
+        assert!(analysis.is_distorted);
+        assert_eq!(analysis.distortion_type, "fabrication");
+    }
+
+    #[test]
+    fn test_signed_path_polarity() {
+        use crate::test_fixtures::{correlation, object};
+
+        // a hamuje b, b wzmacnia cel; a wzmacnia cel bezpośrednio
+        let graph = Graph::new(
+            vec![object("a", 1.0), object("b", 1.0), object("cel", 1.0)],
+            vec![
+                correlation("a", "b", RelationType::NegativeFeedback, 0.5, 1.0),
+                correlation("b", "cel", RelationType::DirectControl, 0.8, 1.0),
+                correlation("a", "cel", RelationType::Supply, 0.3, 1.0),
+            ],
+        );
+        let nodes = graph.rank_influential_nodes("cel", &SteeringGoal::Strengthen);
+        let a = nodes.iter().find(|n| n.object_id == "a").unwrap();
+
+        let indirect = a.paths.iter().find(|p| p.depth == 2).unwrap();
+        assert_eq!(indirect.polarity, Polarity::Negative);
+        assert!((indirect.signed_strength + 0.4).abs() < 1e-12);
+
+        assert!((a.positive_influence - 0.15).abs() < 1e-12);
+        assert!((a.negative_influence - 0.2).abs() < 1e-12);
+        assert!((a.positive_influence + a.negative_influence - a.influence_strength).abs() < 1e-12);
+        assert!(a.net_influence < 0.0);
+        assert_eq!(Polarity::of_path(&[RelationType::NegativeFeedback, RelationType::NegativeFeedback]), Polarity::Positive);
+    }
 }
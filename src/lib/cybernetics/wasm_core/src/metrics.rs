@@ -0,0 +1,483 @@
+//! @fileoverview Miary centralności grafu sterowania
+//! @cybernetic Globalna ważność strukturalna obiektów - bez wybierania celu
+//!
+//! Waga krawędzi = impact_factor × certainty_score. Krawędź source → target
+//! oznacza, że source steruje target, dlatego PageRank i Katz liczymy w obu
+//! kierunkach:
+//! - `*_in`  - ile sterowania spływa do obiektu (obiekt sterowany)
+//! - `*_out` - ile sterowania wychodzi z obiektu (ośrodek sterowania, "hub")
+//!
+//! Pośrednictwo (Brandes) wskazuje "brokerów" leżących na najkrótszych
+//! ścieżkach, gdzie długość krawędzi = 1 / waga (silna relacja = krótka).
+//!
+//! Budżet (`centrality_budgeted`): iteracja PageRank / Katz i ustalenie węzła
+//! w Dijkstrze Brandesa to jedno rozwinięcie. Po wyczerpaniu PageRank i Katz
+//! zwracają ostatnie przybliżenie, a pośrednictwo - sumę po źródłach
+//! przetworzonych w całości.
+
+use crate::budget::{budget_from_js, Budgeted, CancellationToken, WorkBudget};
+use crate::indexed::IndexedGraph;
+use crate::{build_graph_from_json, Graph};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Parametry miar centralności
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CentralityConfig {
+    pub damping: f64,             // Współczynnik tłumienia PageRank
+    pub katz_alpha: Option<f64>,  // None = 0.9 / ograniczenie promienia spektralnego
+    pub katz_beta: f64,           // Wpływ własny węzła w Katz
+    pub max_iterations: usize,
+    pub tolerance: f64,           // Zbieżność (suma |Δ|)
+}
+
+impl Default for CentralityConfig {
+    fn default() -> Self {
+        CentralityConfig {
+            damping: 0.85,
+            katz_alpha: None,
+            katz_beta: 1.0,
+            max_iterations: 200,
+            tolerance: 1e-10,
+        }
+    }
+}
+
+/// @cybernetic Kierunek przepływu w miarach iteracyjnych
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowDirection {
+    Incoming, // Wzdłuż krawędzi: source → target
+    Outgoing, // Pod prąd: target → source
+}
+
+// ============================================================================
+// WYNIK
+// ============================================================================
+
+/// @cybernetic Miary centralności jednego obiektu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeCentrality {
+    pub object_id: String,
+    pub object_name: String,
+    pub pagerank_in: f64,  // Suma po węzłach = 1
+    pub pagerank_out: f64, // Suma po węzłach = 1
+    pub betweenness: f64,  // Znormalizowane do [0, 1]: / ((n-1)(n-2))
+    pub katz_in: f64,      // Znormalizowane: max = 1
+    pub katz_out: f64,     // Znormalizowane: max = 1
+}
+
+// ============================================================================
+// ALGORYTMY
+// ============================================================================
+
+/// Krawędzie o dodatniej wadze w zadanym kierunku: (od, do, waga)
+fn directed_edges(graph: &IndexedGraph, direction: FlowDirection) -> Vec<(usize, usize, f64)> {
+    graph
+        .edges
+        .iter()
+        .filter(|e| e.weight > 0.0)
+        .map(|e| match direction {
+            FlowDirection::Incoming => (e.source, e.target, e.weight),
+            FlowDirection::Outgoing => (e.target, e.source, e.weight),
+        })
+        .collect()
+}
+
+/// @cybernetic Ważony PageRank (węzły bez wyjść rozdzielają masę równomiernie)
+pub fn pagerank(graph: &IndexedGraph, direction: FlowDirection, config: &CentralityConfig) -> Vec<f64> {
+    pagerank_within(graph, direction, config, &mut WorkBudget::unlimited())
+}
+
+/// @cybernetic PageRank w ramach budżetu (jedna iteracja = jedno rozwinięcie)
+pub fn pagerank_within(
+    graph: &IndexedGraph,
+    direction: FlowDirection,
+    config: &CentralityConfig,
+    budget: &mut WorkBudget,
+) -> Vec<f64> {
+    let n = graph.node_count();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let edges = directed_edges(graph, direction);
+    let mut out_weight = vec![0.0; n];
+    for &(from, _, w) in &edges {
+        out_weight[from] += w;
+    }
+
+    let uniform = 1.0 / n as f64;
+    let mut rank = vec![uniform; n];
+
+    for _ in 0..config.max_iterations {
+        if !budget.tick() {
+            break;
+        }
+        let dangling: f64 = (0..n).filter(|&i| out_weight[i] == 0.0).map(|i| rank[i]).sum();
+        let base = (1.0 - config.damping) * uniform + config.damping * dangling * uniform;
+
+        let mut next = vec![base; n];
+        for &(from, to, w) in &edges {
+            next[to] += config.damping * rank[from] * w / out_weight[from];
+        }
+
+        let delta: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if delta < config.tolerance {
+            break;
+        }
+    }
+
+    rank
+}
+
+/// @cybernetic Ważona centralność Katza, znormalizowana do max = 1
+///
+/// x_i = α Σ_{j→i} w_ji x_j + β. Domyślne α = 0.9 / min(max suma wierszy,
+/// max suma kolumn), co gwarantuje zbieżność (α < 1 / promień spektralny).
+pub fn katz(graph: &IndexedGraph, direction: FlowDirection, config: &CentralityConfig) -> Vec<f64> {
+    katz_within(graph, direction, config, &mut WorkBudget::unlimited())
+}
+
+/// @cybernetic Katz w ramach budżetu (jedna iteracja = jedno rozwinięcie)
+pub fn katz_within(
+    graph: &IndexedGraph,
+    direction: FlowDirection,
+    config: &CentralityConfig,
+    budget: &mut WorkBudget,
+) -> Vec<f64> {
+    let n = graph.node_count();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let edges = directed_edges(graph, direction);
+    let alpha = config.katz_alpha.unwrap_or_else(|| {
+        let mut row = vec![0.0; n];
+        let mut col = vec![0.0; n];
+        for &(from, to, w) in &edges {
+            row[from] += w;
+            col[to] += w;
+        }
+        let max_row = row.iter().cloned().fold(0.0, f64::max);
+        let max_col = col.iter().cloned().fold(0.0, f64::max);
+        let bound = max_row.min(max_col);
+        if bound > 0.0 {
+            0.9 / bound
+        } else {
+            0.0
+        }
+    });
+
+    let mut x = vec![config.katz_beta; n];
+    for _ in 0..config.max_iterations {
+        if !budget.tick() {
+            break;
+        }
+        let mut next = vec![config.katz_beta; n];
+        for &(from, to, w) in &edges {
+            next[to] += alpha * w * x[from];
+        }
+
+        let delta: f64 = next.iter().zip(&x).map(|(a, b)| (a - b).abs()).sum();
+        x = next;
+        if delta < config.tolerance {
+            break;
+        }
+    }
+
+    let max = x.iter().cloned().fold(0.0, f64::max);
+    if max > 0.0 {
+        x.iter_mut().for_each(|v| *v /= max);
+    }
+    x
+}
+
+/// Element kolejki Dijkstry (min-heap po odległości)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HeapItem {
+    distance: f64,
+    node: usize,
+}
+
+impl Eq for HeapItem {}
+
+impl Ord for HeapItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for HeapItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// @cybernetic Ważone pośrednictwo (algorytm Brandesa), znormalizowane do [0, 1]
+pub fn betweenness(graph: &IndexedGraph) -> Vec<f64> {
+    betweenness_within(graph, &mut WorkBudget::unlimited())
+}
+
+/// @cybernetic Pośrednictwo w ramach budżetu (ustalenie węzła = jedno rozwinięcie)
+///
+/// Źródło przerwane w połowie Dijkstry nie jest doliczane.
+pub fn betweenness_within(graph: &IndexedGraph, budget: &mut WorkBudget) -> Vec<f64> {
+    let n = graph.node_count();
+    let mut centrality = vec![0.0; n];
+    let edges = directed_edges(graph, FlowDirection::Incoming);
+
+    let mut adjacency: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+    for &(from, to, w) in &edges {
+        if from != to {
+            adjacency[from].push((to, 1.0 / w));
+        }
+    }
+
+    'sources: for s in 0..n {
+        let mut stack = Vec::with_capacity(n);
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut sigma = vec![0.0; n];
+        let mut distance = vec![f64::INFINITY; n];
+        let mut settled = vec![false; n];
+        sigma[s] = 1.0;
+        distance[s] = 0.0;
+
+        let mut heap = BinaryHeap::new();
+        heap.push(HeapItem { distance: 0.0, node: s });
+
+        while let Some(HeapItem { distance: d, node: v }) = heap.pop() {
+            if settled[v] {
+                continue;
+            }
+            if !budget.tick() {
+                break 'sources;
+            }
+            settled[v] = true;
+            stack.push(v);
+
+            for &(w, length) in &adjacency[v] {
+                let candidate = d + length;
+                if candidate < distance[w] {
+                    distance[w] = candidate;
+                    sigma[w] = sigma[v];
+                    predecessors[w] = vec![v];
+                    heap.push(HeapItem { distance: candidate, node: w });
+                } else if candidate == distance[w] {
+                    sigma[w] += sigma[v];
+                    predecessors[w].push(v);
+                }
+            }
+        }
+
+        // Akumulacja zależności w kolejności malejącej odległości
+        let mut delta = vec![0.0; n];
+        while let Some(w) = stack.pop() {
+            for &v in &predecessors[w] {
+                delta[v] += sigma[v] / sigma[w] * (1.0 + delta[w]);
+            }
+            if w != s {
+                centrality[w] += delta[w];
+            }
+        }
+    }
+
+    if n > 2 {
+        let scale = ((n - 1) * (n - 2)) as f64;
+        centrality.iter_mut().for_each(|c| *c /= scale);
+    }
+    centrality
+}
+
+// ============================================================================
+// API GRAFU
+// ============================================================================
+
+impl Graph {
+    /// @cybernetic Miary centralności wszystkich obiektów (kolejność wg ID)
+    pub fn centrality(&self, config: &CentralityConfig) -> Vec<NodeCentrality> {
+        self.centrality_budgeted(config, &mut WorkBudget::unlimited()).result
+    }
+
+    /// @cybernetic Centralność w ramach budżetu (wspólnego dla wszystkich miar)
+    ///
+    /// Miary liczone są po kolei: PageRank, Katz, pośrednictwo. Po wyczerpaniu
+    /// budżetu kolejne miary zostają w stanie początkowym (`completed: false`).
+    pub fn centrality_budgeted(
+        &self,
+        config: &CentralityConfig,
+        budget: &mut WorkBudget,
+    ) -> Budgeted<Vec<NodeCentrality>> {
+        let indexed = self.indexed();
+
+        let pagerank_in = pagerank_within(&indexed, FlowDirection::Incoming, config, budget);
+        let pagerank_out = pagerank_within(&indexed, FlowDirection::Outgoing, config, budget);
+        let katz_in = katz_within(&indexed, FlowDirection::Incoming, config, budget);
+        let katz_out = katz_within(&indexed, FlowDirection::Outgoing, config, budget);
+        let betweenness = betweenness_within(&indexed, budget);
+
+        let nodes = indexed
+            .ids
+            .iter()
+            .enumerate()
+            .map(|(idx, id)| NodeCentrality {
+                object_id: id.clone(),
+                object_name: self.object(id).map(|o| o.name.clone()).unwrap_or_else(|| id.clone()),
+                pagerank_in: pagerank_in[idx],
+                pagerank_out: pagerank_out[idx],
+                betweenness: betweenness[idx],
+                katz_in: katz_in[idx],
+                katz_out: katz_out[idx],
+            })
+            .collect();
+        budget.wrap(nodes)
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// Parsuje CentralityConfig (pusty JSON = domyślna)
+fn parse_centrality_config(config_json: &str) -> Result<CentralityConfig, JsValue> {
+    if config_json.trim().is_empty() {
+        return Ok(CentralityConfig::default());
+    }
+    serde_json::from_str(config_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse centrality config: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Centralność (PageRank, pośrednictwo, Katz) per obiekt
+///
+/// `config_json` pusty = parametry domyślne.
+#[wasm_bindgen]
+pub fn wasm_calculate_centrality(
+    objects_json: &str,
+    correlations_json: &str,
+    config_json: &str,
+) -> Result<String, JsValue> {
+    let config = parse_centrality_config(config_json)?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.centrality(&config);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Centralność z budżetem pracy
+///
+/// `budget_json` pusty = bez limitów. Wynik: `{ result: NodeCentrality[], completed, ... }`.
+#[wasm_bindgen]
+pub fn wasm_calculate_centrality_budgeted(
+    objects_json: &str,
+    correlations_json: &str,
+    config_json: &str,
+    budget_json: &str,
+    clock: Option<js_sys::Function>,
+    token: &CancellationToken,
+) -> Result<String, JsValue> {
+    let config = parse_centrality_config(config_json)?;
+    let mut budget = budget_from_js(budget_json, clock, Some(token))?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.centrality_budgeted(&config, &mut budget);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::graph;
+
+    fn find<'a>(nodes: &'a [NodeCentrality], id: &str) -> &'a NodeCentrality {
+        nodes.iter().find(|n| n.object_id == id).unwrap()
+    }
+
+    #[test]
+    fn test_star_hub_dominates_outgoing_rank() {
+        // Centrum steruje czterema obiektami
+        let g = graph(
+            &["hub", "a", "b", "c", "d"],
+            &[("hub", "a", 1.0), ("hub", "b", 1.0), ("hub", "c", 1.0), ("hub", "d", 1.0)],
+        );
+        let nodes = g.centrality(&CentralityConfig::default());
+
+        let hub = find(&nodes, "hub");
+        assert!(nodes.iter().all(|n| n.pagerank_out <= hub.pagerank_out));
+        assert_eq!(hub.katz_out, 1.0);
+        assert!(hub.katz_in < find(&nodes, "a").katz_in);
+
+        let total: f64 = nodes.iter().map(|n| n.pagerank_in).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_betweenness_identifies_broker() {
+        // a → broker → b, c → broker → d
+        let g = graph(
+            &["a", "b", "c", "d", "broker"],
+            &[("a", "broker", 1.0), ("broker", "b", 1.0), ("c", "broker", 1.0), ("broker", "d", 1.0)],
+        );
+        let nodes = g.centrality(&CentralityConfig::default());
+
+        // 4 pary (a,b), (a,d), (c,b), (c,d) przez brokera / (4 × 3)
+        assert!((find(&nodes, "broker").betweenness - 4.0 / 12.0).abs() < 1e-12);
+        assert_eq!(find(&nodes, "a").betweenness, 0.0);
+    }
+
+    #[test]
+    fn test_weighted_shortest_paths_prefer_strong_edges() {
+        // Silna droga a → x → t (1 + 1) vs słaba bezpośrednia a → t (1 / 0.1 = 10)
+        let g = graph(
+            &["a", "x", "t"],
+            &[("a", "x", 1.0), ("x", "t", 1.0), ("a", "t", 0.1)],
+        );
+        let nodes = g.centrality(&CentralityConfig::default());
+        assert_eq!(find(&nodes, "x").betweenness, 0.5); // 1 para / (2 × 1)
+    }
+
+    #[test]
+    fn test_budget_stops_before_betweenness() {
+        let g = graph(
+            &["a", "b", "c", "d", "broker"],
+            &[("a", "broker", 1.0), ("broker", "b", 1.0), ("c", "broker", 1.0), ("broker", "d", 1.0)],
+        );
+        let full = g.centrality(&CentralityConfig::default());
+
+        // Tyle rozwinięć, ile zużyją PageRank i Katz - pośrednictwo już się nie zmieści
+        let mut probe = WorkBudget::unlimited();
+        let indexed = g.indexed();
+        for direction in [FlowDirection::Incoming, FlowDirection::Outgoing] {
+            pagerank_within(&indexed, direction, &CentralityConfig::default(), &mut probe);
+        }
+        for direction in [FlowDirection::Incoming, FlowDirection::Outgoing] {
+            katz_within(&indexed, direction, &CentralityConfig::default(), &mut probe);
+        }
+        let mut budget = WorkBudget::new(crate::budget::BudgetConfig {
+            max_expanded_nodes: Some(probe.expanded()),
+            ..Default::default()
+        });
+        let partial = g.centrality_budgeted(&CentralityConfig::default(), &mut budget);
+
+        assert!(!partial.outcome.completed);
+        assert_eq!(find(&partial.result, "broker").pagerank_in, find(&full, "broker").pagerank_in);
+        assert_eq!(find(&partial.result, "broker").katz_out, find(&full, "broker").katz_out);
+        assert_eq!(find(&partial.result, "broker").betweenness, 0.0);
+    }
+}
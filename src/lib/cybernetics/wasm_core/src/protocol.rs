@@ -0,0 +1,766 @@
+//! @fileoverview Protokół komunikatów dla Web Workera
+//! @cybernetic Worker posiada Graf, UI wymienia tylko komunikaty
+//!
+//! `MessageHandler::handle_message(bytes) -> bytes` przyjmuje kopertę żądania
+//! (JSON w UTF-8) i zwraca kopertę odpowiedzi:
+//!
+//! ```json
+//! { "version": 1, "id": 7, "command": { "type": "query_influence", "target_id": "x", "goal": "strengthen" } }
+//! { "version": 1, "id": 7, "status": "ok", "result": { "type": "influence", "result": [...], "completed": true, ... } }
+//! { "version": 1, "id": 7, "status": "error", "error": { "code": "no_graph", "message": "..." } }
+//! ```
+//!
+//! `id` jest dowolną wartością JSON odsyłaną bez zmian. Komendy: `load_graph`,
+//! `patch_graph`, `query_influence`, `run_simulation`, `continue`, `cancel`.
+//!
+//! Anulowanie: `handle_message` jest synchroniczne, więc `cancel` wysłany w
+//! trakcie długiego zapytania czeka w kolejce workera aż do jego końca.
+//! Zapytanie z `slice: N` rozwija najwyżej N węzłów na komunikat i odpowiada
+//! `progress`; worker odsyła wtedy sobie `continue` (np. przez `setTimeout(0)`),
+//! więc `cancel` z kolejki zostaje obsłużony między porcjami, a następne
+//! `continue` kończy zapytanie wynikiem częściowym (`stop_reason: cancelled`).
+//! Bez `slice` zapytanie liczy się w jednym komunikacie - przerwać je można
+//! wtedy tylko tokenem przełączonym z callbacku zegara (patrz `CancellationToken`).
+
+use crate::budget::{BudgetConfig, Budgeted, CancellationToken, WorkBudget};
+use crate::decisions::recommend::{Language, SteeringSimulationResult};
+use crate::feedback::FeedbackModel;
+use crate::search::PathSearchState;
+use crate::{Correlation, CyberneticObject, Graph, InfluencePath, InfluentialNode, SteeringGoal};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+/// Aktualna wersja kopert (żądania z wersją 1..=PROTOCOL_VERSION są obsługiwane)
+pub const PROTOCOL_VERSION: u32 = 1;
+
+// ============================================================================
+// ŻĄDANIA
+// ============================================================================
+
+/// @cybernetic Zmiana przyrostowa grafu (upsert po ID, usunięcia po ID)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphPatch {
+    pub upsert_objects: Vec<CyberneticObject>,
+    pub remove_object_ids: Vec<String>,
+    pub upsert_correlations: Vec<Correlation>,
+    pub remove_correlation_ids: Vec<String>,
+}
+
+/// @cybernetic Komenda w kopercie żądania
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Command {
+    LoadGraph {
+        objects: Vec<CyberneticObject>,
+        correlations: Vec<Correlation>,
+    },
+    PatchGraph(GraphPatch),
+    QueryInfluence {
+        target_id: String,
+        goal: SteeringGoal,
+        #[serde(default)]
+        model: FeedbackModel,
+        #[serde(default)]
+        budget: BudgetConfig,
+        #[serde(default)]
+        slice: Option<usize>, // Rozwinięć na komunikat (None = całość od razu)
+    },
+    RunSimulation {
+        target_id: String,
+        goal: SteeringGoal,
+        #[serde(default)]
+        language: Language,
+        #[serde(default)]
+        budget: BudgetConfig,
+        #[serde(default)]
+        slice: Option<usize>,
+    },
+    Continue, // Następna porcja zapytania z `slice`
+    Cancel,
+}
+
+/// @cybernetic Koperta żądania
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestEnvelope {
+    pub version: u32,
+    #[serde(default)]
+    pub id: Value,
+    pub command: Command,
+}
+
+// ============================================================================
+// ODPOWIEDZI
+// ============================================================================
+
+/// @cybernetic Kod błędu protokołu
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    InvalidMessage,     // Niepoprawny JSON, brak pól, nieznana komenda
+    UnsupportedVersion, // Wersja spoza 1..=PROTOCOL_VERSION
+    NoGraph,            // Komenda wymaga wcześniejszego load_graph
+    UnknownTarget,      // Obiekt docelowy nie istnieje w grafie
+    Busy,               // Trwa zapytanie porcjowane - najpierw continue albo cancel
+    NoPendingQuery,     // `continue` bez zapytania porcjowanego
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtocolError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl ProtocolError {
+    fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        ProtocolError {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// @cybernetic Wynik komendy
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CommandResult {
+    GraphLoaded {
+        object_count: usize,
+        correlation_count: usize,
+    },
+    Influence(Budgeted<Vec<InfluentialNode>>),
+    Simulation(Box<Budgeted<SteeringSimulationResult>>),
+    Progress {
+        paths_found: usize,
+        expanded_nodes: usize,
+    },
+    Cancelled {
+        in_flight: bool, // Czy trwało obliczenie, które zostanie przerwane
+    },
+}
+
+/// @cybernetic Treść odpowiedzi: sukces albo błąd
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ResponseBody {
+    Ok { result: CommandResult },
+    Error { error: ProtocolError },
+}
+
+/// @cybernetic Koperta odpowiedzi
+#[derive(Debug, Clone, Serialize)]
+pub struct ResponseEnvelope {
+    pub version: u32,
+    pub id: Value,
+    #[serde(flatten)]
+    pub body: ResponseBody,
+}
+
+// ============================================================================
+// DYSPOZYTOR
+// ============================================================================
+
+/// Co policzyć ze ścieżek po zakończeniu przeszukiwania
+enum QueryKind {
+    Influence { model: FeedbackModel },
+    Simulation { goal: SteeringGoal, language: Language, started_at: Option<f64> },
+}
+
+/// @cybernetic Zapytanie w toku: wznawialny BFS + budżet całego zapytania
+struct PendingQuery {
+    kind: QueryKind,
+    target_id: String,
+    search: PathSearchState,
+    paths: Vec<InfluencePath>,
+    budget: WorkBudget,
+    slice: Option<usize>,
+}
+
+/// @cybernetic Stan workera: graf + token anulowania bieżącego obliczenia
+#[wasm_bindgen]
+pub struct MessageHandler {
+    graph: Option<Graph>,
+    token: CancellationToken,
+    pending: Option<PendingQuery>,
+    clock: Option<Rc<dyn Fn() -> f64>>,
+}
+
+impl Default for MessageHandler {
+    fn default() -> Self {
+        MessageHandler::new()
+    }
+}
+
+impl MessageHandler {
+    pub fn new() -> Self {
+        MessageHandler {
+            graph: None,
+            token: CancellationToken::new(),
+            pending: None,
+            clock: None,
+        }
+    }
+
+    /// Zegar gospodarza w ms - limity czasu i `computation_time_ms`
+    pub fn with_clock(mut self, clock: impl Fn() -> f64 + 'static) -> Self {
+        self.clock = Some(Rc::new(clock));
+        self
+    }
+
+    pub fn graph(&self) -> Option<&Graph> {
+        self.graph.as_ref()
+    }
+
+    /// Token współdzielony z obliczeniami (anulowanie spoza `cancel`)
+    ///
+    /// Ustawiony poza zapytaniem nie przepada: przerywa następne zapytanie.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    /// Czy czeka zapytanie porcjowane (`continue` / `cancel`)
+    pub fn in_flight(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// @cybernetic Bajty żądania → bajty odpowiedzi (zawsze poprawna koperta)
+    pub fn handle_message(&mut self, bytes: &[u8]) -> Vec<u8> {
+        let response = self.handle_bytes(bytes);
+        serde_json::to_vec(&response).unwrap_or_else(|e| {
+            let fallback = ResponseEnvelope {
+                version: PROTOCOL_VERSION,
+                id: response.id.clone(),
+                body: ResponseBody::Error {
+                    error: ProtocolError::new(ErrorCode::InvalidMessage, format!("Failed to serialize result: {}", e)),
+                },
+            };
+            serde_json::to_vec(&fallback).unwrap_or_default()
+        })
+    }
+
+    fn handle_bytes(&mut self, bytes: &[u8]) -> ResponseEnvelope {
+        // Najpierw surowy JSON, by odesłać `id` także przy błędnej komendzie
+        let raw: Value = match serde_json::from_slice(bytes) {
+            Ok(raw) => raw,
+            Err(e) => {
+                return Self::respond(
+                    Value::Null,
+                    Err(ProtocolError::new(ErrorCode::InvalidMessage, format!("Failed to parse message: {}", e))),
+                )
+            }
+        };
+        let id = raw.get("id").cloned().unwrap_or(Value::Null);
+
+        let version = raw.get("version").and_then(Value::as_u64).unwrap_or(0);
+        if version == 0 || version > PROTOCOL_VERSION as u64 {
+            return Self::respond(
+                id,
+                Err(ProtocolError::new(
+                    ErrorCode::UnsupportedVersion,
+                    format!("Unsupported protocol version {} (supported: 1..={})", version, PROTOCOL_VERSION),
+                )),
+            );
+        }
+
+        match serde_json::from_value::<RequestEnvelope>(raw) {
+            Ok(request) => Self::respond(id, self.handle(request.command)),
+            Err(e) => Self::respond(
+                id,
+                Err(ProtocolError::new(ErrorCode::InvalidMessage, format!("Failed to parse command: {}", e))),
+            ),
+        }
+    }
+
+    fn respond(id: Value, result: Result<CommandResult, ProtocolError>) -> ResponseEnvelope {
+        ResponseEnvelope {
+            version: PROTOCOL_VERSION,
+            id,
+            body: match result {
+                Ok(result) => ResponseBody::Ok { result },
+                Err(error) => ResponseBody::Error { error },
+            },
+        }
+    }
+
+    /// @cybernetic Wykonuje komendę na stanie workera
+    pub fn handle(&mut self, command: Command) -> Result<CommandResult, ProtocolError> {
+        match command {
+            Command::LoadGraph { objects, correlations } => {
+                self.require_idle()?;
+                self.graph = Some(Graph::new(objects, correlations));
+                Ok(self.graph_loaded())
+            }
+            Command::PatchGraph(patch) => {
+                self.require_idle()?;
+                let graph = self.require_graph()?;
+                self.graph = Some(graph.patched(&patch));
+                Ok(self.graph_loaded())
+            }
+            Command::QueryInfluence {
+                target_id,
+                goal: _, // Ranking dźwigni nie zależy od kierunku sterowania
+                model,
+                budget,
+                slice,
+            } => {
+                model
+                    .validate()
+                    .map_err(|e| ProtocolError::new(ErrorCode::InvalidMessage, e))?;
+                self.start_query(QueryKind::Influence { model }, target_id, budget, slice)
+            }
+            Command::RunSimulation {
+                target_id,
+                goal,
+                language,
+                budget,
+                slice,
+            } => {
+                let started_at = self.now();
+                let kind = QueryKind::Simulation { goal, language, started_at };
+                self.start_query(kind, target_id, budget, slice)
+            }
+            Command::Continue => {
+                let query = self
+                    .pending
+                    .take()
+                    .ok_or_else(|| ProtocolError::new(ErrorCode::NoPendingQuery, "No sliced query in progress"))?;
+                self.run_query(query)
+            }
+            Command::Cancel => {
+                // Bez zapytania w toku nie ma czego przerwać - token zostaje czysty,
+                // by nie zabić następnego zapytania
+                let in_flight = self.in_flight();
+                if in_flight {
+                    self.token.cancel();
+                }
+                Ok(CommandResult::Cancelled { in_flight })
+            }
+        }
+    }
+
+    /// Nowe zapytanie: budżet całego zapytania, pierwsza porcja od razu
+    fn start_query(
+        &mut self,
+        kind: QueryKind,
+        target_id: String,
+        budget: BudgetConfig,
+        slice: Option<usize>,
+    ) -> Result<CommandResult, ProtocolError> {
+        self.require_idle()?;
+        self.require_target(&target_id)?;
+        if slice == Some(0) {
+            return Err(ProtocolError::new(ErrorCode::InvalidMessage, "slice must be >= 1"));
+        }
+
+        let query = PendingQuery {
+            kind,
+            search: PathSearchState::new(&target_id),
+            target_id,
+            paths: Vec::new(),
+            budget: self.budget(budget),
+            slice,
+        };
+        self.run_query(query)
+    }
+
+    /// Kolejna porcja BFS; po zakończeniu - ranking albo symulacja ze ścieżek
+    fn run_query(&mut self, mut query: PendingQuery) -> Result<CommandResult, ProtocolError> {
+        let graph = self.require_graph()?;
+        if let Some(slice) = query.slice {
+            query.budget.pause_after(slice);
+        }
+
+        let finished = query.search.collect_capped_within(graph, &mut query.paths, &mut query.budget);
+        if !finished {
+            let progress = CommandResult::Progress {
+                paths_found: query.paths.len(),
+                expanded_nodes: query.budget.expanded(),
+            };
+            self.pending = Some(query);
+            return Ok(progress);
+        }
+
+        // `cancel` z czasu trwania zapytania dotyczył tego zapytania - zużyty,
+        // następne startuje z czystym tokenem
+        let outcome = query.budget.outcome();
+        self.token.reset();
+
+        match query.kind {
+            QueryKind::Influence { model } => Ok(CommandResult::Influence(Budgeted {
+                result: graph.rank_paths(query.paths, &model),
+                outcome,
+            })),
+            QueryKind::Simulation { goal, language, started_at } => {
+                let mut result = graph
+                    .recommend_from_paths(&query.target_id, &goal, language, query.paths)
+                    .ok_or_else(|| Self::unknown_target(&query.target_id))?;
+                if let (Some(start), Some(end)) = (started_at, self.now()) {
+                    result.simulation_metadata.computation_time_ms = end - start;
+                }
+                Ok(CommandResult::Simulation(Box::new(Budgeted { result, outcome })))
+            }
+        }
+    }
+
+    fn graph_loaded(&self) -> CommandResult {
+        let graph = self.graph.as_ref();
+        CommandResult::GraphLoaded {
+            object_count: graph.map_or(0, |g| g.objects().count()),
+            correlation_count: graph.map_or(0, |g| g.correlations().len()),
+        }
+    }
+
+    fn require_idle(&self) -> Result<(), ProtocolError> {
+        match self.pending {
+            Some(_) => Err(ProtocolError::new(
+                ErrorCode::Busy,
+                "Sliced query in progress (send continue or cancel first)",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn require_graph(&self) -> Result<&Graph, ProtocolError> {
+        self.graph
+            .as_ref()
+            .ok_or_else(|| ProtocolError::new(ErrorCode::NoGraph, "No graph loaded (send load_graph first)"))
+    }
+
+    fn require_target(&self, target_id: &str) -> Result<&Graph, ProtocolError> {
+        let graph = self.require_graph()?;
+        graph.object(target_id).map(|_| graph).ok_or_else(|| Self::unknown_target(target_id))
+    }
+
+    fn unknown_target(target_id: &str) -> ProtocolError {
+        ProtocolError::new(ErrorCode::UnknownTarget, format!("Unknown target object: {}", target_id))
+    }
+
+    fn now(&self) -> Option<f64> {
+        self.clock.as_ref().map(|clock| clock())
+    }
+
+    /// Nowe obliczenie: świeży budżet na wspólnym tokenie (bez zerowania -
+    /// anulowanie ustawione przed startem przerywa to zapytanie)
+    fn budget(&self, config: BudgetConfig) -> WorkBudget {
+        let mut budget = WorkBudget::new(config).with_cancellation(self.token.clone());
+        if let Some(clock) = &self.clock {
+            let clock = clock.clone();
+            budget = budget.with_clock(move || clock());
+        }
+        budget
+    }
+}
+
+impl Graph {
+    /// @cybernetic Kopia grafu po zastosowaniu zmiany przyrostowej
+    ///
+    /// Relacje usuniętych obiektów znikają; upsert relacji zachowuje jej
+    /// pozycję w kolejności wejściowej, nowe trafiają na koniec.
+    pub fn patched(&self, patch: &GraphPatch) -> Graph {
+        let mut objects: Vec<CyberneticObject> = self
+            .objects()
+            .filter(|o| !patch.remove_object_ids.contains(&o.id))
+            .filter(|o| !patch.upsert_objects.iter().any(|u| u.id == o.id))
+            .cloned()
+            .collect();
+        objects.extend(patch.upsert_objects.iter().cloned());
+
+        let removed = |id: &String| patch.remove_object_ids.contains(id);
+        let mut correlations: Vec<Correlation> = self
+            .correlations()
+            .iter()
+            .filter(|c| !patch.remove_correlation_ids.contains(&c.id))
+            .filter(|c| !removed(&c.source_id) && !removed(&c.target_id))
+            .cloned()
+            .collect();
+        for upsert in &patch.upsert_correlations {
+            match correlations.iter_mut().find(|c| c.id == upsert.id) {
+                Some(existing) => *existing = upsert.clone(),
+                None => correlations.push(upsert.clone()),
+            }
+        }
+
+        Graph::new(objects, correlations)
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+#[wasm_bindgen]
+impl MessageHandler {
+    /// @cybernetic Konstruktor dla JS (zegar: Date.now)
+    #[wasm_bindgen(constructor)]
+    pub fn wasm_new() -> MessageHandler {
+        MessageHandler::new().with_clock(js_sys::Date::now)
+    }
+
+    /// @cybernetic Uint8Array żądania → Uint8Array odpowiedzi
+    #[wasm_bindgen(js_name = handleMessage)]
+    pub fn wasm_handle_message(&mut self, bytes: &[u8]) -> Vec<u8> {
+        self.handle_message(bytes)
+    }
+
+    #[wasm_bindgen(getter, js_name = cancellationToken)]
+    pub fn wasm_cancellation_token(&self) -> CancellationToken {
+        self.cancellation_token()
+    }
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{correlation, object};
+    use crate::RelationType;
+    use serde_json::json;
+
+    fn send(handler: &mut MessageHandler, request: Value) -> Value {
+        let bytes = handler.handle_message(request.to_string().as_bytes());
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    fn loaded() -> MessageHandler {
+        let mut handler = MessageHandler::new();
+        let response = send(
+            &mut handler,
+            json!({
+                "version": 1,
+                "id": "load",
+                "command": {
+                    "type": "load_graph",
+                    "objects": [object("a", 1.0), object("b", 0.5), object("cel", 1.0)],
+                    "correlations": [
+                        correlation("a", "cel", RelationType::DirectControl, 0.9, 1.0),
+                        correlation("b", "cel", RelationType::DirectControl, 0.8, 1.0),
+                    ],
+                }
+            }),
+        );
+        assert_eq!(response["result"]["object_count"], 3);
+        handler
+    }
+
+    fn query(handler: &mut MessageHandler, id: u32) -> Value {
+        send(
+            handler,
+            json!({
+                "version": 1,
+                "id": id,
+                "command": { "type": "query_influence", "target_id": "cel", "goal": "strengthen" }
+            }),
+        )
+    }
+
+    #[test]
+    fn test_load_and_query_influence() {
+        let mut handler = loaded();
+        let response = query(&mut handler, 7);
+
+        assert_eq!(response["version"], PROTOCOL_VERSION);
+        assert_eq!(response["id"], 7);
+        assert_eq!(response["status"], "ok");
+        assert_eq!(response["result"]["type"], "influence");
+        assert_eq!(response["result"]["completed"], true);
+        assert_eq!(response["result"]["result"][0]["object_id"], "a");
+    }
+
+    #[test]
+    fn test_patch_graph_changes_ranking() {
+        let mut handler = loaded();
+        let response = send(
+            &mut handler,
+            json!({
+                "version": 1,
+                "id": 2,
+                "command": {
+                    "type": "patch_graph",
+                    "remove_object_ids": ["a"],
+                    "upsert_objects": [object("b", 4.0)],
+                }
+            }),
+        );
+        assert_eq!(response["result"]["object_count"], 2);
+        assert_eq!(response["result"]["correlation_count"], 1);
+
+        let ranking = query(&mut handler, 3);
+        assert_eq!(ranking["result"]["result"][0]["object_id"], "b");
+        assert_eq!(ranking["result"]["result"][0]["available_power"], 4.0);
+    }
+
+    #[test]
+    fn test_error_envelopes() {
+        let mut handler = MessageHandler::new();
+
+        let garbage: Value = serde_json::from_slice(&handler.handle_message(b"not json")).unwrap();
+        assert_eq!(garbage["error"]["code"], "invalid_message");
+        assert_eq!(garbage["id"], Value::Null);
+
+        let future = send(&mut handler, json!({ "version": 99, "id": 1, "command": { "type": "cancel" } }));
+        assert_eq!(future["error"]["code"], "unsupported_version");
+        assert_eq!(future["id"], 1);
+
+        let unknown = send(&mut handler, json!({ "version": 1, "id": 2, "command": { "type": "explode" } }));
+        assert_eq!(unknown["error"]["code"], "invalid_message");
+
+        assert_eq!(query(&mut handler, 3)["error"]["code"], "no_graph");
+        let mut handler = loaded();
+        let missing = send(
+            &mut handler,
+            json!({ "version": 1, "id": 4, "command": { "type": "run_simulation", "target_id": "x", "goal": "weaken" } }),
+        );
+        assert_eq!(missing["status"], "error");
+        assert_eq!(missing["error"]["code"], "unknown_target");
+    }
+
+    #[test]
+    fn test_simulation_and_cancel() {
+        let mut handler = loaded().with_clock(|| 5.0);
+        let simulation = send(
+            &mut handler,
+            json!({
+                "version": 1,
+                "id": 1,
+                "command": { "type": "run_simulation", "target_id": "cel", "goal": "strengthen", "language": "en" }
+            }),
+        );
+        assert_eq!(simulation["result"]["type"], "simulation");
+        assert_eq!(simulation["result"]["completed"], true);
+        assert_eq!(
+            simulation["result"]["result"]["primary_recommendation"]["object_id"],
+            "a"
+        );
+        assert_eq!(
+            simulation["result"]["result"]["simulation_metadata"]["computation_time_ms"],
+            0.0
+        );
+
+        // Bez zapytania w toku `cancel` nie rusza tokenu
+        let cancel = send(
+            &mut handler,
+            json!({ "version": 1, "id": 2, "command": { "type": "cancel" } }),
+        );
+        assert_eq!(cancel["result"]["in_flight"], false);
+        assert!(!handler.cancellation_token().is_cancelled());
+        assert_eq!(query(&mut handler, 3)["result"]["completed"], true);
+
+        // Token ustawiony z zewnątrz przed startem nie przepada - przerywa symulację
+        handler.cancellation_token().cancel();
+        let cancelled = send(
+            &mut handler,
+            json!({
+                "version": 1,
+                "id": 4,
+                "command": { "type": "run_simulation", "target_id": "cel", "goal": "strengthen" }
+            }),
+        );
+        assert_eq!(cancelled["result"]["completed"], false);
+        assert_eq!(cancelled["result"]["stop_reason"], "cancelled");
+        assert!(!handler.cancellation_token().is_cancelled());
+    }
+
+    /// Łańcuch cel <- m0 <- m1 <- ... z dwoma źródłami na każdym ogniwie
+    fn chain(length: usize) -> MessageHandler {
+        let mut objects = vec![object("cel", 1.0)];
+        let mut correlations = Vec::new();
+        for i in 0..length {
+            let link = format!("m{}", i);
+            let next = if i == 0 { "cel".to_string() } else { format!("m{}", i - 1) };
+            objects.push(object(&link, 1.0));
+            correlations.push(correlation(&link, &next, RelationType::DirectControl, 0.9, 1.0));
+            for side in ["l", "r"] {
+                let source = format!("{}{}", side, i);
+                objects.push(object(&source, 0.5));
+                correlations.push(correlation(&source, &link, RelationType::DirectControl, 0.8, 1.0));
+            }
+        }
+
+        let mut handler = MessageHandler::new();
+        handler.handle(Command::LoadGraph { objects, correlations }).unwrap();
+        handler
+    }
+
+    fn sliced_query(handler: &mut MessageHandler, id: u32) -> Value {
+        send(
+            handler,
+            json!({
+                "version": 1,
+                "id": id,
+                "command": { "type": "query_influence", "target_id": "cel", "goal": "strengthen", "slice": 1 }
+            }),
+        )
+    }
+
+    fn continue_query(handler: &mut MessageHandler, id: u32) -> Value {
+        send(
+            handler,
+            json!({ "version": 1, "id": id, "command": { "type": "continue" } }),
+        )
+    }
+
+    #[test]
+    fn test_sliced_query_matches_single_message_query() {
+        let mut handler = chain(4);
+        let whole = query(&mut handler, 1);
+
+        let mut response = sliced_query(&mut handler, 2);
+        let mut steps = 0;
+        while response["result"]["type"] == "progress" {
+            assert!(handler.in_flight());
+            steps += 1;
+            response = continue_query(&mut handler, 3);
+        }
+
+        assert!(steps > 1);
+        assert!(!handler.in_flight());
+        assert_eq!(response["result"]["completed"], true);
+        assert_eq!(response["result"]["result"], whole["result"]["result"]);
+        assert_eq!(
+            response["result"]["expanded_nodes"],
+            whole["result"]["expanded_nodes"]
+        );
+    }
+
+    #[test]
+    fn test_cancel_stops_sliced_query_between_steps() {
+        let mut handler = chain(6);
+        let whole = query(&mut handler, 1);
+        let whole_nodes = whole["result"]["result"].as_array().unwrap().len();
+
+        let progress = sliced_query(&mut handler, 2);
+        assert_eq!(progress["result"]["type"], "progress");
+        let expanded_before = progress["result"]["expanded_nodes"].as_u64().unwrap();
+
+        // Nowe zapytanie w trakcie porcjowanego jest odrzucane
+        assert_eq!(query(&mut handler, 3)["error"]["code"], "busy");
+
+        let cancel = send(
+            &mut handler,
+            json!({ "version": 1, "id": 4, "command": { "type": "cancel" } }),
+        );
+        assert_eq!(cancel["result"]["in_flight"], true);
+
+        let stopped = continue_query(&mut handler, 5);
+        assert_eq!(stopped["result"]["type"], "influence");
+        assert_eq!(stopped["result"]["completed"], false);
+        assert_eq!(stopped["result"]["stop_reason"], "cancelled");
+        assert_eq!(
+            stopped["result"]["expanded_nodes"].as_u64().unwrap(),
+            expanded_before
+        );
+        assert!(stopped["result"]["result"].as_array().unwrap().len() < whole_nodes);
+
+        assert!(!handler.in_flight());
+        assert_eq!(
+            continue_query(&mut handler, 6)["error"]["code"],
+            "no_pending_query"
+        );
+        assert_eq!(query(&mut handler, 7)["result"]["completed"], true);
+    }
+}
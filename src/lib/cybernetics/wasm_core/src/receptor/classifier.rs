@@ -0,0 +1,397 @@
+//! @fileoverview Klasyfikatory leksykalne Receptora
+//! @cybernetic Port detectIdeologicalFlags i classifyCivilizationPattern (receptor/classifier.ts)
+//!
+//! Tekst jest normalizowany (małe litery, bez polskich znaków diakrytycznych),
+//! dzielony na słowa i sprowadzany do rdzeni prostym stemmerem sufiksowym.
+//! Dzięki temu "władza", "władzy" i "władzą" trafiają w ten sam wskaźnik,
+//! a "prawo" nie trafia już w "prawdopodobnie".
+
+use crate::CivilizationCode;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use wasm_bindgen::prelude::*;
+
+/// Domyślne leksykony - wskaźniki z constants.ts i classifier.ts
+const DEFAULT_LEXICONS_JSON: &str = include_str!("lexicons.json");
+
+/// Minimalny wynik dominującego wzorca (poniżej = 'mixed'), jak w classifier.ts
+const MIN_DOMINANT_SCORE: f64 = 2.0;
+
+/// Minimalna długość rdzenia po obcięciu końcówki
+const MIN_STEM_LENGTH: usize = 3;
+
+/// Końcówki fleksyjne (po usunięciu diakrytyków), od najdłuższej
+const SUFFIXES: &[&str] = &[
+    "owie", "ami", "ach", "ego", "emu", "ich", "imi", "ymi", "ych", "iej", "iem", "owi", "ow",
+    "om", "em", "ej", "ie", "ia", "ii", "ja", "ji", "je", "ju", "a", "e", "i", "o", "u", "y",
+];
+
+// ============================================================================
+// NORMALIZACJA I STEMMING
+// ============================================================================
+
+/// @cybernetic Zamienia polskie litery na odpowiedniki ASCII (ą→a, ł→l, ż→z...)
+pub fn fold_diacritics(c: char) -> char {
+    match c {
+        'ą' => 'a',
+        'ć' => 'c',
+        'ę' => 'e',
+        'ł' => 'l',
+        'ń' => 'n',
+        'ó' => 'o',
+        'ś' => 's',
+        'ź' | 'ż' => 'z',
+        _ => c,
+    }
+}
+
+/// @cybernetic Prosty stemmer sufiksowy dla języka polskiego
+///
+/// Obcina najdłuższą pasującą końcówkę, o ile rdzeń zachowa min. 3 znaki.
+/// Oczekuje słowa już znormalizowanego (małe litery, bez diakrytyków).
+pub fn stem(word: &str) -> String {
+    let length = word.chars().count();
+
+    for suffix in SUFFIXES {
+        if word.ends_with(suffix) && length - suffix.len() >= MIN_STEM_LENGTH {
+            return word[..word.len() - suffix.len()].to_string();
+        }
+    }
+
+    word.to_string()
+}
+
+/// @cybernetic Normalizuje tekst do listy rdzeni słów
+pub fn normalize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .chars()
+        .map(fold_diacritics)
+        .collect::<String>()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(stem)
+        .collect()
+}
+
+// ============================================================================
+// LEKSYKONY WAŻONE
+// ============================================================================
+
+fn default_term_weight() -> f64 {
+    1.0
+}
+
+/// @cybernetic Wskaźnik leksykalny (słowo lub fraza) z wagą
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LexiconTerm {
+    pub term: String,
+    #[serde(default = "default_term_weight")]
+    pub weight: f64,
+}
+
+/// @cybernetic Klasa leksykonu (np. wzorzec cywilizacyjny, flaga ideologiczna)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LexiconClass {
+    pub label: String,
+    pub terms: Vec<LexiconTerm>,
+}
+
+/// @cybernetic Leksykon ważony - kolejność klas rozstrzyga remisy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeightedLexicon {
+    pub classes: Vec<LexiconClass>,
+}
+
+/// @cybernetic Wynik jednej klasy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassScore {
+    pub label: String,
+    pub score: f64,
+    pub matched_terms: Vec<String>,
+}
+
+impl WeightedLexicon {
+    /// @cybernetic Wczytuje leksykon z JSON
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse lexicon: {}", e))
+    }
+
+    /// @cybernetic Ocenia tekst - wynik dla każdej klasy (w kolejności leksykonu)
+    ///
+    /// Każdy wskaźnik liczony jest raz (obecność), jak w classifier.ts.
+    pub fn score(&self, text: &str) -> Vec<ClassScore> {
+        let stems = normalize(text);
+
+        self.classes
+            .iter()
+            .map(|class| {
+                let mut score = 0.0;
+                let mut matched_terms = Vec::new();
+
+                for term in &class.terms {
+                    if contains_sequence(&stems, &normalize(&term.term)) {
+                        score += term.weight;
+                        matched_terms.push(term.term.clone());
+                    }
+                }
+
+                ClassScore {
+                    label: class.label.clone(),
+                    score,
+                    matched_terms,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Czy `needle` występuje w `haystack` jako ciągła sekwencja rdzeni
+fn contains_sequence(haystack: &[String], needle: &[String]) -> bool {
+    !needle.is_empty() && haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+/// @cybernetic Komplet leksykonów Receptora
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lexicons {
+    pub civilization: WeightedLexicon,
+    pub ideological_flags: WeightedLexicon,
+}
+
+fn default_lexicons() -> &'static Lexicons {
+    static LEXICONS: OnceLock<Lexicons> = OnceLock::new();
+    LEXICONS.get_or_init(|| {
+        serde_json::from_str(DEFAULT_LEXICONS_JSON).expect("lexicons.json must be valid")
+    })
+}
+
+impl Default for Lexicons {
+    fn default() -> Self {
+        default_lexicons().clone()
+    }
+}
+
+// ============================================================================
+// KLASYFIKATOR CYWILIZACYJNY
+// ============================================================================
+
+/// @cybernetic Wzorzec cywilizacyjny wg Kosseckiego
+///
+/// - LATIN - Prawo ponad władzą
+/// - BYZANTINE - Władza ponad prawem
+/// - TURANIAN - Siła ponad wszystkim
+/// - JEWISH - Tożsamość grupowa
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CivilizationPattern {
+    Latin,
+    Byzantine,
+    Turanian,
+    Jewish,
+    Mixed,
+}
+
+impl CivilizationPattern {
+    /// @cybernetic Wzorzec odpowiadający etykiecie klasy leksykonu
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "latin" => Some(CivilizationPattern::Latin),
+            "byzantine" => Some(CivilizationPattern::Byzantine),
+            "turanian" => Some(CivilizationPattern::Turanian),
+            "jewish" => Some(CivilizationPattern::Jewish),
+            "mixed" => Some(CivilizationPattern::Mixed),
+            _ => None,
+        }
+    }
+
+    /// @cybernetic Kod cywilizacyjny do zapisu w `objects.civilization_code`
+    ///
+    /// schema.sql nie ma kodu dla wzorca żydowskiego - zapisujemy go jako 'mixed'.
+    pub fn civilization_code(&self) -> CivilizationCode {
+        match self {
+            CivilizationPattern::Latin => CivilizationCode::Latin,
+            CivilizationPattern::Byzantine => CivilizationCode::Byzantine,
+            CivilizationPattern::Turanian => CivilizationCode::Turandot,
+            CivilizationPattern::Jewish | CivilizationPattern::Mixed => CivilizationCode::Mixed,
+        }
+    }
+}
+
+/// @cybernetic Wynik klasyfikacji cywilizacyjnej
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CivilizationClassification {
+    pub pattern: CivilizationPattern,
+    pub civilization_code: CivilizationCode,
+    pub scores: Vec<ClassScore>,
+}
+
+/// @cybernetic Klasyfikacja wzorca cywilizacyjnego (domyślny leksykon)
+pub fn classify_civilization_pattern(text: &str) -> CivilizationClassification {
+    classify_civilization_with(text, &default_lexicons().civilization)
+}
+
+/// @cybernetic Klasyfikacja wzorca cywilizacyjnego z własnym leksykonem
+///
+/// Dominujący wzorzec = najwyższy wynik (remis → pierwszy w leksykonie);
+/// wynik poniżej 2.0 oznacza brak wyraźnego wzorca → 'mixed'.
+pub fn classify_civilization_with(
+    text: &str,
+    lexicon: &WeightedLexicon,
+) -> CivilizationClassification {
+    let scores = lexicon.score(text);
+
+    let mut max_score = 0.0;
+    let mut dominant = CivilizationPattern::Mixed;
+    for class_score in &scores {
+        if class_score.score > max_score {
+            max_score = class_score.score;
+            dominant = CivilizationPattern::from_label(&class_score.label)
+                .unwrap_or(CivilizationPattern::Mixed);
+        }
+    }
+
+    let pattern = if max_score < MIN_DOMINANT_SCORE {
+        CivilizationPattern::Mixed
+    } else {
+        dominant
+    };
+
+    CivilizationClassification {
+        pattern,
+        civilization_code: pattern.civilization_code(),
+        scores,
+    }
+}
+
+// ============================================================================
+// DETEKTOR FLAG IDEOLOGICZNYCH
+// ============================================================================
+
+/// @cybernetic Wynik detekcji flag ideologicznych
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdeologicalFlags {
+    pub flags: Vec<String>, // Flagi z wynikiem > 0 (jak detectIdeologicalFlags)
+    pub scores: Vec<ClassScore>,
+}
+
+/// @cybernetic Wykrywanie flag ideologicznych (domyślny leksykon)
+pub fn detect_ideological_flags(text: &str) -> IdeologicalFlags {
+    detect_ideological_flags_with(text, &default_lexicons().ideological_flags)
+}
+
+/// @cybernetic Wykrywanie flag ideologicznych z własnym leksykonem
+pub fn detect_ideological_flags_with(text: &str, lexicon: &WeightedLexicon) -> IdeologicalFlags {
+    let scores = lexicon.score(text);
+    let flags = scores
+        .iter()
+        .filter(|class_score| class_score.score > 0.0)
+        .map(|class_score| class_score.label.clone())
+        .collect();
+
+    IdeologicalFlags { flags, scores }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+fn parse_lexicon_arg(lexicon_json: &str) -> Result<Option<WeightedLexicon>, JsValue> {
+    if lexicon_json.trim().is_empty() {
+        return Ok(None);
+    }
+    WeightedLexicon::from_json(lexicon_json)
+        .map(Some)
+        .map_err(|e| JsValue::from_str(&e))
+}
+
+/// @cybernetic WASM Entry Point - Klasyfikacja cywilizacyjna z wynikami per klasa
+///
+/// `lexicon_json` pusty = domyślne wskaźniki z constants.ts
+#[wasm_bindgen]
+pub fn wasm_classify_civilization_pattern(
+    text: &str,
+    lexicon_json: &str,
+) -> Result<String, JsValue> {
+    let result = match parse_lexicon_arg(lexicon_json)? {
+        Some(lexicon) => classify_civilization_with(text, &lexicon),
+        None => classify_civilization_pattern(text),
+    };
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Flagi ideologiczne z wynikami per flaga
+#[wasm_bindgen]
+pub fn wasm_detect_ideological_flags(text: &str, lexicon_json: &str) -> Result<String, JsValue> {
+    let result = match parse_lexicon_arg(lexicon_json)? {
+        Some(lexicon) => detect_ideological_flags_with(text, &lexicon),
+        None => detect_ideological_flags(text),
+    };
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stemmer_handles_inflection() {
+        assert_eq!(normalize("władza władzy władzą"), vec!["wladz", "wladz", "wladz"]);
+        assert_eq!(normalize("Konstytucja, konstytucji!"), vec!["konstytuc", "konstytuc"]);
+        assert_eq!(normalize("wrogowie wrogiem wróg"), vec!["wrog", "wrog", "wrog"]);
+        assert_eq!(stem("dom"), "dom"); // Za krótkie, by obcinać
+    }
+
+    #[test]
+    fn test_civilization_pattern_with_inflected_text() {
+        let text = "Władzy należy się posłuszeństwo, a biurokracji hierarchia.";
+        let result = classify_civilization_pattern(text);
+
+        assert_eq!(result.pattern, CivilizationPattern::Byzantine);
+        assert_eq!(result.civilization_code, CivilizationCode::Byzantine);
+
+        let byzantine = result.scores.iter().find(|s| s.label == "byzantine").unwrap();
+        assert_eq!(byzantine.score, 4.0);
+        assert_eq!(result.scores.len(), 4);
+    }
+
+    #[test]
+    fn test_civilization_pattern_below_threshold_is_mixed() {
+        // "prawdopodobnie" nie może trafiać we wskaźnik "prawo"
+        let result = classify_civilization_pattern("Prawdopodobnie chodzi o prawo.");
+        assert_eq!(result.pattern, CivilizationPattern::Mixed);
+        assert_eq!(result.scores[0].score, 1.0);
+    }
+
+    #[test]
+    fn test_ideological_flags() {
+        let result = detect_ideological_flags("Wrogowie ludu i zdrajcy mówią o historycznej konieczności.");
+        assert_eq!(
+            result.flags,
+            vec!["WRÓG_LUDU", "ETYKIETA_ZDRAJCY", "DETERMINIZM_HISTORYCZNY"]
+        );
+        assert_eq!(result.scores.len(), 9);
+    }
+
+    #[test]
+    fn test_custom_weighted_lexicon() {
+        let lexicon = WeightedLexicon::from_json(
+            r#"{ "classes": [
+                { "label": "latin", "terms": [{ "term": "sąd", "weight": 2.5 }] },
+                { "label": "turanian", "terms": [{ "term": "siła" }] }
+            ] }"#,
+        )
+        .unwrap();
+
+        let result = classify_civilization_with("Sądy ponad siłą", &lexicon);
+        assert_eq!(result.pattern, CivilizationPattern::Latin);
+        assert_eq!(result.scores[0].score, 2.5);
+        assert_eq!(result.scores[1].score, 1.0);
+    }
+}
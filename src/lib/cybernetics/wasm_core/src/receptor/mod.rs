@@ -0,0 +1,9 @@
+//! @fileoverview Receptor - analiza tekstu źródłowego po stronie Rust
+//! @cybernetic Port heurystyk z receptor/classifier.ts
+//!
+//! Pozwala uruchomić te same oceny sygnału w Wasm (przeglądarka)
+//! i natywnie (zadania wsadowe), bez zależności od Node.
+
+pub mod classifier;
+pub mod noise;
+pub mod validator;
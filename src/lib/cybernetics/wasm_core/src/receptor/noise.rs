@@ -0,0 +1,308 @@
+//! @fileoverview Detektor szumu semantycznego
+//! @cybernetic Port calculateSemanticNoise (receptor/classifier.ts)
+//!
+//! Szum = mętność, pustosłowie, brak konkretów.
+//! - 0.0-0.3 = tekst precyzyjny
+//! - 0.3-0.7 = tekst mieszany
+//! - 0.7-1.0 = tekst mętny, ideologiczny
+//!
+//! Listy wskaźników i wagi pochodzą z danych (`noise_indicators.json`),
+//! a nie z kodu - redakcja może je podmienić bez przebudowy modułu.
+
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use wasm_bindgen::prelude::*;
+
+/// Domyślna konfiguracja - identyczna z listami w classifier.ts
+const DEFAULT_CONFIG_JSON: &str = include_str!("noise_indicators.json");
+
+// ============================================================================
+// KONFIGURACJA
+// ============================================================================
+
+/// @cybernetic Grupa wskaźników szumu o wspólnej wadze
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndicatorGroup {
+    pub category: String,
+    pub weight: f64,
+    pub indicators: Vec<String>,
+}
+
+/// @cybernetic Konfiguracja detektora szumu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoiseConfig {
+    pub indicator_groups: Vec<IndicatorGroup>,
+    pub long_sentence_words: usize,    // Zdanie dłuższe niż N słów = szum
+    pub long_sentence_weight: f64,     // Waga udziału długich zdań
+    pub missing_numbers_penalty: f64,  // Kara za brak liczb
+    pub missing_proper_nouns_penalty: f64, // Kara za brak nazw własnych
+}
+
+impl NoiseConfig {
+    /// @cybernetic Wczytuje konfigurację z JSON
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse noise config: {}", e))
+    }
+}
+
+impl Default for NoiseConfig {
+    fn default() -> Self {
+        default_config().clone()
+    }
+}
+
+fn default_config() -> &'static NoiseConfig {
+    static CONFIG: OnceLock<NoiseConfig> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        NoiseConfig::from_json(DEFAULT_CONFIG_JSON).expect("noise_indicators.json must be valid")
+    })
+}
+
+// ============================================================================
+// WYNIK ANALIZY
+// ============================================================================
+
+/// @cybernetic Wskaźnik, który zadziałał w tekście
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FiredIndicator {
+    pub category: String,
+    pub indicator: String,
+    pub contribution: f64,
+}
+
+/// @cybernetic Rozbicie oceny szumu - dlaczego tekst uznano za mętny
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoiseBreakdown {
+    pub noise_level: f64,              // Wynik końcowy (0-1), = calculateSemanticNoise
+    pub raw_score: f64,                // Suma przed obcięciem do 1.0
+    pub fired_indicators: Vec<FiredIndicator>,
+    pub sentence_count: usize,
+    pub long_sentence_count: usize,
+    pub long_sentence_contribution: f64,
+    pub has_numbers: bool,
+    pub has_proper_nouns: bool,
+    pub missing_concrete_contribution: f64,
+}
+
+// ============================================================================
+// ANALIZA
+// ============================================================================
+
+/// @cybernetic Obliczanie poziomu szumu semantycznego (domyślne wskaźniki)
+pub fn calculate_semantic_noise(text: &str) -> f64 {
+    analyze_semantic_noise(text, default_config()).noise_level
+}
+
+/// @cybernetic Pełna analiza szumu z rozbiciem na wskaźniki
+///
+/// Kolejność sumowania odpowiada classifier.ts, więc wynik jest bitowo
+/// zgodny z wersją TypeScript.
+pub fn analyze_semantic_noise(text: &str, config: &NoiseConfig) -> NoiseBreakdown {
+    let mut noise_score = 0.0;
+    let lower_text = text.to_lowercase();
+    let mut fired_indicators = Vec::new();
+
+    // Zlicz wystąpienia wskaźników (każdy wskaźnik liczony raz)
+    for group in &config.indicator_groups {
+        for indicator in &group.indicators {
+            if lower_text.contains(indicator.to_lowercase().as_str()) {
+                noise_score += group.weight;
+                fired_indicators.push(FiredIndicator {
+                    category: group.category.clone(),
+                    indicator: indicator.clone(),
+                    contribution: group.weight,
+                });
+            }
+        }
+    }
+
+    // Sprawdź długość zdań - bardzo długie zdania = szum
+    let sentences = split_sentences(text);
+    let long_sentence_count = sentences
+        .iter()
+        .filter(|sentence| sentence.split(' ').count() > config.long_sentence_words)
+        .count();
+    let long_sentence_contribution =
+        (long_sentence_count as f64 / sentences.len() as f64) * config.long_sentence_weight;
+    noise_score += long_sentence_contribution;
+
+    // Sprawdź brak konkretów (liczb, nazw własnych)
+    let has_numbers = text.chars().any(|c| c.is_ascii_digit());
+    let has_proper_nouns = contains_proper_noun(text);
+
+    let mut missing_concrete_contribution = 0.0;
+    if !has_numbers {
+        noise_score += config.missing_numbers_penalty;
+        missing_concrete_contribution += config.missing_numbers_penalty;
+    }
+    if !has_proper_nouns {
+        noise_score += config.missing_proper_nouns_penalty;
+        missing_concrete_contribution += config.missing_proper_nouns_penalty;
+    }
+
+    NoiseBreakdown {
+        noise_level: noise_score.min(1.0),
+        raw_score: noise_score,
+        fired_indicators,
+        sentence_count: sentences.len(),
+        long_sentence_count,
+        long_sentence_contribution,
+        has_numbers,
+        has_proper_nouns,
+        missing_concrete_contribution,
+    }
+}
+
+/// Odpowiednik `text.split(/[.!?]+/)` - ciąg separatorów dzieli tekst raz
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut in_separator = false;
+
+    for (idx, c) in text.char_indices() {
+        let is_separator = matches!(c, '.' | '!' | '?');
+        if is_separator && !in_separator {
+            sentences.push(&text[start..idx]);
+        }
+        if !is_separator && in_separator {
+            start = idx;
+        }
+        in_separator = is_separator;
+    }
+
+    sentences.push(if in_separator { "" } else { &text[start..] });
+    sentences
+}
+
+/// Odpowiednik `/[A-ZĄĆĘŁŃÓŚŹŻ][a-ząćęłńóśźż]+/`
+fn contains_proper_noun(text: &str) -> bool {
+    const UPPER_PL: &str = "ĄĆĘŁŃÓŚŹŻ";
+    const LOWER_PL: &str = "ąćęłńóśźż";
+
+    let is_upper = |c: char| c.is_ascii_uppercase() || UPPER_PL.contains(c);
+    let is_lower = |c: char| c.is_ascii_lowercase() || LOWER_PL.contains(c);
+
+    let mut previous_upper = false;
+    for c in text.chars() {
+        if previous_upper && is_lower(c) {
+            return true;
+        }
+        previous_upper = is_upper(c);
+    }
+    false
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Poziom szumu semantycznego (0-1)
+#[wasm_bindgen]
+pub fn wasm_calculate_semantic_noise(text: &str) -> f64 {
+    calculate_semantic_noise(text)
+}
+
+/// @cybernetic WASM Entry Point - Analiza szumu z rozbiciem na wskaźniki
+///
+/// `config_json` pusty = domyślne wskaźniki z classifier.ts
+#[wasm_bindgen]
+pub fn wasm_analyze_semantic_noise(text: &str, config_json: &str) -> Result<String, JsValue> {
+    let breakdown = if config_json.trim().is_empty() {
+        analyze_semantic_noise(text, default_config())
+    } else {
+        let config = NoiseConfig::from_json(config_json).map_err(|e| JsValue::from_str(&e))?;
+        analyze_semantic_noise(text, &config)
+    };
+
+    serde_json::to_string(&breakdown)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Korpus z receptor/extractor.test.ts + oczekiwane wyniki calculateSemanticNoise (TS)
+    #[test]
+    fn test_matches_typescript_corpus() {
+        let cognitive_text = "
+      Elektrownia jądrowa w Fukushimie składa się z 6 reaktorów typu BWR (Boiling Water Reactor).
+      Każdy reaktor ma moc nominalną 460-784 MW elektrycznych.
+      W 2011 roku, po trzęsieniu ziemi o magnitudzie 9.0, systemy chłodzenia uległy awarii.
+      Reaktory 1, 2 i 3 były w trakcie pracy, reaktory 4, 5 i 6 były wyłączone na konserwację.
+      Temperatura rdzenia reaktora 1 przekroczyła 2800°C, co spowodowało topienie paliwa.
+    ";
+        let ideological_text = "
+      Sprawiedliwy rząd demokratyczny walczy z reakcyjnymi siłami ciemności.
+      Postępowa polityka społeczna jest jedyną słuszną drogą do wolności.
+      Wrogowie narodu próbują zniszczyć nasze piękne wartości.
+      Tylko prawdziwie patriotyczne siły mogą ocalić ojczyznę przed upadkiem.
+      Niesprawiedliwe elity eksploatują uczciwych obywateli.
+      Nowoczesne społeczeństwo odrzuca przestarzałe dogmaty.
+    ";
+
+        let corpus = [
+            (cognitive_text, 0.0),
+            (ideological_text, 0.1),
+            ("Temperatura wody wynosi 25°C. Ciśnienie wynosi 1013 hPa.", 0.0),
+            (
+                "Firma X inwestuje w technologię Y, co może zwiększyć efektywność o 20%. Niektórzy eksperci uważają to za korzystne.",
+                0.05,
+            ),
+            ("", 0.2),
+            ("...Może to nasz naród?! ŁÓDŹ i Żółw", 0.25),
+        ];
+
+        for (text, expected) in corpus {
+            assert_eq!(calculate_semantic_noise(text), expected, "text: {:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_breakdown_lists_fired_indicators() {
+        let long = vec!["słowo"; 45].join(" ");
+        let text = format!(
+            "Wszyscy wiedzą, że wróg ludu to niewątpliwie zdrajca. {}! Oczywiste?",
+            long
+        );
+
+        let breakdown = analyze_semantic_noise(&text, &NoiseConfig::default());
+        assert_eq!(breakdown.noise_level, 0.5); // Wynik TS
+        assert_eq!(breakdown.sentence_count, 4);
+        assert_eq!(breakdown.long_sentence_count, 1);
+        assert!(!breakdown.has_numbers);
+        assert!(breakdown.has_proper_nouns);
+
+        let fired: Vec<&str> = breakdown
+            .fired_indicators
+            .iter()
+            .map(|f| f.indicator.as_str())
+            .collect();
+        assert_eq!(
+            fired,
+            vec!["niewątpliwie", "oczywiste", "wszyscy wiedzą", "wróg ludu", "zdrajca"]
+        );
+    }
+
+    #[test]
+    fn test_custom_config() {
+        let config = NoiseConfig::from_json(
+            r#"{
+                "indicator_groups": [{ "category": "hedge", "weight": 0.3, "indicators": ["RACZEJ"] }],
+                "long_sentence_words": 40,
+                "long_sentence_weight": 0.2,
+                "missing_numbers_penalty": 0.0,
+                "missing_proper_nouns_penalty": 0.0
+            }"#,
+        )
+        .unwrap();
+
+        let breakdown = analyze_semantic_noise("To raczej prawda", &config);
+        assert_eq!(breakdown.noise_level, 0.3);
+        assert_eq!(breakdown.fired_indicators[0].category, "hedge");
+    }
+}
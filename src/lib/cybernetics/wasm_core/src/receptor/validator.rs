@@ -0,0 +1,549 @@
+//! @fileoverview Bramka walidacji sygnału (CLEAR / WARNING / REJECT)
+//! @cybernetic Port calculateSignalStatus i validateCyberneticInput (receptor/validator.ts)
+//!
+//! Sprawdza wyekstrahowane obiekty i relacje: ograniczenia schematów zod
+//! (enumy, zakresy liczbowe), integralność referencyjną oraz progi szumu.
+//! Zamiast przerywać na pierwszym błędzie zbiera pełną listę problemów,
+//! więc ta sama bramka działa w Wasm i w natywnym zadaniu wsadowym.
+
+use crate::MotivationType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// PROGI (zgodne z constants.ts)
+// ============================================================================
+
+/// @cybernetic Maksymalny szum dla STATUS: CLEAR (SIGNAL_CLEAR_MAX_NOISE)
+pub const SIGNAL_CLEAR_MAX_NOISE: f64 = 0.4;
+
+/// @cybernetic Maksymalny szum dla STATUS: WARNING (SIGNAL_WARNING_MAX_NOISE)
+pub const SIGNAL_WARNING_MAX_NOISE: f64 = 0.7;
+
+const SYSTEM_TYPES: &[&str] = &["autonomous_system", "heteronomous_system", "environment", "tool"];
+const CIVILIZATION_CODES: &[&str] = &["latin", "byzantine", "turandot", "mixed", "unknown"];
+// Z enuma MotivationType - obiekt przechodzący bramkę musi się dać wczytać
+const MOTIVATION_TYPES: &[&str] = &{
+    let mut names = [""; MotivationType::ALL.len()];
+    let mut i = 0;
+    while i < names.len() {
+        names[i] = MotivationType::ALL[i].as_str();
+        i += 1;
+    }
+    names
+};
+const PROCESS_TYPES: &[&str] = &["energetic", "informational", "hybrid"];
+const FEEDBACK_TYPES: &[&str] = &["positive", "negative", "neutral"];
+const CONTROL_SYSTEM_TYPES: &[&str] = &["cognitive", "ideological", "ethical", "economic"];
+const NORM_CATEGORIES: &[&str] = &["cognitive", "ideological", "ethical", "legal", "economic", "vital"];
+const SIGNAL_STATUSES: &[&str] = &["CLEAR", "WARNING", "REJECT"];
+
+// ============================================================================
+// TYPY WEJŚCIOWE (odpowiedniki schematów zod)
+// ============================================================================
+
+fn default_one() -> f64 {
+    1.0
+}
+
+fn default_civilization_code() -> String {
+    "unknown".to_string()
+}
+
+fn default_motivation_type() -> String {
+    "informational".to_string()
+}
+
+fn default_norm_category() -> String {
+    "cognitive".to_string()
+}
+
+fn default_source_name() -> String {
+    "unknown".to_string()
+}
+
+/// @cybernetic Obiekt elementarny (ExtractedObjectSchema)
+///
+/// Pola enumeracyjne trzymamy jako tekst, by niedozwolona wartość była
+/// zgłoszonym problemem, a nie błędem parsowania całego sygnału.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedObject {
+    pub id: String,
+    pub label: String,
+    #[serde(rename = "type")]
+    pub object_type: String,
+    pub description: Option<String>,
+    pub estimated_energy: Option<f64>,
+    #[serde(default = "default_one")]
+    pub power_v: f64,
+    #[serde(default = "default_one")]
+    pub quality_a: f64,
+    #[serde(default = "default_one")]
+    pub mass_c: f64,
+    #[serde(default = "default_civilization_code")]
+    pub civilization_code: String,
+    #[serde(default = "default_motivation_type")]
+    pub motivation_type: String,
+}
+
+/// @cybernetic Relacja sterownicza (ExtractedRelationSchema)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedRelation {
+    pub subject_id: String,
+    pub object_id: String,
+    pub process_type: String,
+    pub feedback_type: String,
+    pub system_class: String,
+    pub influence_strength: f64,
+    pub description: String,
+    pub evidence: Option<Vec<String>>,
+    #[serde(default = "default_norm_category")]
+    pub norm_category: String,
+}
+
+/// @cybernetic Metadane ekstrakcji (ExtractionMetadataSchema)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractionMetadata {
+    pub semantic_noise_level: f64,
+    pub signal_status: String,
+    #[serde(default)]
+    pub is_ambiguous: bool,
+    pub ideological_flags: Option<Vec<String>>,
+    pub dominant_system_type: String,
+    pub raw_context: String,
+    pub object_count: f64,
+    pub relation_count: f64,
+    pub warning_message: Option<String>,
+    #[serde(default = "default_source_name")]
+    pub source_name: String,
+}
+
+/// @cybernetic Pełny sygnał po transformacji przez Receptor (CyberneticInputSchema)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CyberneticInput {
+    pub objects: Vec<ExtractedObject>,
+    pub relations: Vec<ExtractedRelation>,
+    pub metadata: ExtractionMetadata,
+}
+
+// ============================================================================
+// WYNIK WALIDACJI
+// ============================================================================
+
+/// @cybernetic Status sygnału po analizie szumu semantycznego
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SignalStatus {
+    Clear,   // 0.0-0.4: Pełna akceptacja sygnału
+    Warning, // 0.4-0.7: Akceptacja warunkowa, wymaga weryfikacji
+    Reject,  // >0.7: Odrzucenie sygnału
+}
+
+impl SignalStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignalStatus::Clear => "CLEAR",
+            SignalStatus::Warning => "WARNING",
+            SignalStatus::Reject => "REJECT",
+        }
+    }
+}
+
+/// @cybernetic Rodzaj problemu wykrytego przez bramkę
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    Schema,               // Niedozwolony enum, zakres liczbowy, puste pole
+    ReferentialIntegrity, // Relacja wskazuje na nieistniejący obiekt / samą siebie
+    CountMismatch,        // metadata.*_count != długość listy
+    StatusMismatch,       // signal_status niezgodny z poziomem szumu
+    NoiseReject,          // Szum > SIGNAL_WARNING_MAX_NOISE
+    NoiseWarning,         // Szum w strefie WARNING bez flagi is_ambiguous
+}
+
+/// @cybernetic Pojedynczy problem walidacji
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub kind: IssueKind,
+    pub path: String,
+    pub message: String,
+}
+
+/// @cybernetic Wynik bramki: status z poziomu szumu + lista problemów
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignalValidation {
+    pub valid: bool,
+    pub status: SignalStatus,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl SignalValidation {
+    /// @cybernetic Komunikaty błędów - odpowiednik `errors` z validateCyberneticInput
+    pub fn errors(&self) -> Vec<String> {
+        self.issues.iter().map(|issue| issue.message.clone()).collect()
+    }
+}
+
+// ============================================================================
+// WALIDACJA
+// ============================================================================
+
+/// @cybernetic Status sygnału na podstawie poziomu szumu semantycznego
+pub fn calculate_signal_status(noise_level: f64) -> SignalStatus {
+    if noise_level <= SIGNAL_CLEAR_MAX_NOISE {
+        SignalStatus::Clear
+    } else if noise_level <= SIGNAL_WARNING_MAX_NOISE {
+        SignalStatus::Warning
+    } else {
+        SignalStatus::Reject
+    }
+}
+
+/// @cybernetic Walidacja całego sygnału wejściowego
+///
+/// Najpierw ograniczenia schematów (w TS egzekwowane przez zod), potem
+/// kontrole validateCyberneticInput w tej samej kolejności i z tymi samymi
+/// komunikatami.
+pub fn validate_cybernetic_input(input: &CyberneticInput) -> SignalValidation {
+    let mut issues = Vec::new();
+
+    check_schema(input, &mut issues);
+
+    // Sprawdź czy wszystkie relacje wskazują na istniejące obiekty
+    let object_ids: HashSet<&str> = input.objects.iter().map(|o| o.id.as_str()).collect();
+    for (idx, relation) in input.relations.iter().enumerate() {
+        let valid = object_ids.contains(relation.subject_id.as_str())
+            && object_ids.contains(relation.object_id.as_str())
+            && relation.subject_id != relation.object_id; // Zakaz samozamykających się relacji
+
+        if !valid {
+            issues.push(issue(
+                IssueKind::ReferentialIntegrity,
+                format!("relations[{}]", idx),
+                format!(
+                    "Relacja {} -> {} wskazuje na nieistniejący obiekt",
+                    relation.subject_id, relation.object_id
+                ),
+            ));
+        }
+    }
+
+    // Sprawdź czy liczba obiektów/relacji zgadza się z metadanymi
+    let metadata = &input.metadata;
+    if metadata.object_count != input.objects.len() as f64 {
+        issues.push(issue(
+            IssueKind::CountMismatch,
+            "metadata.object_count".to_string(),
+            format!(
+                "Niezgodność: metadata.object_count ({}) != objects.length ({})",
+                metadata.object_count,
+                input.objects.len()
+            ),
+        ));
+    }
+
+    if metadata.relation_count != input.relations.len() as f64 {
+        issues.push(issue(
+            IssueKind::CountMismatch,
+            "metadata.relation_count".to_string(),
+            format!(
+                "Niezgodność: metadata.relation_count ({}) != relations.length ({})",
+                metadata.relation_count,
+                input.relations.len()
+            ),
+        ));
+    }
+
+    // Sprawdź poziom szumu semantycznego i status sygnału
+    let noise_level = metadata.semantic_noise_level;
+    let expected_status = calculate_signal_status(noise_level);
+
+    if metadata.signal_status != expected_status.as_str() {
+        issues.push(issue(
+            IssueKind::StatusMismatch,
+            "metadata.signal_status".to_string(),
+            format!(
+                "Niezgodność statusu sygnału: oczekiwano {}, otrzymano {}",
+                expected_status.as_str(),
+                metadata.signal_status
+            ),
+        ));
+    }
+
+    // REJECT: Odrzuć sygnał jeśli noise_level > 0.7
+    if noise_level > SIGNAL_WARNING_MAX_NOISE || metadata.signal_status == "REJECT" {
+        issues.push(issue(
+            IssueKind::NoiseReject,
+            "metadata.semantic_noise_level".to_string(),
+            format!(
+                "STATUS: REJECT - Sygnał zbyt zniekształcony ideologicznie lub merytorycznie pusty. Noise level: {:.2}",
+                noise_level
+            ),
+        ));
+    }
+
+    // WARNING: Sygnał niejednoznaczny wymaga is_ambiguous = true
+    if noise_level > SIGNAL_CLEAR_MAX_NOISE
+        && noise_level <= SIGNAL_WARNING_MAX_NOISE
+        && !metadata.is_ambiguous
+    {
+        issues.push(issue(
+            IssueKind::NoiseWarning,
+            "metadata.is_ambiguous".to_string(),
+            format!(
+                "STATUS: WARNING - Sygnał wymaga flagi is_ambiguous=true. Noise level: {:.2}",
+                noise_level
+            ),
+        ));
+    }
+
+    SignalValidation {
+        valid: issues.is_empty(),
+        status: expected_status,
+        issues,
+    }
+}
+
+fn issue(kind: IssueKind, path: String, message: String) -> ValidationIssue {
+    ValidationIssue { kind, path, message }
+}
+
+/// Ograniczenia z ExtractedObjectSchema / ExtractedRelationSchema / ExtractionMetadataSchema
+fn check_schema(input: &CyberneticInput, issues: &mut Vec<ValidationIssue>) {
+    if input.objects.is_empty() {
+        issues.push(issue(
+            IssueKind::Schema,
+            "objects".to_string(),
+            "Musi być co najmniej 1 obiekt".to_string(),
+        ));
+    }
+
+    for (idx, obj) in input.objects.iter().enumerate() {
+        let path = format!("objects[{}]", idx);
+        check_non_empty(issues, &path, "id", &obj.id);
+        check_non_empty(issues, &path, "label", &obj.label);
+        check_enum(issues, &path, "type", &obj.object_type, SYSTEM_TYPES);
+        if let Some(energy) = obj.estimated_energy {
+            check_range(issues, &path, "estimated_energy", energy, 0.0, Some(1.0));
+        }
+        check_range(issues, &path, "power_v", obj.power_v, 0.0, None);
+        check_range(issues, &path, "quality_a", obj.quality_a, 0.0, Some(1.0));
+        check_range(issues, &path, "mass_c", obj.mass_c, 0.0, None);
+        check_enum(issues, &path, "civilization_code", &obj.civilization_code, CIVILIZATION_CODES);
+        check_enum(issues, &path, "motivation_type", &obj.motivation_type, MOTIVATION_TYPES);
+    }
+
+    for (idx, rel) in input.relations.iter().enumerate() {
+        let path = format!("relations[{}]", idx);
+        check_non_empty(issues, &path, "subject_id", &rel.subject_id);
+        check_non_empty(issues, &path, "object_id", &rel.object_id);
+        check_enum(issues, &path, "process_type", &rel.process_type, PROCESS_TYPES);
+        check_enum(issues, &path, "feedback_type", &rel.feedback_type, FEEDBACK_TYPES);
+        check_enum(issues, &path, "system_class", &rel.system_class, CONTROL_SYSTEM_TYPES);
+        check_range(issues, &path, "influence_strength", rel.influence_strength, 0.0, Some(1.0));
+        check_enum(issues, &path, "norm_category", &rel.norm_category, NORM_CATEGORIES);
+    }
+
+    let meta = &input.metadata;
+    let path = "metadata";
+    check_range(issues, path, "semantic_noise_level", meta.semantic_noise_level, 0.0, Some(1.0));
+    check_enum(issues, path, "signal_status", &meta.signal_status, SIGNAL_STATUSES);
+    check_enum(issues, path, "dominant_system_type", &meta.dominant_system_type, CONTROL_SYSTEM_TYPES);
+    check_count(issues, path, "object_count", meta.object_count);
+    check_count(issues, path, "relation_count", meta.relation_count);
+}
+
+fn check_non_empty(issues: &mut Vec<ValidationIssue>, path: &str, field: &str, value: &str) {
+    if value.is_empty() {
+        issues.push(issue(
+            IssueKind::Schema,
+            format!("{}.{}", path, field),
+            format!("{}.{}: pole nie może być puste", path, field),
+        ));
+    }
+}
+
+fn check_enum(issues: &mut Vec<ValidationIssue>, path: &str, field: &str, value: &str, allowed: &[&str]) {
+    if !allowed.contains(&value) {
+        issues.push(issue(
+            IssueKind::Schema,
+            format!("{}.{}", path, field),
+            format!(
+                "{}.{}: niedozwolona wartość '{}' (dozwolone: {})",
+                path,
+                field,
+                value,
+                allowed.join(", ")
+            ),
+        ));
+    }
+}
+
+fn check_range(
+    issues: &mut Vec<ValidationIssue>,
+    path: &str,
+    field: &str,
+    value: f64,
+    min: f64,
+    max: Option<f64>,
+) {
+    let in_range = value >= min && max.is_none_or(|max| value <= max);
+    if !in_range {
+        let range = match max {
+            Some(max) => format!("[{}, {}]", min, max),
+            None => format!(">= {}", min),
+        };
+        issues.push(issue(
+            IssueKind::Schema,
+            format!("{}.{}", path, field),
+            format!("{}.{}: wartość {} poza zakresem {}", path, field, value, range),
+        ));
+    }
+}
+
+fn check_count(issues: &mut Vec<ValidationIssue>, path: &str, field: &str, value: f64) {
+    if value < 0.0 || value.fract() != 0.0 {
+        issues.push(issue(
+            IssueKind::Schema,
+            format!("{}.{}", path, field),
+            format!("{}.{}: oczekiwano nieujemnej liczby całkowitej, otrzymano {}", path, field, value),
+        ));
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Status sygnału ("CLEAR" | "WARNING" | "REJECT")
+#[wasm_bindgen]
+pub fn wasm_calculate_signal_status(noise_level: f64) -> String {
+    calculate_signal_status(noise_level).as_str().to_string()
+}
+
+/// @cybernetic WASM Entry Point - Bramka walidacji sygnału
+///
+/// Przyjmuje JSON CyberneticInput, zwraca JSON SignalValidation.
+#[wasm_bindgen]
+pub fn wasm_validate_cybernetic_input(input_json: &str) -> Result<String, JsValue> {
+    let input: CyberneticInput = serde_json::from_str(input_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse input: {}", e)))?;
+
+    let validation = validate_cybernetic_input(&input);
+
+    serde_json::to_string(&validation)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_json(noise: f64, status: &str, ambiguous: bool, relation_target: &str) -> String {
+        format!(
+            r#"{{
+                "objects": [
+                    {{ "id": "a", "label": "Państwo X", "type": "autonomous_system" }},
+                    {{ "id": "b", "label": "Media Y", "type": "heteronomous_system" }}
+                ],
+                "relations": [{{
+                    "subject_id": "a", "object_id": "{}",
+                    "process_type": "informational", "feedback_type": "positive",
+                    "system_class": "ideological", "influence_strength": 0.6,
+                    "description": "Finansowanie przekazu"
+                }}],
+                "metadata": {{
+                    "semantic_noise_level": {}, "signal_status": "{}", "is_ambiguous": {},
+                    "dominant_system_type": "ideological", "raw_context": "...",
+                    "object_count": 2, "relation_count": 1
+                }}
+            }}"#,
+            relation_target, noise, status, ambiguous
+        )
+    }
+
+    fn validate(json: &str) -> SignalValidation {
+        validate_cybernetic_input(&serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn test_signal_status_thresholds() {
+        assert_eq!(calculate_signal_status(0.0), SignalStatus::Clear);
+        assert_eq!(calculate_signal_status(0.4), SignalStatus::Clear);
+        assert_eq!(calculate_signal_status(0.41), SignalStatus::Warning);
+        assert_eq!(calculate_signal_status(0.7), SignalStatus::Warning);
+        assert_eq!(calculate_signal_status(0.71), SignalStatus::Reject);
+    }
+
+    #[test]
+    fn test_clear_signal_is_valid() {
+        let result = validate(&input_json(0.2, "CLEAR", false, "b"));
+        assert!(result.valid, "{:?}", result.errors());
+        assert_eq!(result.status, SignalStatus::Clear);
+    }
+
+    #[test]
+    fn test_warning_requires_ambiguous_flag() {
+        let result = validate(&input_json(0.5, "WARNING", false, "b"));
+        assert!(!result.valid);
+        assert_eq!(result.status, SignalStatus::Warning);
+        assert_eq!(
+            result.errors(),
+            vec!["STATUS: WARNING - Sygnał wymaga flagi is_ambiguous=true. Noise level: 0.50"]
+        );
+
+        assert!(validate(&input_json(0.5, "WARNING", true, "b")).valid);
+    }
+
+    #[test]
+    fn test_reject_and_referential_integrity() {
+        let result = validate(&input_json(0.9, "CLEAR", false, "ghost"));
+        assert_eq!(result.status, SignalStatus::Reject);
+
+        let kinds: Vec<IssueKind> = result.issues.iter().map(|i| i.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                IssueKind::ReferentialIntegrity,
+                IssueKind::StatusMismatch,
+                IssueKind::NoiseReject
+            ]
+        );
+        assert_eq!(result.issues[0].message, "Relacja a -> ghost wskazuje na nieistniejący obiekt");
+    }
+
+    #[test]
+    fn test_schema_enums_and_ranges() {
+        let json = input_json(0.2, "CLEAR", false, "b")
+            .replace("\"heteronomous_system\"", "\"alien\"")
+            .replace("0.6", "1.6");
+        let result = validate(&json);
+
+        let paths: Vec<&str> = result.issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, vec!["objects[1].type", "relations[0].influence_strength"]);
+        assert!(result.issues.iter().all(|i| i.kind == IssueKind::Schema));
+    }
+
+    #[test]
+    fn test_motivation_types_match_enum() {
+        for name in MOTIVATION_TYPES {
+            let parsed: MotivationType = serde_json::from_value(serde_json::json!(name)).unwrap();
+            assert_eq!(parsed.as_str(), *name);
+        }
+        assert_eq!(MOTIVATION_TYPES.len(), MotivationType::ALL.len());
+
+        // "economic" nie jest wariantem MotivationType - bramka go odrzuca
+        let json = input_json(0.2, "CLEAR", false, "b").replace(
+            "\"heteronomous_system\" }",
+            "\"heteronomous_system\", \"motivation_type\": \"economic\" }",
+        );
+        let result = validate(&json);
+        assert!(!result.valid);
+        assert_eq!(result.issues[0].path, "objects[1].motivation_type");
+    }
+}
@@ -0,0 +1,526 @@
+//! @fileoverview Silnie spójne składowe i graf kondensacji
+//! @cybernetic Klastry wzajemnego sterowania (pętle) jako super-węzły
+//!
+//! Algorytm Tarjana (iteracyjny - bez rekurencji, bezpieczny w Wasm) dzieli
+//! graf na silnie spójne składowe (SCC). Graf kondensacji, w którym każda
+//! składowa jest jednym węzłem, jest acykliczny (DAG), więc przeszukiwanie
+//! ścieżek na nim nie eksploduje wykładniczo wewnątrz gęstych pętli.
+//!
+//! Składowe numerujemy w porządku topologicznym kondensacji (źródła najpierw).
+
+use crate::budget::{budget_from_js, Budgeted, CancellationToken, WorkBudget};
+use crate::indexed::IndexedGraph;
+use crate::{
+    build_graph_from_json, parse_steering_goal, CyberneticObject, EnergyParams, Graph,
+    InfluentialNode, RelationType, SteeringGoal,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+/// Prefiks ID super-węzła składowej w grafie kondensacji
+pub const COMPONENT_ID_PREFIX: &str = "scc:";
+
+// ============================================================================
+// WYNIK
+// ============================================================================
+
+/// @cybernetic Statystyki pętli wewnątrz składowej
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoopStatistics {
+    pub internal_edges: usize,
+    pub self_loops: usize,
+    pub cyclomatic_number: usize, // E - V + 1: liczba niezależnych cykli
+    pub density: f64,             // E / (V(V-1)); 0 dla pojedynczego węzła
+    pub mean_weight: f64,         // Średnie impact_factor × certainty_score
+    pub positive_feedback_edges: usize,
+    pub negative_feedback_edges: usize,
+}
+
+/// @cybernetic Silnie spójna składowa
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StronglyConnectedComponent {
+    pub index: usize,
+    pub object_ids: Vec<String>, // Posortowane
+    pub object_names: Vec<String>,
+    pub is_cyclic: bool, // Więcej niż jeden węzeł lub pętla własna
+    pub loop_stats: LoopStatistics,
+}
+
+/// @cybernetic Krawędź grafu kondensacji (zbiorcza)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CondensationEdge {
+    pub source: usize,
+    pub target: usize,
+    pub correlation_ids: Vec<String>,
+    pub total_weight: f64,
+    pub max_weight: f64,
+}
+
+/// @cybernetic Składowe + DAG kondensacji
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CondensationReport {
+    pub components: Vec<StronglyConnectedComponent>,
+    pub edges: Vec<CondensationEdge>,
+    pub component_of: BTreeMap<String, usize>, // ID obiektu → indeks składowej
+    pub cyclic_component_count: usize,
+    pub largest_component_size: usize,
+}
+
+// ============================================================================
+// ALGORYTM TARJANA
+// ============================================================================
+
+/// @cybernetic Silnie spójne składowe (indeksy węzłów) w porządku topologicznym
+///
+/// Uwzględnia krawędzie o dodatniej wadze.
+pub fn tarjan_scc(graph: &IndexedGraph) -> Vec<Vec<usize>> {
+    tarjan_scc_within(graph, &mut WorkBudget::unlimited())
+}
+
+/// @cybernetic Tarjan w ramach budżetu (jeden tick na odwiedzony węzeł)
+///
+/// Po wyczerpaniu budżetu zwraca tylko składowe domknięte do tej pory -
+/// każda z nich jest pełną SCC, ale część węzłów nie należy do żadnej.
+pub fn tarjan_scc_within(graph: &IndexedGraph, budget: &mut WorkBudget) -> Vec<Vec<usize>> {
+    let n = graph.node_count();
+    let successors: Vec<Vec<usize>> = (0..n)
+        .map(|v| {
+            graph.out_edges[v]
+                .iter()
+                .map(|&e| &graph.edges[e])
+                .filter(|e| e.weight > 0.0)
+                .map(|e| e.target)
+                .collect()
+        })
+        .collect();
+
+    let mut index = vec![usize::MAX; n];
+    let mut lowlink = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut counter = 0;
+
+    for root in 0..n {
+        if index[root] != usize::MAX {
+            continue;
+        }
+        if !budget.tick() {
+            break;
+        }
+
+        // Stos wywołań: (węzeł, pozycja w liście następników)
+        let mut call_stack = vec![(root, 0usize)];
+        index[root] = counter;
+        lowlink[root] = counter;
+        counter += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&mut (v, ref mut next)) = call_stack.last_mut() {
+            if let Some(&w) = successors[v].get(*next) {
+                *next += 1;
+                if index[w] == usize::MAX {
+                    if !budget.tick() {
+                        break;
+                    }
+                    index[w] = counter;
+                    lowlink[w] = counter;
+                    counter += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    call_stack.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(index[w]);
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[v]);
+            }
+
+            if lowlink[v] == index[v] {
+                let mut component = Vec::new();
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    // Tarjan zwraca składowe w odwrotnym porządku topologicznym
+    components.reverse();
+    components
+}
+
+// ============================================================================
+// API GRAFU
+// ============================================================================
+
+impl Graph {
+    /// @cybernetic Składowe silnie spójne, statystyki pętli i DAG kondensacji
+    pub fn condensation(&self) -> CondensationReport {
+        self.condensation_budgeted(&mut WorkBudget::unlimited()).result
+    }
+
+    /// @cybernetic Kondensacja w ramach budżetu
+    ///
+    /// Przy `completed: false` raport obejmuje tylko domknięte składowe;
+    /// pozostałe węzły nie występują w `component_of`.
+    pub fn condensation_budgeted(&self, budget: &mut WorkBudget) -> Budgeted<CondensationReport> {
+        let indexed = self.indexed();
+        let groups = tarjan_scc_within(&indexed, budget);
+
+        let mut component_idx = vec![None; indexed.node_count()];
+        for (c, members) in groups.iter().enumerate() {
+            for &v in members {
+                component_idx[v] = Some(c);
+            }
+        }
+
+        let mut stats: Vec<LoopStatistics> = groups
+            .iter()
+            .map(|_| LoopStatistics {
+                internal_edges: 0,
+                self_loops: 0,
+                cyclomatic_number: 0,
+                density: 0.0,
+                mean_weight: 0.0,
+                positive_feedback_edges: 0,
+                negative_feedback_edges: 0,
+            })
+            .collect();
+        let mut dag: BTreeMap<(usize, usize), CondensationEdge> = BTreeMap::new();
+
+        for edge in indexed.edges.iter().filter(|e| e.weight > 0.0) {
+            let corr = &self.correlations[edge.correlation];
+            let (Some(from), Some(to)) = (component_idx[edge.source], component_idx[edge.target]) else {
+                continue;
+            };
+
+            if from == to {
+                let s = &mut stats[from];
+                s.internal_edges += 1;
+                s.mean_weight += edge.weight;
+                if edge.source == edge.target {
+                    s.self_loops += 1;
+                }
+                match corr.relation_type {
+                    RelationType::PositiveFeedback => s.positive_feedback_edges += 1,
+                    RelationType::NegativeFeedback => s.negative_feedback_edges += 1,
+                    _ => {}
+                }
+            } else {
+                let entry = dag.entry((from, to)).or_insert_with(|| CondensationEdge {
+                    source: from,
+                    target: to,
+                    correlation_ids: Vec::new(),
+                    total_weight: 0.0,
+                    max_weight: 0.0,
+                });
+                entry.correlation_ids.push(corr.id.clone());
+                entry.total_weight += edge.weight;
+                entry.max_weight = entry.max_weight.max(edge.weight);
+            }
+        }
+
+        let components: Vec<StronglyConnectedComponent> = groups
+            .iter()
+            .zip(stats)
+            .enumerate()
+            .map(|(c, (members, mut s))| {
+                let size = members.len();
+                if s.internal_edges > 0 {
+                    s.mean_weight /= s.internal_edges as f64;
+                }
+                s.cyclomatic_number = (s.internal_edges + 1).saturating_sub(size);
+                if size > 1 {
+                    s.density = s.internal_edges as f64 / (size * (size - 1)) as f64;
+                }
+
+                let object_ids: Vec<String> =
+                    members.iter().map(|&v| indexed.ids[v].clone()).collect();
+                StronglyConnectedComponent {
+                    index: c,
+                    object_names: object_ids
+                        .iter()
+                        .map(|id| self.object(id).map(|o| o.name.clone()).unwrap_or_else(|| id.clone()))
+                        .collect(),
+                    object_ids,
+                    is_cyclic: size > 1 || s.self_loops > 0,
+                    loop_stats: s,
+                }
+            })
+            .collect();
+
+        budget.wrap(CondensationReport {
+            cyclic_component_count: components.iter().filter(|c| c.is_cyclic).count(),
+            largest_component_size: groups.iter().map(|g| g.len()).max().unwrap_or(0),
+            component_of: indexed
+                .ids
+                .iter()
+                .enumerate()
+                .filter_map(|(v, id)| component_idx[v].map(|c| (id.clone(), c)))
+                .collect(),
+            components,
+            edges: dag.into_values().collect(),
+        })
+    }
+
+    /// @cybernetic Graf z wieloelementowymi składowymi zwiniętymi w super-węzły
+    ///
+    /// Super-węzeł ma ID `scc:<indeks>`, nazwę z nazw członków, sumę mocy
+    /// (energy_params, mass_c) i średnią power_v / quality_a. Relacje
+    /// wewnątrz składowej znikają, pozostałe zachowują ID i typ.
+    pub fn condensed(&self, report: &CondensationReport) -> Graph {
+        let node_id = |object_id: &str| -> String {
+            match report.component_of.get(object_id) {
+                Some(&c) if report.components[c].object_ids.len() > 1 => {
+                    format!("{}{}", COMPONENT_ID_PREFIX, c)
+                }
+                _ => object_id.to_string(),
+            }
+        };
+
+        let mut objects = Vec::new();
+        for component in &report.components {
+            let members: Vec<&CyberneticObject> =
+                component.object_ids.iter().filter_map(|id| self.object(id)).collect();
+            if members.len() == 1 {
+                objects.push(members[0].clone());
+                continue;
+            }
+
+            let count = members.len() as f64;
+            let mut super_node = members[0].clone();
+            super_node.id = format!("{}{}", COMPONENT_ID_PREFIX, component.index);
+            super_node.name = component.object_names.join(" + ");
+            super_node.description = None;
+            super_node.energy_params = EnergyParams {
+                working_power: members.iter().map(|o| o.energy_params.working_power).sum(),
+                idle_power: members.iter().map(|o| o.energy_params.idle_power).sum(),
+                available_power: members.iter().map(|o| o.energy_params.available_power).sum(),
+            };
+            super_node.power_v = members.iter().map(|o| o.power_v).sum::<f64>() / count;
+            super_node.quality_a = members.iter().map(|o| o.quality_a).sum::<f64>() / count;
+            super_node.mass_c = members.iter().map(|o| o.mass_c).sum();
+            super_node.created_at = members
+                .iter()
+                .map(|o| o.created_at.clone())
+                .min()
+                .unwrap_or_default();
+            objects.push(super_node);
+        }
+
+        let correlations = self
+            .correlations
+            .iter()
+            .filter_map(|corr| {
+                let (source, target) = (node_id(&corr.source_id), node_id(&corr.target_id));
+                let collapsed = source == target && source.starts_with(COMPONENT_ID_PREFIX);
+                if collapsed {
+                    return None;
+                }
+                let mut remapped = corr.clone();
+                remapped.source_id = source;
+                remapped.target_id = target;
+                Some(remapped)
+            })
+            .collect();
+
+        Graph::new(objects, correlations)
+    }
+
+    /// @cybernetic Analiza wpływu na grafie kondensacji (bez eksplozji w pętlach)
+    ///
+    /// Cel należący do wieloelementowej składowej zastępuje jej super-węzeł.
+    pub fn rank_condensed_influential_nodes(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+    ) -> Vec<InfluentialNode> {
+        let report = self.condensation();
+        let condensed = self.condensed(&report);
+
+        let target = match report.component_of.get(target_id) {
+            Some(&c) if report.components[c].object_ids.len() > 1 => {
+                format!("{}{}", COMPONENT_ID_PREFIX, c)
+            }
+            _ => target_id.to_string(),
+        };
+
+        condensed.rank_influential_nodes(&target, goal)
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Składowe silnie spójne i DAG kondensacji
+#[wasm_bindgen]
+pub fn wasm_strongly_connected_components(
+    objects_json: &str,
+    correlations_json: &str,
+) -> Result<String, JsValue> {
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.condensation();
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Składowe silnie spójne z budżetem pracy
+///
+/// `budget_json` pusty = bez limitów. Wynik: `{ result: CondensationReport, completed, ... }`.
+#[wasm_bindgen]
+pub fn wasm_strongly_connected_components_budgeted(
+    objects_json: &str,
+    correlations_json: &str,
+    budget_json: &str,
+    clock: Option<js_sys::Function>,
+    token: &CancellationToken,
+) -> Result<String, JsValue> {
+    let mut budget = budget_from_js(budget_json, clock, Some(token))?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let result = graph.condensation_budgeted(&mut budget);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Graf zwinięty do super-węzłów (dla efektora)
+///
+/// Zwraca JSON `{ objects, correlations }` w formacie wejściowym.
+#[wasm_bindgen]
+pub fn wasm_condense_graph(objects_json: &str, correlations_json: &str) -> Result<String, JsValue> {
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let condensed = graph.condensed(&graph.condensation());
+
+    let mut objects: Vec<&CyberneticObject> = condensed.objects().collect();
+    objects.sort_by(|a, b| a.id.cmp(&b.id));
+
+    serde_json::to_string(&serde_json::json!({
+        "objects": objects,
+        "correlations": condensed.correlations(),
+    }))
+    .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - BFS wpływu na grafie kondensacji
+#[wasm_bindgen]
+pub fn wasm_find_condensed_influence_paths(
+    objects_json: &str,
+    correlations_json: &str,
+    target_id: &str,
+    goal: &str,
+) -> Result<String, JsValue> {
+    let steering_goal = parse_steering_goal(goal)?;
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let influential_nodes = graph.rank_condensed_influential_nodes(target_id, &steering_goal);
+
+    serde_json::to_string(&influential_nodes)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::graph;
+
+    #[test]
+    fn test_components_in_topological_order() {
+        // a → (b ⇄ c) → d
+        let g = graph(
+            &["a", "b", "c", "d"],
+            &[("a", "b", 1.0), ("b", "c", 1.0), ("c", "b", 1.0), ("c", "d", 1.0)],
+        );
+        let report = g.condensation();
+
+        let ids: Vec<Vec<String>> = report.components.iter().map(|c| c.object_ids.clone()).collect();
+        assert_eq!(ids, vec![vec!["a"], vec!["b", "c"], vec!["d"]]);
+        assert_eq!(report.cyclic_component_count, 1);
+        assert_eq!(report.largest_component_size, 2);
+        assert_eq!(report.edges.len(), 2);
+        assert_eq!(report.edges[0].correlation_ids, vec!["a-b".to_string()]);
+        assert_eq!(report.component_of["c"], 1);
+    }
+
+    #[test]
+    fn test_loop_statistics() {
+        // Pełny graf 3 węzłów (6 krawędzi) + pętla własna
+        let mut edges = Vec::new();
+        for s in ["x", "y", "z"] {
+            for t in ["x", "y", "z"] {
+                if s != t {
+                    edges.push((s, t, 0.5));
+                }
+            }
+        }
+        edges.push(("x", "x", 1.0));
+        let report = graph(&["x", "y", "z"], &edges).condensation();
+
+        let stats = &report.components[0].loop_stats;
+        assert_eq!(stats.internal_edges, 7);
+        assert_eq!(stats.self_loops, 1);
+        assert_eq!(stats.cyclomatic_number, 5);
+        assert!((stats.density - 7.0 / 6.0).abs() < 1e-12);
+        assert!((stats.mean_weight - 4.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_budget_returns_closed_components_only() {
+        // {a} domknięta od razu; b → c przerwane przed odwiedzeniem c
+        let g = graph(&["a", "b", "c"], &[("b", "c", 1.0)]);
+        let mut budget = WorkBudget::new(crate::budget::BudgetConfig {
+            max_expanded_nodes: Some(2),
+            ..Default::default()
+        });
+        let partial = g.condensation_budgeted(&mut budget);
+
+        assert!(!partial.outcome.completed);
+        let ids: Vec<Vec<String>> = partial.result.components.iter().map(|c| c.object_ids.clone()).collect();
+        assert_eq!(ids, vec![vec!["a"]]);
+        assert_eq!(partial.result.component_of.len(), 1);
+        assert!(g.condensation_budgeted(&mut WorkBudget::unlimited()).outcome.completed);
+    }
+
+    #[test]
+    fn test_condensed_search_avoids_cycle_blowup() {
+        // Gęsta pętla 5 węzłów zasilana przez "src", cel "t" poza pętlą
+        let ring = ["r1", "r2", "r3", "r4", "r5"];
+        let mut edges = vec![("src", "r1", 1.0), ("r5", "t", 1.0)];
+        for s in ring {
+            for t in ring {
+                if s != t {
+                    edges.push((s, t, 1.0));
+                }
+            }
+        }
+        let mut ids = vec!["src", "t"];
+        ids.extend_from_slice(&ring);
+        let g = graph(&ids, &edges);
+
+        let condensed = g.condensed(&g.condensation());
+        assert_eq!(condensed.objects().count(), 3);
+        assert_eq!(condensed.correlations().len(), 2);
+
+        let nodes = g.rank_condensed_influential_nodes("t", &SteeringGoal::Strengthen);
+        let ids: Vec<&str> = nodes.iter().map(|n| n.object_id.as_str()).collect();
+        assert_eq!(nodes.len(), 2);
+        assert!(ids.contains(&"src") && ids.contains(&"scc:1"));
+    }
+}
@@ -0,0 +1,627 @@
+//! @fileoverview Strumieniowe przeszukiwanie ścieżek wpływu
+//! @cybernetic BFS wstecz od celu jako iterator - ścieżka po ścieżce
+//!
+//! `find_influence_paths` zbierało wszystko do `Vec` i po cichu kończyło na
+//! MAX_PATHS. Tu stan BFS (`PathSearchState`) jest wznawialny: iterator
+//! `InfluencePathSearch` oddaje ścieżki w tej samej kolejności co dawniej,
+//! a kursor Wasm (`InfluencePathCursor`) zwraca kolejne strony po N ścieżek
+//! razem z licznikami rozwiniętych i odciętych węzłów oraz flagą `truncated`.
+//!
+//! Pętla BFS nie klonuje `String`: węzły to indeksy u32 (`PathIndex`), a
+//! częściowe ścieżki to wpisy areny ze wskaźnikiem na rodzica. ID, nazwy,
+//! typy i pewności odtwarzamy w `materialize`, raz na oddaną ścieżkę (nie przy
+//! każdym rozwinięciu) - `InfluencePath` niesie gotowe `path_names`, więc
+//! serializacja nie potrzebuje już grafu.
+
+use crate::budget::{budget_from_js, Budgeted, CancellationToken, WorkBudget};
+use crate::{
+    build_graph_from_json, parse_steering_goal, Correlation, Graph, InfluencePath, Polarity,
+    SteeringGoal, MAX_DEPTH, MAX_PATHS, MIN_INFLUENCE_THRESHOLD,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// INDEKS WĘZŁÓW
+// ============================================================================
+
+/// Brak węzła / rodzica (cel spoza grafu, korzeń areny)
+const NONE: u32 = u32::MAX;
+
+/// @cybernetic Węzły jako u32 i relacje wchodzące jako indeksy w `Graph::correlations()`
+///
+/// Budowany raz w `Graph::new`. Węzłami są obiekty oraz źródła relacji spoza
+/// grafu (trafiają do ścieżek z ID zamiast nazwy). Relacje wchodzące mają
+/// tylko obiekty - jak dawna `reverse_adjacency_list` - w kolejności wejściowej.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PathIndex {
+    nodes: HashMap<String, u32>,
+    incoming: Vec<Vec<u32>>, // Węzeł → relacje, których jest celem
+    sources: Vec<u32>,       // Relacja → węzeł źródłowy
+}
+
+impl PathIndex {
+    pub(crate) fn new<'a>(object_ids: impl Iterator<Item = &'a String>, correlations: &[Correlation]) -> Self {
+        let mut index = PathIndex::default();
+        for id in object_ids {
+            index.intern(id);
+        }
+        let object_count = index.incoming.len();
+
+        for (idx, corr) in correlations.iter().enumerate() {
+            let source = index.intern(&corr.source_id);
+            index.sources.push(source);
+            if let Some(&target) = index.nodes.get(&corr.target_id).filter(|&&t| (t as usize) < object_count) {
+                index.incoming[target as usize].push(idx as u32);
+            }
+        }
+        index
+    }
+
+    fn intern(&mut self, id: &str) -> u32 {
+        if let Some(&node) = self.nodes.get(id) {
+            return node;
+        }
+        let node = self.incoming.len() as u32;
+        self.nodes.insert(id.to_string(), node);
+        self.incoming.push(Vec::new());
+        node
+    }
+
+    fn node(&self, id: &str) -> u32 {
+        self.nodes.get(id).copied().unwrap_or(NONE)
+    }
+
+    fn incoming(&self, node: u32) -> &[u32] {
+        self.incoming.get(node as usize).map_or(&[], Vec::as_slice)
+    }
+}
+
+// ============================================================================
+// STAN PRZESZUKIWANIA
+// ============================================================================
+
+/// Węzeł areny: częściowa ścieżka = relacja `correlation` dopięta przed ścieżkę rodzica
+///
+/// Ścieżki współdzielą prefiksy (od strony celu), więc rozwinięcie kosztuje
+/// jeden wpis zamiast kopii wektorów ID, typów, pewności i relacji.
+#[derive(Debug, Clone, Copy)]
+struct PathNode {
+    parent: u32,      // NONE = cel (korzeń)
+    correlation: u32, // Relacja source → węzeł rodzica (nieużywana w korzeniu)
+    node: u32,        // Pierwszy węzeł ścieżki
+    depth: u32,
+    total_strength: f64,
+}
+
+/// @cybernetic Liczniki postępu przeszukiwania
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PathSearchStats {
+    pub explored: usize,  // Rozwinięte węzły (pozycje kolejki)
+    pub pruned: usize,    // Odcięte: relacje poniżej progu + węzły na limicie głębokości
+    pub emitted: usize,   // Oddane ścieżki
+    pub truncated: bool,  // Przerwano na limicie, choć zostały nieodwiedzone ścieżki
+}
+
+/// @cybernetic Wznawialny stan BFS
+///
+/// Przechowuje indeksy węzłów i relacji grafu, więc musi być używany z tym
+/// samym grafem przez cały czas życia. Nazwy i ID rozwiązywane są w
+/// `materialize`, gdy ścieżka jest oddawana.
+pub struct PathSearchState {
+    target_id: String,
+    target: u32, // Rozwiązywany przy rozwinięciu korzenia
+    arena: Vec<PathNode>,
+    queue: VecDeque<u32>,   // Wpisy areny do rozwinięcia
+    pending: VecDeque<u32>, // Ścieżki z ostatnio rozwiniętego węzła
+    stats: PathSearchStats,
+}
+
+impl PathSearchState {
+    pub fn new(target_id: &str) -> Self {
+        PathSearchState {
+            target_id: target_id.to_string(),
+            target: NONE,
+            arena: vec![PathNode {
+                parent: NONE,
+                correlation: NONE,
+                node: NONE,
+                depth: 0,
+                total_strength: 1.0,
+            }],
+            queue: VecDeque::from([0]),
+            pending: VecDeque::new(),
+            stats: PathSearchStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> &PathSearchStats {
+        &self.stats
+    }
+
+    /// Wszystkie ścieżki ostatnio rozwiniętego węzła zostały oddane
+    pub fn at_expansion_boundary(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Brak dalszych ścieżek
+    pub fn is_exhausted(&self) -> bool {
+        self.pending.is_empty() && self.queue.is_empty()
+    }
+
+    /// Oznacza przerwanie na limicie (jeśli coś jeszcze zostało)
+    pub fn stop(&mut self) {
+        self.stats.truncated = !self.is_exhausted();
+    }
+
+    /// @cybernetic Następna ścieżka wpływu albo `None`, gdy BFS się wyczerpał
+    pub fn next_path(&mut self, graph: &Graph) -> Option<InfluencePath> {
+        self.next_path_within(graph, &mut WorkBudget::unlimited())
+    }
+
+    /// @cybernetic Następna ścieżka w ramach budżetu
+    ///
+    /// `None` także po wyczerpaniu budżetu - stan pozostaje wznawialny.
+    pub fn next_path_within(&mut self, graph: &Graph, budget: &mut WorkBudget) -> Option<InfluencePath> {
+        loop {
+            if let Some(entry) = self.pending.pop_front() {
+                self.stats.emitted += 1;
+                return Some(self.materialize(graph, entry));
+            }
+            if self.queue.is_empty() || !budget.tick() {
+                return None;
+            }
+            let current = self.queue.pop_front()?;
+            self.expand(graph, current);
+        }
+    }
+
+    /// @cybernetic Dokłada ścieżki do `paths` (limit MAX_PATHS jak w `find_influence_paths`)
+    ///
+    /// Zwraca `true`, gdy przeszukiwanie się zakończyło: BFS wyczerpany, limit
+    /// MAX_PATHS albo wyczerpany budżet. `false` = budżet na pauzie - kolejne
+    /// wywołanie z tymi samymi `paths` wznawia.
+    pub fn collect_capped_within(&mut self, graph: &Graph, paths: &mut Vec<InfluencePath>, budget: &mut WorkBudget) -> bool {
+        while let Some(path) = self.next_path_within(graph, budget) {
+            paths.push(path);
+            if paths.len() >= MAX_PATHS && self.at_expansion_boundary() {
+                break;
+            }
+        }
+        if budget.is_paused() && !self.is_exhausted() && paths.len() < MAX_PATHS {
+            return false;
+        }
+        self.stop();
+        true
+    }
+
+    /// Czy węzeł leży już na ścieżce wpisu `entry` (łącznie z celem)
+    fn on_path(&self, mut entry: u32, node: u32) -> bool {
+        while entry != NONE {
+            let item = &self.arena[entry as usize];
+            if item.node == node {
+                return true;
+            }
+            entry = item.parent;
+        }
+        false
+    }
+
+    /// Rozwija jeden wpis kolejki - algorytm z findInfluencePaths (pathfinder.ts)
+    fn expand(&mut self, graph: &Graph, entry: u32) {
+        let current = self.arena[entry as usize];
+        if current.depth as usize >= MAX_DEPTH {
+            self.stats.pruned += 1;
+            return;
+        }
+        self.stats.explored += 1;
+
+        let index = &graph.path_index;
+        let node = if current.parent == NONE {
+            self.target = index.node(&self.target_id);
+            self.arena[entry as usize].node = self.target;
+            self.target
+        } else {
+            current.node
+        };
+
+        // Relacje wpływające na current (reverse)
+        for &correlation in index.incoming(node) {
+            let relation = &graph.correlations[correlation as usize];
+            let source = index.sources[correlation as usize];
+
+            // Unikaj cykli (chyba że to pętla sprzężenia zwrotnego)
+            if source != self.target && self.on_path(entry, source) {
+                continue;
+            }
+
+            // Oblicz siłę wpływu i filtruj słabe wpływy
+            let total_strength = current.total_strength * relation.impact_factor;
+            if total_strength < MIN_INFLUENCE_THRESHOLD {
+                self.stats.pruned += 1;
+                continue;
+            }
+
+            // Zapisz ścieżkę i kontynuuj przeszukiwanie od niej
+            let child = self.arena.len() as u32;
+            self.arena.push(PathNode {
+                parent: entry,
+                correlation,
+                node: source,
+                depth: current.depth + 1,
+                total_strength,
+            });
+            self.pending.push_back(child);
+            self.queue.push_back(child);
+        }
+    }
+
+    /// Odtwarza ścieżkę z areny (source → ... → cel) i rozwiązuje nazwy
+    fn materialize(&self, graph: &Graph, entry: u32) -> InfluencePath {
+        let leaf = self.arena[entry as usize];
+        let len = leaf.depth as usize;
+        let mut path = Vec::with_capacity(len + 1);
+        let mut feedback_types = Vec::with_capacity(len);
+        let mut correlation_ids = Vec::with_capacity(len);
+        let mut certainty_sum = 0.0;
+
+        let mut cursor = entry;
+        while cursor != NONE {
+            let item = &self.arena[cursor as usize];
+            if item.parent == NONE {
+                path.push(self.target_id.clone());
+            } else {
+                let relation = &graph.correlations[item.correlation as usize];
+                path.push(relation.source_id.clone());
+                feedback_types.push(relation.relation_type.clone());
+                correlation_ids.push(relation.id.clone());
+                certainty_sum += relation.certainty_score;
+            }
+            cursor = item.parent;
+        }
+
+        let path_names = path
+            .iter()
+            .map(|id| graph.objects.get(id).map_or_else(|| id.clone(), |obj| obj.name.clone()))
+            .collect();
+        let polarity = Polarity::of_path(&feedback_types);
+
+        InfluencePath {
+            path,
+            path_names,
+            total_strength: leaf.total_strength,
+            feedback_types,
+            certainty_score: certainty_sum / len as f64,
+            depth: len,
+            is_feedback_loop: leaf.node == self.target,
+            correlation_ids,
+            polarity,
+            signed_strength: polarity.factor() * leaf.total_strength,
+        }
+    }
+}
+
+// ============================================================================
+// ITERATOR
+// ============================================================================
+
+/// @cybernetic Iterator ścieżek wpływu (bez limitu MAX_PATHS)
+pub struct InfluencePathSearch<'a> {
+    graph: &'a Graph,
+    state: PathSearchState,
+}
+
+impl InfluencePathSearch<'_> {
+    pub fn stats(&self) -> &PathSearchStats {
+        self.state.stats()
+    }
+}
+
+impl Iterator for InfluencePathSearch<'_> {
+    type Item = InfluencePath;
+
+    fn next(&mut self) -> Option<InfluencePath> {
+        self.state.next_path(self.graph)
+    }
+}
+
+impl Graph {
+    /// @cybernetic Leniwe przeszukiwanie ścieżek wpływu do celu
+    pub fn influence_path_search(&self, target_id: &str, _goal: &SteeringGoal) -> InfluencePathSearch<'_> {
+        InfluencePathSearch {
+            graph: self,
+            state: PathSearchState::new(target_id),
+        }
+    }
+
+    /// @cybernetic Ścieżki wpływu z limitem MAX_PATHS i licznikami postępu
+    ///
+    /// Limit sprawdzany jest między rozwinięciami węzłów (jak w TS), więc
+    /// ostatni węzeł może dodać kilka ścieżek ponad MAX_PATHS.
+    pub fn find_influence_paths_with_stats(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+    ) -> (Vec<InfluencePath>, PathSearchStats) {
+        self.find_influence_paths_within(target_id, goal, &mut WorkBudget::unlimited())
+    }
+
+    /// @cybernetic Ścieżki wpływu (limit MAX_PATHS) w ramach budżetu pracy
+    pub fn find_influence_paths_within(
+        &self,
+        target_id: &str,
+        _goal: &SteeringGoal,
+        budget: &mut WorkBudget,
+    ) -> (Vec<InfluencePath>, PathSearchStats) {
+        let mut state = PathSearchState::new(target_id);
+        let mut paths = Vec::new();
+        state.collect_capped_within(self, &mut paths, budget);
+
+        (paths, state.stats)
+    }
+
+    /// @cybernetic Ścieżki wpływu z budżetem - wynik częściowy przy `completed: false`
+    pub fn find_influence_paths_budgeted(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+        budget: &mut WorkBudget,
+    ) -> Budgeted<Vec<InfluencePath>> {
+        let (paths, _) = self.find_influence_paths_within(target_id, goal, budget);
+        budget.wrap(paths)
+    }
+}
+
+// ============================================================================
+// WASM CURSOR
+// ============================================================================
+
+/// @cybernetic Strona wyników kursora
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathPage {
+    pub paths: Vec<InfluencePath>,
+    pub done: bool, // Brak dalszych ścieżek (wyczerpane lub limit)
+    pub stats: PathSearchStats,
+}
+
+/// @cybernetic Kursor ścieżek wpływu dla UI ("wczytaj więcej")
+#[wasm_bindgen]
+pub struct InfluencePathCursor {
+    graph: Graph,
+    state: PathSearchState,
+    max_paths: Option<usize>,
+}
+
+impl InfluencePathCursor {
+    /// `max_paths` = None - bez limitu całkowitego
+    pub fn new(graph: Graph, target_id: &str, max_paths: Option<usize>) -> Self {
+        InfluencePathCursor {
+            graph,
+            state: PathSearchState::new(target_id),
+            max_paths,
+        }
+    }
+
+    /// @cybernetic Następne `count` ścieżek
+    pub fn next_page(&mut self, count: usize) -> PathPage {
+        self.next_page_within(count, &mut WorkBudget::unlimited())
+    }
+
+    /// @cybernetic Następne `count` ścieżek w ramach budżetu
+    ///
+    /// Po wyczerpaniu budżetu strona może być krótsza, a `done` = false -
+    /// kolejne wywołanie wznawia przeszukiwanie.
+    pub fn next_page_within(&mut self, count: usize, budget: &mut WorkBudget) -> PathPage {
+        let mut paths = Vec::with_capacity(count);
+
+        let at_limit = |state: &PathSearchState| self.max_paths.is_some_and(|max| state.stats.emitted >= max);
+        let mut limit_reached = at_limit(&self.state);
+
+        // Limit sprawdzany także po każdej ścieżce - strona kończąca się
+        // dokładnie na max_paths od razu ma `done`, bez pustego dopytania
+        while !limit_reached && paths.len() < count {
+            match self.state.next_path_within(&self.graph, budget) {
+                Some(path) => paths.push(path),
+                None => break,
+            }
+            limit_reached = at_limit(&self.state);
+        }
+
+        if limit_reached {
+            self.state.stop();
+        }
+
+        PathPage {
+            paths,
+            done: limit_reached || self.state.is_exhausted(),
+            stats: self.state.stats.clone(),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl InfluencePathCursor {
+    /// @cybernetic Konstruktor dla JS - `max_paths` = 0 oznacza brak limitu
+    #[wasm_bindgen(constructor)]
+    pub fn wasm_new(
+        objects_json: &str,
+        correlations_json: &str,
+        target_id: &str,
+        goal: &str,
+        max_paths: u32,
+    ) -> Result<InfluencePathCursor, JsValue> {
+        parse_steering_goal(goal)?;
+        let graph = build_graph_from_json(objects_json, correlations_json)?;
+        let limit = (max_paths > 0).then_some(max_paths as usize);
+        Ok(InfluencePathCursor::new(graph, target_id, limit))
+    }
+
+    /// @cybernetic Następna strona jako JSON PathPage
+    #[wasm_bindgen(js_name = next)]
+    pub fn wasm_next(&mut self, count: u32) -> Result<String, JsValue> {
+        serde_json::to_string(&self.next_page(count as usize))
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+    }
+
+    /// @cybernetic Następna strona z budżetem - JSON `{ result: PathPage, completed, ... }`
+    #[wasm_bindgen(js_name = nextWithin)]
+    pub fn wasm_next_within(
+        &mut self,
+        count: u32,
+        budget_json: &str,
+        clock: Option<js_sys::Function>,
+        token: &CancellationToken,
+    ) -> Result<String, JsValue> {
+        let mut budget = budget_from_js(budget_json, clock, Some(token))?;
+        let page = self.next_page_within(count as usize, &mut budget);
+        serde_json::to_string(&budget.wrap(page))
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+    }
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{correlation, graph, object};
+    use crate::RelationType;
+
+    /// Pełne drzewo: cel ← 12 źródeł ← po 12 źródeł = 12 + 144 ścieżek
+    fn wide_graph() -> Graph {
+        let mut ids = vec!["cel".to_string()];
+        let mut edges = Vec::new();
+        for i in 0..12 {
+            let mid = format!("m{}", i);
+            edges.push((mid.clone(), "cel".to_string()));
+            for j in 0..12 {
+                let leaf = format!("l{}_{}", i, j);
+                edges.push((leaf.clone(), mid.clone()));
+                ids.push(leaf);
+            }
+            ids.push(mid);
+        }
+        let id_refs: Vec<&str> = ids.iter().map(|s| s.as_str()).collect();
+        let edge_refs: Vec<(&str, &str, f64)> = edges.iter().map(|(s, t)| (s.as_str(), t.as_str(), 0.9)).collect();
+        graph(&id_refs, &edge_refs)
+    }
+
+    #[test]
+    fn test_legacy_limit_reports_truncation() {
+        let g = wide_graph();
+        let (paths, stats) = g.find_influence_paths_with_stats("cel", &SteeringGoal::Strengthen);
+
+        // 12 + 8 × 12 = 108: limit sprawdzany między rozwinięciami węzłów
+        assert_eq!(paths.len(), 108);
+        assert!(stats.truncated);
+        assert_eq!(stats.emitted, 108);
+        assert_eq!(stats.explored, 9);
+
+        let all: Vec<InfluencePath> = g.influence_path_search("cel", &SteeringGoal::Strengthen).collect();
+        assert_eq!(all.len(), 156);
+        assert_eq!(all[..108].iter().map(|p| &p.path).collect::<Vec<_>>(), paths.iter().map(|p| &p.path).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_arena_rebuilds_paths_with_loops_and_foreign_sources() {
+        let g = Graph::new(
+            vec![object("cel", 1.0), object("a", 1.0), object("b", 1.0)],
+            vec![
+                correlation("a", "cel", RelationType::NegativeFeedback, 0.9, 0.8),
+                correlation("b", "a", RelationType::Supply, 0.8, 0.6),
+                correlation("cel", "b", RelationType::PositiveFeedback, 0.9, 0.4),
+                correlation("obcy", "a", RelationType::DirectControl, 0.5, 1.0),
+                correlation("a", "b", RelationType::Supply, 0.9, 1.0), // a już na ścieżce
+            ],
+        );
+        let paths: Vec<InfluencePath> = g.influence_path_search("cel", &SteeringGoal::Strengthen).collect();
+        let ids: Vec<Vec<&str>> = paths.iter().map(|p| p.path.iter().map(String::as_str).collect()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                vec!["a", "cel"],
+                vec!["b", "a", "cel"],
+                vec!["obcy", "a", "cel"],
+                vec!["cel", "b", "a", "cel"],
+            ]
+        );
+
+        let foreign = &paths[2];
+        assert_eq!(foreign.path_names, vec!["obcy", "A", "CEL"]);
+        assert_eq!(foreign.correlation_ids, vec!["obcy-a", "a-cel"]);
+
+        let feedback_loop = &paths[3];
+        assert!(feedback_loop.is_feedback_loop && !paths[1].is_feedback_loop);
+        assert_eq!(feedback_loop.depth, 3);
+        assert!((feedback_loop.total_strength - 0.9 * 0.8 * 0.9).abs() < 1e-12);
+        assert!((feedback_loop.certainty_score - (0.4 + 0.6 + 0.8) / 3.0).abs() < 1e-12);
+        assert_eq!(
+            feedback_loop.feedback_types,
+            vec![RelationType::PositiveFeedback, RelationType::Supply, RelationType::NegativeFeedback]
+        );
+        assert_eq!(feedback_loop.polarity, Polarity::Negative);
+    }
+
+    #[test]
+    fn test_cursor_pages_through_all_paths() {
+        let mut cursor = InfluencePathCursor::new(wide_graph(), "cel", None);
+        let mut total = 0;
+        loop {
+            let page = cursor.next_page(50);
+            total += page.paths.len();
+            if page.done {
+                assert!(!page.stats.truncated);
+                break;
+            }
+        }
+        assert_eq!(total, 156);
+    }
+
+    #[test]
+    fn test_cursor_limit_and_pruned_counts() {
+        let g = graph(&["a", "b", "cel"], &[("a", "cel", 0.05), ("b", "cel", 0.9)]);
+        let mut cursor = InfluencePathCursor::new(g, "cel", Some(1));
+
+        let page = cursor.next_page(10);
+        assert_eq!(page.paths.len(), 1);
+        assert_eq!(page.stats.pruned, 1); // a → cel poniżej progu
+        assert!(page.done);
+
+        // Strona kończąca się dokładnie na limicie od razu zgłasza koniec
+        let mut cursor = InfluencePathCursor::new(wide_graph(), "cel", Some(24));
+        assert!(!cursor.next_page(12).done);
+        let page = cursor.next_page(12);
+        assert_eq!(page.paths.len(), 12);
+        assert!(page.done && page.stats.truncated);
+        assert!(cursor.next_page(12).paths.is_empty());
+    }
+
+    #[test]
+    fn test_budget_stops_and_cursor_resumes() {
+        let g = wide_graph();
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut cancelled = WorkBudget::unlimited().with_cancellation(token);
+        let ranked = g.rank_influential_nodes_budgeted(
+            "cel",
+            &SteeringGoal::Strengthen,
+            &crate::feedback::FeedbackModel::default(),
+            &mut cancelled,
+        );
+        assert!(!ranked.outcome.completed && ranked.result.is_empty());
+
+        // Budżet 2 rozwinięć: cel (12 ścieżek) + m0 (12 ścieżek), potem wznowienie
+        let mut cursor = InfluencePathCursor::new(g, "cel", None);
+        let mut budget = WorkBudget::new(crate::budget::BudgetConfig {
+            max_expanded_nodes: Some(2),
+            ..Default::default()
+        });
+        let page = cursor.next_page_within(100, &mut budget);
+        assert_eq!(page.paths.len(), 24);
+        assert!(!page.done && !budget.outcome().completed);
+        assert_eq!(cursor.next_page(1000).paths.len(), 156 - 24);
+    }
+}
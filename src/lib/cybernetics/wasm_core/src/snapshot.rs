@@ -0,0 +1,720 @@
+//! @fileoverview Binarny snapshot grafu (cache w IndexedDB)
+//! @cybernetic Szybkie odtworzenie Grafu bez ponownego pobierania JSON z Supabase
+//!
+//! Format (wszystkie liczby little-endian):
+//!
+//! ```text
+//! nagłówek (16 B): "KMSG" | wersja u16 | zarezerwowane u16 | długość treści u32 | CRC-32 treści u32
+//! treść:           tablica napisów (liczba u32, [długość u32, UTF-8]...)
+//!                  liczba obiektów u32 | liczba relacji u32
+//!                  kolumny: [tag u16 | długość u32 | dane]...
+//! ```
+//!
+//! Każdy napis (ID, nazwy, daty) występuje w tablicy raz; kolumny przechowują
+//! indeksy u32 (`NONE_STRING` = brak wartości). Kolumny liczbowe to ciągłe
+//! tablice f64. Czytnik pomija nieznane kolumny, a brakujące kolumny pól
+//! opcjonalnych uzupełnia domyślnymi - dzięki temu snapshoty starszych wersji
+//! (bez kolumn dodanych później) pozostają czytelne.
+
+use crate::{
+    CivilizationCode, ControlSystemType, Correlation, CyberneticObject, EnergyParams, Graph,
+    MotivationType, RelationType, SystemClass,
+};
+use std::collections::HashMap;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Sygnatura pliku
+pub const SNAPSHOT_MAGIC: [u8; 4] = *b"KMSG";
+
+/// Wersja zapisywana przez `to_snapshot`
+pub const SNAPSHOT_VERSION: u16 = 1;
+
+/// Najstarsza wersja, którą potrafimy wczytać
+pub const MIN_SNAPSHOT_VERSION: u16 = 1;
+
+/// Indeks napisu oznaczający `None`
+pub const NONE_STRING: u32 = u32::MAX;
+
+const HEADER_LEN: usize = 16;
+
+// ============================================================================
+// KOLUMNY
+// ============================================================================
+
+/// @cybernetic Tagi kolumn (stałe - nowe kolumny dostają nowe tagi)
+pub mod column {
+    // Obiekty
+    pub const OBJECT_ID: u16 = 1;
+    pub const OBJECT_NAME: u16 = 2;
+    pub const OBJECT_DESCRIPTION: u16 = 3;
+    pub const OBJECT_SYSTEM_CLASS: u16 = 4;
+    pub const OBJECT_CONTROL_SYSTEM_TYPE: u16 = 5;
+    pub const OBJECT_WORKING_POWER: u16 = 6;
+    pub const OBJECT_IDLE_POWER: u16 = 7;
+    pub const OBJECT_AVAILABLE_POWER: u16 = 8;
+    pub const OBJECT_POWER_V: u16 = 9;
+    pub const OBJECT_QUALITY_A: u16 = 10;
+    pub const OBJECT_MASS_C: u16 = 11;
+    pub const OBJECT_CIVILIZATION_CODE: u16 = 12;
+    pub const OBJECT_MOTIVATION_TYPE: u16 = 13;
+    pub const OBJECT_CREATED_AT: u16 = 14;
+
+    // Relacje
+    pub const CORRELATION_ID: u16 = 32;
+    pub const CORRELATION_SOURCE_ID: u16 = 33;
+    pub const CORRELATION_TARGET_ID: u16 = 34;
+    pub const CORRELATION_RELATION_TYPE: u16 = 35;
+    pub const CORRELATION_CERTAINTY_SCORE: u16 = 36;
+    pub const CORRELATION_IMPACT_FACTOR: u16 = 37;
+    pub const CORRELATION_SOURCE_NAME: u16 = 38;
+    pub const CORRELATION_SUPERSEDED_AT: u16 = 39;
+    pub const CORRELATION_SUPERSEDED_BY: u16 = 40;
+    pub const CORRELATION_CREATED_AT: u16 = 41;
+}
+
+// ============================================================================
+// BŁĘDY
+// ============================================================================
+
+/// @cybernetic Błąd odczytu snapshotu
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotError {
+    BadMagic,
+    UnsupportedVersion(u16),
+    Truncated,
+    ChecksumMismatch { expected: u32, actual: u32 },
+    InvalidUtf8,
+    InvalidStringIndex(u32),
+    MissingColumn(u16),
+    ColumnLength(u16),
+    InvalidEnum { column: u16, value: u8 },
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::BadMagic => write!(f, "not a KMS graph snapshot"),
+            SnapshotError::UnsupportedVersion(v) => write!(
+                f,
+                "unsupported snapshot version {} (supported: {}..={})",
+                v, MIN_SNAPSHOT_VERSION, SNAPSHOT_VERSION
+            ),
+            SnapshotError::Truncated => write!(f, "snapshot is truncated"),
+            SnapshotError::ChecksumMismatch { expected, actual } => {
+                write!(f, "checksum mismatch (expected {:08x}, got {:08x})", expected, actual)
+            }
+            SnapshotError::InvalidUtf8 => write!(f, "string table contains invalid UTF-8"),
+            SnapshotError::InvalidStringIndex(i) => write!(f, "string index {} out of range", i),
+            SnapshotError::MissingColumn(tag) => write!(f, "required column {} is missing", tag),
+            SnapshotError::ColumnLength(tag) => write!(f, "column {} has invalid length", tag),
+            SnapshotError::InvalidEnum { column, value } => {
+                write!(f, "column {} has invalid enum value {}", column, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+// ============================================================================
+// CRC-32 (IEEE 802.3)
+// ============================================================================
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// @cybernetic Suma kontrolna CRC-32 (jak w zlib / PNG)
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &b| CRC_TABLE[((crc ^ b as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+// ============================================================================
+// KODOWANIE ENUMÓW
+// ============================================================================
+
+fn system_class_code(value: &SystemClass) -> u8 {
+    match value {
+        SystemClass::AutonomousSystem => 0,
+        SystemClass::HeteronomousSystem => 1,
+        SystemClass::Environment => 2,
+        SystemClass::Tool => 3,
+    }
+}
+
+fn system_class_from(code: u8) -> Option<SystemClass> {
+    Some(match code {
+        0 => SystemClass::AutonomousSystem,
+        1 => SystemClass::HeteronomousSystem,
+        2 => SystemClass::Environment,
+        3 => SystemClass::Tool,
+        _ => return None,
+    })
+}
+
+fn control_system_type_code(value: &ControlSystemType) -> u8 {
+    match value {
+        ControlSystemType::Cognitive => 0,
+        ControlSystemType::Ideological => 1,
+        ControlSystemType::Ethical => 2,
+        ControlSystemType::Economic => 3,
+    }
+}
+
+fn control_system_type_from(code: u8) -> Option<ControlSystemType> {
+    Some(match code {
+        0 => ControlSystemType::Cognitive,
+        1 => ControlSystemType::Ideological,
+        2 => ControlSystemType::Ethical,
+        3 => ControlSystemType::Economic,
+        _ => return None,
+    })
+}
+
+fn civilization_code(value: CivilizationCode) -> u8 {
+    match value {
+        CivilizationCode::Latin => 0,
+        CivilizationCode::Byzantine => 1,
+        CivilizationCode::Turandot => 2,
+        CivilizationCode::Mixed => 3,
+        CivilizationCode::Unknown => 4,
+    }
+}
+
+fn civilization_from(code: u8) -> Option<CivilizationCode> {
+    Some(match code {
+        0 => CivilizationCode::Latin,
+        1 => CivilizationCode::Byzantine,
+        2 => CivilizationCode::Turandot,
+        3 => CivilizationCode::Mixed,
+        4 => CivilizationCode::Unknown,
+        _ => return None,
+    })
+}
+
+fn motivation_code(value: MotivationType) -> u8 {
+    match value {
+        MotivationType::Vital => 0,
+        MotivationType::Informational => 1,
+        MotivationType::Mixed => 2,
+    }
+}
+
+fn motivation_from(code: u8) -> Option<MotivationType> {
+    Some(match code {
+        0 => MotivationType::Vital,
+        1 => MotivationType::Informational,
+        2 => MotivationType::Mixed,
+        _ => return None,
+    })
+}
+
+fn relation_type_code(value: &RelationType) -> u8 {
+    match value {
+        RelationType::DirectControl => 0,
+        RelationType::PositiveFeedback => 1,
+        RelationType::NegativeFeedback => 2,
+        RelationType::Supply => 3,
+    }
+}
+
+fn relation_type_from(code: u8) -> Option<RelationType> {
+    Some(match code {
+        0 => RelationType::DirectControl,
+        1 => RelationType::PositiveFeedback,
+        2 => RelationType::NegativeFeedback,
+        3 => RelationType::Supply,
+        _ => return None,
+    })
+}
+
+// ============================================================================
+// ZAPIS
+// ============================================================================
+
+/// Tablica napisów z internowaniem
+#[derive(Default)]
+struct StringTable<'a> {
+    index: HashMap<&'a str, u32>,
+    strings: Vec<&'a str>,
+}
+
+impl<'a> StringTable<'a> {
+    fn intern(&mut self, s: &'a str) -> u32 {
+        if let Some(&i) = self.index.get(s) {
+            return i;
+        }
+        let i = self.strings.len() as u32;
+        self.strings.push(s);
+        self.index.insert(s, i);
+        i
+    }
+
+    fn intern_opt(&mut self, s: &'a Option<String>) -> u32 {
+        s.as_deref().map_or(NONE_STRING, |s| self.intern(s))
+    }
+}
+
+fn put_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_column(out: &mut Vec<u8>, tag: u16, data: &[u8]) {
+    out.extend_from_slice(&tag.to_le_bytes());
+    put_u32(out, data.len() as u32);
+    out.extend_from_slice(data);
+}
+
+fn u32_column(values: impl Iterator<Item = u32>) -> Vec<u8> {
+    values.flat_map(u32::to_le_bytes).collect()
+}
+
+fn f64_column(values: impl Iterator<Item = f64>) -> Vec<u8> {
+    values.flat_map(f64::to_le_bytes).collect()
+}
+
+impl Graph {
+    /// @cybernetic Zapis grafu do snapshotu (obiekty posortowane po ID)
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        let mut objects: Vec<&CyberneticObject> = self.objects().collect();
+        objects.sort_by(|a, b| a.id.cmp(&b.id));
+        let correlations = self.correlations();
+
+        let mut table = StringTable::default();
+        let obj_ids: Vec<u32> = objects.iter().map(|o| table.intern(&o.id)).collect();
+        let obj_names: Vec<u32> = objects.iter().map(|o| table.intern(&o.name)).collect();
+        let obj_descriptions: Vec<u32> = objects.iter().map(|o| table.intern_opt(&o.description)).collect();
+        let obj_created: Vec<u32> = objects.iter().map(|o| table.intern(&o.created_at)).collect();
+        let corr_ids: Vec<u32> = correlations.iter().map(|c| table.intern(&c.id)).collect();
+        let corr_sources: Vec<u32> = correlations.iter().map(|c| table.intern(&c.source_id)).collect();
+        let corr_targets: Vec<u32> = correlations.iter().map(|c| table.intern(&c.target_id)).collect();
+        let corr_source_names: Vec<u32> = correlations.iter().map(|c| table.intern_opt(&c.source_name)).collect();
+        let corr_superseded_at: Vec<u32> = correlations.iter().map(|c| table.intern_opt(&c.superseded_at)).collect();
+        let corr_superseded_by: Vec<u32> = correlations.iter().map(|c| table.intern_opt(&c.superseded_by)).collect();
+        let corr_created: Vec<u32> = correlations.iter().map(|c| table.intern(&c.created_at)).collect();
+
+        let mut payload = Vec::new();
+        put_u32(&mut payload, table.strings.len() as u32);
+        for s in &table.strings {
+            put_u32(&mut payload, s.len() as u32);
+            payload.extend_from_slice(s.as_bytes());
+        }
+        put_u32(&mut payload, objects.len() as u32);
+        put_u32(&mut payload, correlations.len() as u32);
+
+        let p = &mut payload;
+        put_column(p, column::OBJECT_ID, &u32_column(obj_ids.into_iter()));
+        put_column(p, column::OBJECT_NAME, &u32_column(obj_names.into_iter()));
+        put_column(p, column::OBJECT_DESCRIPTION, &u32_column(obj_descriptions.into_iter()));
+        put_column(
+            p,
+            column::OBJECT_SYSTEM_CLASS,
+            &objects.iter().map(|o| system_class_code(&o.system_class)).collect::<Vec<u8>>(),
+        );
+        put_column(
+            p,
+            column::OBJECT_CONTROL_SYSTEM_TYPE,
+            &objects.iter().map(|o| control_system_type_code(&o.control_system_type)).collect::<Vec<u8>>(),
+        );
+        put_column(p, column::OBJECT_WORKING_POWER, &f64_column(objects.iter().map(|o| o.energy_params.working_power)));
+        put_column(p, column::OBJECT_IDLE_POWER, &f64_column(objects.iter().map(|o| o.energy_params.idle_power)));
+        put_column(
+            p,
+            column::OBJECT_AVAILABLE_POWER,
+            &f64_column(objects.iter().map(|o| o.energy_params.available_power)),
+        );
+        put_column(p, column::OBJECT_POWER_V, &f64_column(objects.iter().map(|o| o.power_v)));
+        put_column(p, column::OBJECT_QUALITY_A, &f64_column(objects.iter().map(|o| o.quality_a)));
+        put_column(p, column::OBJECT_MASS_C, &f64_column(objects.iter().map(|o| o.mass_c)));
+        put_column(
+            p,
+            column::OBJECT_CIVILIZATION_CODE,
+            &objects.iter().map(|o| civilization_code(o.civilization_code)).collect::<Vec<u8>>(),
+        );
+        put_column(
+            p,
+            column::OBJECT_MOTIVATION_TYPE,
+            &objects.iter().map(|o| motivation_code(o.motivation_type)).collect::<Vec<u8>>(),
+        );
+        put_column(p, column::OBJECT_CREATED_AT, &u32_column(obj_created.into_iter()));
+
+        put_column(p, column::CORRELATION_ID, &u32_column(corr_ids.into_iter()));
+        put_column(p, column::CORRELATION_SOURCE_ID, &u32_column(corr_sources.into_iter()));
+        put_column(p, column::CORRELATION_TARGET_ID, &u32_column(corr_targets.into_iter()));
+        put_column(
+            p,
+            column::CORRELATION_RELATION_TYPE,
+            &correlations.iter().map(|c| relation_type_code(&c.relation_type)).collect::<Vec<u8>>(),
+        );
+        put_column(p, column::CORRELATION_CERTAINTY_SCORE, &f64_column(correlations.iter().map(|c| c.certainty_score)));
+        put_column(p, column::CORRELATION_IMPACT_FACTOR, &f64_column(correlations.iter().map(|c| c.impact_factor)));
+        put_column(p, column::CORRELATION_SOURCE_NAME, &u32_column(corr_source_names.into_iter()));
+        put_column(p, column::CORRELATION_SUPERSEDED_AT, &u32_column(corr_superseded_at.into_iter()));
+        put_column(p, column::CORRELATION_SUPERSEDED_BY, &u32_column(corr_superseded_by.into_iter()));
+        put_column(p, column::CORRELATION_CREATED_AT, &u32_column(corr_created.into_iter()));
+
+        let mut out = Vec::with_capacity(HEADER_LEN + payload.len());
+        out.extend_from_slice(&SNAPSHOT_MAGIC);
+        out.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        put_u32(&mut out, payload.len() as u32);
+        put_u32(&mut out, crc32(&payload));
+        out.extend_from_slice(&payload);
+        out
+    }
+}
+
+// ============================================================================
+// ODCZYT
+// ============================================================================
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], SnapshotError> {
+        let end = self.pos.checked_add(n).filter(|&end| end <= self.bytes.len()).ok_or(SnapshotError::Truncated)?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u16(&mut self) -> Result<u16, SnapshotError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, SnapshotError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
+
+/// Kolumny treści snapshotu po tagu
+struct Columns<'a> {
+    strings: Vec<&'a str>,
+    data: HashMap<u16, &'a [u8]>,
+}
+
+impl<'a> Columns<'a> {
+    fn raw(&self, tag: u16, n: usize, width: usize) -> Result<Option<&'a [u8]>, SnapshotError> {
+        // Liczba rekordów pochodzi z pliku - na wasm32 n × width może się przepełnić
+        let len = n.checked_mul(width).ok_or(SnapshotError::Truncated)?;
+        match self.data.get(&tag) {
+            Some(data) if data.len() == len => Ok(Some(data)),
+            Some(_) => Err(SnapshotError::ColumnLength(tag)),
+            None => Ok(None),
+        }
+    }
+
+    fn required(&self, tag: u16, n: usize, width: usize) -> Result<&'a [u8], SnapshotError> {
+        self.raw(tag, n, width)?.ok_or(SnapshotError::MissingColumn(tag))
+    }
+
+    fn string(&self, index: u32) -> Result<Option<String>, SnapshotError> {
+        if index == NONE_STRING {
+            return Ok(None);
+        }
+        self.strings
+            .get(index as usize)
+            .map(|s| Some(s.to_string()))
+            .ok_or(SnapshotError::InvalidStringIndex(index))
+    }
+
+    /// Kolumna napisów wymaganych (`NONE_STRING` → "")
+    fn strings(&self, tag: u16, n: usize) -> Result<Vec<String>, SnapshotError> {
+        Ok(self.optional_strings(tag, n, true)?.into_iter().map(Option::unwrap_or_default).collect())
+    }
+
+    fn optional_strings(&self, tag: u16, n: usize, required: bool) -> Result<Vec<Option<String>>, SnapshotError> {
+        let data = if required { Some(self.required(tag, n, 4)?) } else { self.raw(tag, n, 4)? };
+        match data {
+            Some(data) => data
+                .chunks_exact(4)
+                .map(|c| self.string(u32::from_le_bytes(c.try_into().unwrap())))
+                .collect(),
+            None => Ok(vec![None; n]),
+        }
+    }
+
+    fn f64s(&self, tag: u16, n: usize) -> Result<Vec<f64>, SnapshotError> {
+        Ok(self
+            .required(tag, n, 8)?
+            .chunks_exact(8)
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+            .collect())
+    }
+
+    fn enums<T>(
+        &self,
+        tag: u16,
+        n: usize,
+        default: Option<T>,
+        decode: fn(u8) -> Option<T>,
+    ) -> Result<Vec<T>, SnapshotError>
+    where
+        T: Clone,
+    {
+        let data = match (self.raw(tag, n, 1)?, default) {
+            (Some(data), _) => data,
+            (None, Some(default)) => return Ok(vec![default; n]),
+            (None, None) => return Err(SnapshotError::MissingColumn(tag)),
+        };
+        data.iter()
+            .map(|&value| decode(value).ok_or(SnapshotError::InvalidEnum { column: tag, value }))
+            .collect()
+    }
+}
+
+impl Graph {
+    /// @cybernetic Odtworzenie grafu ze snapshotu (dowolnej obsługiwanej wersji)
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Graph, SnapshotError> {
+        let mut header = Reader { bytes, pos: 0 };
+        if header.take(4)? != SNAPSHOT_MAGIC {
+            return Err(SnapshotError::BadMagic);
+        }
+        let version = header.u16()?;
+        if !(MIN_SNAPSHOT_VERSION..=SNAPSHOT_VERSION).contains(&version) {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        header.u16()?; // Zarezerwowane
+        let payload_len = header.u32()? as usize;
+        let expected = header.u32()?;
+        let payload = header.take(payload_len)?;
+        let actual = crc32(payload);
+        if actual != expected {
+            return Err(SnapshotError::ChecksumMismatch { expected, actual });
+        }
+
+        let mut r = Reader { bytes: payload, pos: 0 };
+        let string_count = r.u32()? as usize;
+        let mut strings = Vec::with_capacity(string_count.min(payload_len));
+        for _ in 0..string_count {
+            let len = r.u32()? as usize;
+            strings.push(std::str::from_utf8(r.take(len)?).map_err(|_| SnapshotError::InvalidUtf8)?);
+        }
+        let n = r.u32()? as usize;
+        let m = r.u32()? as usize;
+
+        let mut data = HashMap::new();
+        while r.pos < payload.len() {
+            let tag = r.u16()?;
+            let len = r.u32()? as usize;
+            data.insert(tag, r.take(len)?); // Nieznane tagi (nowsze kolumny) są ignorowane
+        }
+        let cols = Columns { strings, data };
+
+        use column::*;
+        let ids = cols.strings(OBJECT_ID, n)?;
+        let names = cols.strings(OBJECT_NAME, n)?;
+        let descriptions = cols.optional_strings(OBJECT_DESCRIPTION, n, false)?;
+        let system_classes = cols.enums(OBJECT_SYSTEM_CLASS, n, None, system_class_from)?;
+        let control_types = cols.enums(OBJECT_CONTROL_SYSTEM_TYPE, n, None, control_system_type_from)?;
+        let working = cols.f64s(OBJECT_WORKING_POWER, n)?;
+        let idle = cols.f64s(OBJECT_IDLE_POWER, n)?;
+        let available = cols.f64s(OBJECT_AVAILABLE_POWER, n)?;
+        let power_v = cols.f64s(OBJECT_POWER_V, n)?;
+        let quality_a = cols.f64s(OBJECT_QUALITY_A, n)?;
+        let mass_c = cols.f64s(OBJECT_MASS_C, n)?;
+        let civilizations = cols.enums(OBJECT_CIVILIZATION_CODE, n, Some(CivilizationCode::default()), civilization_from)?;
+        let motivations = cols.enums(OBJECT_MOTIVATION_TYPE, n, Some(MotivationType::default()), motivation_from)?;
+        let created = cols.strings(OBJECT_CREATED_AT, n)?;
+
+        let objects = (0..n)
+            .map(|i| CyberneticObject {
+                id: ids[i].clone(),
+                name: names[i].clone(),
+                description: descriptions[i].clone(),
+                system_class: system_classes[i].clone(),
+                control_system_type: control_types[i].clone(),
+                energy_params: EnergyParams {
+                    working_power: working[i],
+                    idle_power: idle[i],
+                    available_power: available[i],
+                },
+                power_v: power_v[i],
+                quality_a: quality_a[i],
+                mass_c: mass_c[i],
+                civilization_code: civilizations[i],
+                motivation_type: motivations[i],
+                created_at: created[i].clone(),
+            })
+            .collect();
+
+        let corr_ids = cols.strings(CORRELATION_ID, m)?;
+        let sources = cols.strings(CORRELATION_SOURCE_ID, m)?;
+        let targets = cols.strings(CORRELATION_TARGET_ID, m)?;
+        let relation_types = cols.enums(CORRELATION_RELATION_TYPE, m, None, relation_type_from)?;
+        let certainty = cols.f64s(CORRELATION_CERTAINTY_SCORE, m)?;
+        let impact = cols.f64s(CORRELATION_IMPACT_FACTOR, m)?;
+        let source_names = cols.optional_strings(CORRELATION_SOURCE_NAME, m, false)?;
+        let superseded_at = cols.optional_strings(CORRELATION_SUPERSEDED_AT, m, false)?;
+        let superseded_by = cols.optional_strings(CORRELATION_SUPERSEDED_BY, m, false)?;
+        let corr_created = cols.strings(CORRELATION_CREATED_AT, m)?;
+
+        let correlations = (0..m)
+            .map(|i| Correlation {
+                id: corr_ids[i].clone(),
+                source_id: sources[i].clone(),
+                target_id: targets[i].clone(),
+                relation_type: relation_types[i].clone(),
+                certainty_score: certainty[i],
+                impact_factor: impact[i],
+                source_name: source_names[i].clone(),
+                superseded_at: superseded_at[i].clone(),
+                superseded_by: superseded_by[i].clone(),
+                created_at: corr_created[i].clone(),
+            })
+            .collect();
+
+        Ok(Graph::new(objects, correlations))
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+/// @cybernetic WASM Entry Point - Zapis grafu do snapshotu (Uint8Array)
+#[wasm_bindgen]
+pub fn wasm_save_snapshot(objects_json: &str, correlations_json: &str) -> Result<Vec<u8>, JsValue> {
+    let graph = crate::build_graph_from_json(objects_json, correlations_json)?;
+    Ok(graph.to_snapshot())
+}
+
+/// @cybernetic WASM Entry Point - Odczyt snapshotu do JSON `{ objects, correlations }`
+#[wasm_bindgen]
+pub fn wasm_load_snapshot(bytes: &[u8]) -> Result<String, JsValue> {
+    let graph = Graph::from_snapshot(bytes)
+        .map_err(|e| JsValue::from_str(&format!("Failed to load snapshot: {}", e)))?;
+
+    let mut objects: Vec<&CyberneticObject> = graph.objects().collect();
+    objects.sort_by(|a, b| a.id.cmp(&b.id));
+
+    serde_json::to_string(&serde_json::json!({
+        "objects": objects,
+        "correlations": graph.correlations(),
+    }))
+    .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{correlation, object};
+
+    fn sample() -> Graph {
+        let mut a = object("a", 2.0);
+        a.description = Some("Źródło".to_string());
+        a.civilization_code = CivilizationCode::Latin;
+        let mut supersede = correlation("b", "cel", RelationType::NegativeFeedback, 0.4, 0.7);
+        supersede.superseded_by = Some("a-cel".to_string());
+        Graph::new(
+            vec![a, object("b", 1.0), object("cel", 0.5)],
+            vec![correlation("a", "cel", RelationType::DirectControl, 0.9, 1.0), supersede],
+        )
+    }
+
+    fn json(graph: &Graph) -> serde_json::Value {
+        let mut objects: Vec<&CyberneticObject> = graph.objects().collect();
+        objects.sort_by(|a, b| a.id.cmp(&b.id));
+        serde_json::json!({ "objects": objects, "correlations": graph.correlations() })
+    }
+
+    /// Przebudowa snapshotu bez wybranych kolumn (symulacja starszego zapisu)
+    fn without_columns(bytes: &[u8], dropped: &[u16]) -> Vec<u8> {
+        let payload = &bytes[HEADER_LEN..];
+        let mut r = Reader { bytes: payload, pos: 0 };
+        let string_count = r.u32().unwrap();
+        for _ in 0..string_count {
+            let len = r.u32().unwrap() as usize;
+            r.take(len).unwrap();
+        }
+        r.take(8).unwrap();
+        let mut rebuilt = payload[..r.pos].to_vec();
+        while r.pos < payload.len() {
+            let tag = r.u16().unwrap();
+            let len = r.u32().unwrap() as usize;
+            let data = r.take(len).unwrap();
+            if !dropped.contains(&tag) {
+                put_column(&mut rebuilt, tag, data);
+            }
+        }
+
+        let mut out = bytes[..8].to_vec();
+        put_u32(&mut out, rebuilt.len() as u32);
+        put_u32(&mut out, crc32(&rebuilt));
+        out.extend_from_slice(&rebuilt);
+        out
+    }
+
+    #[test]
+    fn test_roundtrip_preserves_graph() {
+        let graph = sample();
+        let bytes = graph.to_snapshot();
+        let restored = Graph::from_snapshot(&bytes).unwrap();
+
+        assert_eq!(json(&restored), json(&graph));
+        assert_eq!(&bytes[..4], b"KMSG");
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_corruption_is_detected() {
+        let mut bytes = sample().to_snapshot();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        assert!(matches!(Graph::from_snapshot(&bytes), Err(SnapshotError::ChecksumMismatch { .. })));
+
+        assert_eq!(Graph::from_snapshot(&bytes[..10]).err(), Some(SnapshotError::Truncated));
+        assert_eq!(Graph::from_snapshot(b"JSON{}..........").err(), Some(SnapshotError::BadMagic));
+
+        let mut future = sample().to_snapshot();
+        future[4..6].copy_from_slice(&(SNAPSHOT_VERSION + 1).to_le_bytes());
+        assert_eq!(
+            Graph::from_snapshot(&future).err(),
+            Some(SnapshotError::UnsupportedVersion(SNAPSHOT_VERSION + 1))
+        );
+    }
+
+    #[test]
+    fn test_column_length_overflow_is_truncated() {
+        let columns = Columns { strings: Vec::new(), data: HashMap::from([(column::OBJECT_ID, &[0u8; 8][..])]) };
+        assert_eq!(columns.raw(column::OBJECT_ID, usize::MAX, 4).err(), Some(SnapshotError::Truncated));
+        assert_eq!(columns.raw(column::OBJECT_ID, 2, 4).unwrap().map(<[u8]>::len), Some(8));
+    }
+
+    #[test]
+    fn test_older_snapshot_without_optional_columns() {
+        let bytes = without_columns(
+            &sample().to_snapshot(),
+            &[column::OBJECT_CIVILIZATION_CODE, column::OBJECT_MOTIVATION_TYPE, column::CORRELATION_SUPERSEDED_BY],
+        );
+        let restored = Graph::from_snapshot(&bytes).unwrap();
+        assert_eq!(restored.object("a").unwrap().civilization_code, CivilizationCode::Unknown);
+        assert!(restored.correlations()[1].superseded_by.is_none());
+
+        let broken = without_columns(&sample().to_snapshot(), &[column::CORRELATION_SOURCE_ID]);
+        assert_eq!(
+            Graph::from_snapshot(&broken).err(),
+            Some(SnapshotError::MissingColumn(column::CORRELATION_SOURCE_ID))
+        );
+    }
+}
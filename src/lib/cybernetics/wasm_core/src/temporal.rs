@@ -0,0 +1,385 @@
+//! @fileoverview Zapytania temporalne na grafie sterowania
+//! @cybernetic Stan grafu "na dzień" - relacje z przedziałami ważności
+//!
+//! Relacja jest ważna od `created_at` do `superseded_at` (wyłącznie).
+//! Brak `superseded_at` oznacza relację wciąż aktywną - zgodnie z filtrem
+//! `.is('superseded_at', null)` w contradiction-engine.ts i pathfinder.ts.
+
+use crate::{
+    build_graph_from_json, parse_steering_goal, Correlation, CyberneticObject, Graph, SteeringGoal,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// ZNACZNIKI CZASU
+// ============================================================================
+
+/// @cybernetic Parsuje znacznik czasu ISO 8601 / RFC 3339 do milisekund od epoki (UTC)
+///
+/// Obsługuje formaty zwracane przez Supabase i `Date.toISOString()`:
+/// - `2025-01-01`
+/// - `2025-01-01T12:30:00Z`
+/// - `2025-01-01T12:30:00.123456+00:00`
+/// - `2025-01-01 12:30:00+02`
+pub fn parse_timestamp(input: &str) -> Option<i64> {
+    let s = input.trim();
+    let bytes = s.as_bytes();
+    if bytes.len() < 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+
+    let year: i64 = s.get(0..4)?.parse().ok()?;
+    let month: i64 = s.get(5..7)?.parse().ok()?;
+    let day: i64 = s.get(8..10)?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    let mut millis = days * 86_400_000;
+
+    let rest = &s[10..];
+    if rest.is_empty() {
+        return Some(millis);
+    }
+
+    let rest = rest.strip_prefix('T').or_else(|| rest.strip_prefix(' '))?;
+    let (time_part, offset_minutes) = split_offset(rest)?;
+
+    let mut fields = time_part.splitn(3, ':');
+    let hour: i64 = fields.next()?.parse().ok()?;
+    let minute: i64 = fields.next()?.parse().ok()?;
+    let (second, fraction_ms) = match fields.next() {
+        Some(sec) => parse_seconds(sec)?,
+        None => (0, 0),
+    };
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    millis += ((hour * 60 + minute) * 60 + second) * 1000 + fraction_ms;
+    millis -= offset_minutes * 60_000;
+
+    Some(millis)
+}
+
+/// Dzieli część czasową na (czas, przesunięcie strefy w minutach)
+fn split_offset(rest: &str) -> Option<(&str, i64)> {
+    if let Some(time) = rest.strip_suffix('Z').or_else(|| rest.strip_suffix('z')) {
+        return Some((time, 0));
+    }
+
+    match rest.rfind(['+', '-']) {
+        Some(pos) => {
+            let (time, offset) = rest.split_at(pos);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let digits: String = offset[1..].chars().filter(|c| *c != ':').collect();
+            let (hours, minutes) = match digits.len() {
+                2 => (digits.parse::<i64>().ok()?, 0),
+                4 => (digits[0..2].parse::<i64>().ok()?, digits[2..4].parse::<i64>().ok()?),
+                _ => return None,
+            };
+            Some((time, sign * (hours * 60 + minutes)))
+        }
+        None => Some((rest, 0)),
+    }
+}
+
+/// Parsuje sekundy z opcjonalnym ułamkiem: "05" | "05.123456"
+fn parse_seconds(sec: &str) -> Option<(i64, i64)> {
+    match sec.split_once('.') {
+        Some((whole, fraction)) => {
+            let millis_digits: String = fraction.chars().chain("000".chars()).take(3).collect();
+            Some((whole.parse().ok()?, millis_digits.parse().ok()?))
+        }
+        None => Some((sec.parse().ok()?, 0)),
+    }
+}
+
+/// Liczba dni od 1970-01-01 (algorytm H. Hinnanta, kalendarz gregoriański)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// ============================================================================
+// PRZEDZIAŁ WAŻNOŚCI RELACJI
+// ============================================================================
+
+/// @cybernetic Przedział ważności relacji [valid_from, valid_to)
+///
+/// `None` oznacza brak ograniczenia z danej strony (np. nieparsowalne `created_at`
+/// traktujemy jak relację istniejącą "od zawsze").
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ValidityInterval {
+    pub valid_from: Option<i64>,
+    pub valid_to: Option<i64>,
+}
+
+impl ValidityInterval {
+    /// @cybernetic Wyznacza przedział z `created_at` i `superseded_at`
+    pub fn from_correlation(correlation: &Correlation) -> Self {
+        ValidityInterval {
+            valid_from: parse_timestamp(&correlation.created_at),
+            valid_to: correlation
+                .superseded_at
+                .as_deref()
+                .and_then(parse_timestamp),
+        }
+    }
+
+    /// @cybernetic Czy relacja była ważna w chwili `timestamp` (ms)
+    pub fn contains(&self, timestamp: i64) -> bool {
+        let started = self.valid_from.is_none_or(|from| from <= timestamp);
+        let not_ended = self.valid_to.is_none_or(|to| timestamp < to);
+        started && not_ended
+    }
+}
+
+// ============================================================================
+// GRAF "NA DZIEŃ"
+// ============================================================================
+
+impl Graph {
+    /// @cybernetic Przedziały ważności relacji (w kolejności wejściowej)
+    pub fn validity_intervals(&self) -> impl Iterator<Item = (&Correlation, &ValidityInterval)> {
+        self.correlations.iter().zip(self.validity.iter())
+    }
+
+    /// @cybernetic Widok grafu w chwili `timestamp` (ms od epoki)
+    ///
+    /// Zawiera tylko obiekty utworzone do tej chwili i relacje ważne w tej chwili.
+    /// Zwraca pełnoprawny `Graph`, więc każda analiza działa na widoku bez zmian.
+    pub fn at(&self, timestamp: i64) -> Graph {
+        let mut objects: Vec<CyberneticObject> = self
+            .objects
+            .values()
+            .filter(|obj| parse_timestamp(&obj.created_at).is_none_or(|created| created <= timestamp))
+            .cloned()
+            .collect();
+        // Stabilna kolejność niezależna od HashMap
+        objects.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let correlations: Vec<Correlation> = self
+            .validity_intervals()
+            .filter(|(_, interval)| interval.contains(timestamp))
+            .map(|(corr, _)| corr.clone())
+            .collect();
+
+        Graph::new(objects, correlations)
+    }
+
+    /// @cybernetic Porównuje ranking dźwigni sterowniczej między dwiema datami
+    ///
+    /// Pokazuje, jak przesunęła się kontrola nad celem: kto zyskał, kto stracił,
+    /// kto pojawił się lub zniknął z rankingu. Wynik posortowany po |delta| (DESC).
+    pub fn influence_shift(
+        &self,
+        target_id: &str,
+        goal: &SteeringGoal,
+        from: i64,
+        to: i64,
+    ) -> Vec<InfluenceShift> {
+        let before = self.at(from).rank_influential_nodes(target_id, goal);
+        let after = self.at(to).rank_influential_nodes(target_id, goal);
+
+        let mut shifts: HashMap<String, InfluenceShift> = HashMap::new();
+
+        for (rank, node) in before.iter().enumerate() {
+            let shift = shifts
+                .entry(node.object_id.clone())
+                .or_insert_with(|| InfluenceShift::empty(&node.object_id, &node.object_name));
+            shift.leverage_before = node.control_leverage;
+            shift.rank_before = Some(rank + 1);
+        }
+
+        for (rank, node) in after.iter().enumerate() {
+            let shift = shifts
+                .entry(node.object_id.clone())
+                .or_insert_with(|| InfluenceShift::empty(&node.object_id, &node.object_name));
+            shift.leverage_after = node.control_leverage;
+            shift.rank_after = Some(rank + 1);
+        }
+
+        let mut result: Vec<InfluenceShift> = shifts
+            .into_values()
+            .map(|mut shift| {
+                shift.leverage_delta = shift.leverage_after - shift.leverage_before;
+                shift
+            })
+            .collect();
+
+        result.sort_by(|a, b| {
+            b.leverage_delta
+                .abs()
+                .partial_cmp(&a.leverage_delta.abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.object_id.cmp(&b.object_id))
+        });
+
+        result
+    }
+}
+
+/// @cybernetic Zmiana pozycji węzła w rankingu wpływu między dwiema datami
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfluenceShift {
+    pub object_id: String,
+    pub object_name: String,
+    pub leverage_before: f64,
+    pub leverage_after: f64,
+    pub leverage_delta: f64,
+    pub rank_before: Option<usize>, // None = brak w rankingu (1 = najsilniejszy)
+    pub rank_after: Option<usize>,
+}
+
+impl InfluenceShift {
+    fn empty(object_id: &str, object_name: &str) -> Self {
+        InfluenceShift {
+            object_id: object_id.to_string(),
+            object_name: object_name.to_string(),
+            leverage_before: 0.0,
+            leverage_after: 0.0,
+            leverage_delta: 0.0,
+            rank_before: None,
+            rank_after: None,
+        }
+    }
+}
+
+// ============================================================================
+// WASM BINDINGS
+// ============================================================================
+
+fn parse_timestamp_arg(name: &str, value: &str) -> Result<i64, JsValue> {
+    parse_timestamp(value)
+        .ok_or_else(|| JsValue::from_str(&format!("Invalid timestamp for '{}': {}", name, value)))
+}
+
+/// @cybernetic WASM Entry Point - BFS na grafie w stanie z dnia `at`
+///
+/// Identyczny wynik jak `wasm_find_influence_paths`, ale tylko dla relacji
+/// ważnych w podanej chwili (ISO 8601).
+#[wasm_bindgen]
+pub fn wasm_find_influence_paths_at(
+    objects_json: &str,
+    correlations_json: &str,
+    target_id: &str,
+    goal: &str,
+    at: &str,
+) -> Result<String, JsValue> {
+    let steering_goal = parse_steering_goal(goal)?;
+    let timestamp = parse_timestamp_arg("at", at)?;
+
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let influential_nodes = graph.at(timestamp).rank_influential_nodes(target_id, &steering_goal);
+
+    serde_json::to_string(&influential_nodes)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+/// @cybernetic WASM Entry Point - Przesunięcie kontroli nad celem między datami
+#[wasm_bindgen]
+pub fn wasm_influence_shift(
+    objects_json: &str,
+    correlations_json: &str,
+    target_id: &str,
+    goal: &str,
+    from: &str,
+    to: &str,
+) -> Result<String, JsValue> {
+    let steering_goal = parse_steering_goal(goal)?;
+    let from_ts = parse_timestamp_arg("from", from)?;
+    let to_ts = parse_timestamp_arg("to", to)?;
+
+    let graph = build_graph_from_json(objects_json, correlations_json)?;
+    let shifts = graph.influence_shift(target_id, &steering_goal, from_ts, to_ts);
+
+    serde_json::to_string(&shifts)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+}
+
+// ============================================================================
+// TESTY JEDNOSTKOWE
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{correlation, object};
+    use crate::RelationType;
+
+    /// a→t ważna do czerwca 2024, b→t od maja 2024 (impact 0.5)
+    fn timeline() -> Graph {
+        Graph::new(
+            vec![object("a", 10.0), object("b", 10.0), object("t", 1.0)],
+            vec![
+                Correlation {
+                    superseded_at: Some("2024-06-01T00:00:00Z".to_string()),
+                    ..correlation("a", "t", RelationType::DirectControl, 0.5, 1.0)
+                },
+                Correlation {
+                    created_at: "2024-05-01T00:00:00Z".to_string(),
+                    ..correlation("b", "t", RelationType::DirectControl, 0.5, 1.0)
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        assert_eq!(parse_timestamp("1970-01-01"), Some(0));
+        assert_eq!(parse_timestamp("1970-01-01T00:00:01Z"), Some(1000));
+        assert_eq!(parse_timestamp("2025-01-01T00:00:00Z"), Some(1_735_689_600_000));
+        assert_eq!(
+            parse_timestamp("2025-01-01T02:00:00.250+02:00"),
+            Some(1_735_689_600_250)
+        );
+        assert_eq!(parse_timestamp("2025-01-01 00:00:00.123456+00"), Some(1_735_689_600_123));
+        assert_eq!(parse_timestamp("not a date"), None);
+    }
+
+    #[test]
+    fn test_graph_at_filters_superseded_relations() {
+        let graph = timeline();
+
+        let march = graph.at(parse_timestamp("2024-03-01").unwrap());
+        let march_nodes = march.rank_influential_nodes("t", &SteeringGoal::Strengthen);
+        assert_eq!(march_nodes.len(), 1);
+        assert_eq!(march_nodes[0].object_id, "a");
+
+        let july = graph.at(parse_timestamp("2024-07-01").unwrap());
+        let july_nodes = july.rank_influential_nodes("t", &SteeringGoal::Strengthen);
+        assert_eq!(july_nodes.len(), 1);
+        assert_eq!(july_nodes[0].object_id, "b");
+    }
+
+    #[test]
+    fn test_influence_shift_between_dates() {
+        let graph = timeline();
+
+        let shifts = graph.influence_shift(
+            "t",
+            &SteeringGoal::Strengthen,
+            parse_timestamp("2024-03-01").unwrap(),
+            parse_timestamp("2024-07-01").unwrap(),
+        );
+
+        let a = shifts.iter().find(|s| s.object_id == "a").unwrap();
+        assert_eq!(a.rank_before, Some(1));
+        assert_eq!(a.rank_after, None);
+        assert_eq!(a.leverage_delta, -5.0); // 10 × 0.5 × 1.0 → 0
+
+        let b = shifts.iter().find(|s| s.object_id == "b").unwrap();
+        assert_eq!(b.rank_before, None);
+        assert_eq!(b.rank_after, Some(1));
+        assert_eq!(b.leverage_delta, 5.0);
+    }
+}
@@ -0,0 +1,62 @@
+//! @fileoverview Wspólne fabryki danych testowych
+//! @cybernetic Minimalne obiekty i relacje do testów algorytmów grafowych
+
+use crate::{
+    ControlSystemType, Correlation, CyberneticObject, EnergyParams, Graph, RelationType,
+    SystemClass,
+};
+
+/// Obiekt o nazwie = ID wielkimi literami i zadanej mocy swobodnej
+pub fn object(id: &str, available_power: f64) -> CyberneticObject {
+    CyberneticObject {
+        id: id.to_string(),
+        name: id.to_uppercase(),
+        description: None,
+        system_class: SystemClass::AutonomousSystem,
+        control_system_type: ControlSystemType::Cognitive,
+        energy_params: EnergyParams {
+            working_power: 0.0,
+            idle_power: 0.0,
+            available_power,
+        },
+        power_v: 1.0,
+        quality_a: 1.0,
+        mass_c: 1.0,
+        civilization_code: Default::default(),
+        motivation_type: Default::default(),
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+    }
+}
+
+/// Relacja source → target o ID "source-target"
+pub fn correlation(
+    source: &str,
+    target: &str,
+    relation_type: RelationType,
+    impact_factor: f64,
+    certainty_score: f64,
+) -> Correlation {
+    Correlation {
+        id: format!("{}-{}", source, target),
+        source_id: source.to_string(),
+        target_id: target.to_string(),
+        relation_type,
+        certainty_score,
+        impact_factor,
+        source_name: None,
+        superseded_at: None,
+        superseded_by: None,
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+    }
+}
+
+/// Graf z listy ID (moc 1.0) i krawędzi DirectControl (impact, certainty = 1.0)
+pub fn graph(ids: &[&str], edges: &[(&str, &str, f64)]) -> Graph {
+    Graph::new(
+        ids.iter().map(|id| object(id, 1.0)).collect(),
+        edges
+            .iter()
+            .map(|(s, t, impact)| correlation(s, t, RelationType::DirectControl, *impact, 1.0))
+            .collect(),
+    )
+}